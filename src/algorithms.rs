@@ -0,0 +1,55 @@
+use crate::math::modint::{ModInt, Modulo};
+use crate::math::num::alge_struct::types::{AddMonoid, MaxMonoid};
+use crate::math::num::{BoundedAbove, Zero};
+use crate::structs::binary_indexed_tree::BinaryIndexedTree;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("Lis", include("BinaryIndexedTree", "MaxMonoid"))
+)]
+pub mod lis;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("Inversions", include("BinaryIndexedTree", "AddMonoid"))
+)]
+pub mod inversions;
+
+#[cfg_attr(nightly, codesnip::entry("ConvexHullTrick"))]
+pub mod convex_hull_trick;
+
+#[cfg_attr(nightly, codesnip::entry("DpOptimization", include("BoundedAbove")))]
+pub mod dp_optimization;
+
+#[cfg_attr(nightly, codesnip::entry("MonotonicDeque"))]
+pub mod monotonic_deque;
+
+#[cfg_attr(nightly, codesnip::entry("MonotonicStack"))]
+pub mod monotonic_stack;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("OfflineRank", include("BinaryIndexedTree", "AddMonoid"))
+)]
+pub mod offline_rank;
+
+#[cfg_attr(nightly, codesnip::entry("Knapsack", include("ModInt", "Zero")))]
+pub mod knapsack;
+
+#[cfg_attr(nightly, codesnip::entry("DigitDp"))]
+pub mod digit_dp;
+
+#[cfg_attr(nightly, codesnip::entry("KWayMerge"))]
+pub mod k_way_merge;
+
+#[cfg_attr(nightly, codesnip::entry("Smawk"))]
+pub mod smawk;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("RangeCount2d", include("BinaryIndexedTree", "AddMonoid"))
+)]
+pub mod range_count_2d;
+
+#[cfg_attr(nightly, codesnip::entry("LineSweep"))]
+pub mod line_sweep;
@@ -0,0 +1,328 @@
+/// A line `y = slope * x + intercept`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Line {
+    slope: i64,
+    intercept: i64,
+}
+
+impl Line {
+    fn eval(self, x: i64) -> i64 {
+        self.slope * x + self.intercept
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    line: Line,
+    children: [Option<usize>; 2],
+}
+
+/// A Li Chao tree, which keeps lines and answers the minimum value at a point.
+///
+/// The points are restricted to the integers in `[x_lo, x_hi)`, and the nodes are created lazily.
+/// For the maximum, add the negated lines and negate the result.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::convex_hull_trick::LiChaoTree;
+///
+/// let mut tree = LiChaoTree::new(-10, 10);
+/// tree.add_line(2, 3);
+/// tree.add_line(-1, 0);
+/// assert_eq!(tree.query_min(-5), Some(-7));
+/// assert_eq!(tree.query_min(3), Some(-3));
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm     | Worst case           |
+/// | ------------- | -------------------- |
+/// | Memory        | O(min(*n* log(*W*), *W*)) |
+/// | [`add_line`]  | O(log(*W*))          |
+/// | [`query_min`] | O(log(*W*))          |
+///
+/// *W* is `x_hi - x_lo`.
+///
+/// [`add_line`]: LiChaoTree::add_line
+/// [`query_min`]: LiChaoTree::query_min
+#[derive(Clone, Debug)]
+pub struct LiChaoTree {
+    x_lo: i64,
+    x_hi: i64,
+    nodes: Vec<Node>,
+}
+
+impl LiChaoTree {
+    /// Creates an empty tree for the points in `[x_lo, x_hi)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x_lo >= x_hi`.
+    pub fn new(x_lo: i64, x_hi: i64) -> Self {
+        assert!(x_lo < x_hi);
+        Self {
+            x_lo,
+            x_hi,
+            nodes: vec![],
+        }
+    }
+
+    /// Adds the line `y = slope * x + intercept`.
+    ///
+    /// This operation is O(log(*W*)).
+    pub fn add_line(&mut self, slope: i64, intercept: i64) {
+        let mut line = Line { slope, intercept };
+        if self.nodes.is_empty() {
+            self.nodes.push(Node {
+                line,
+                children: [None, None],
+            });
+            return;
+        }
+
+        let (mut l, mut r) = (self.x_lo, self.x_hi);
+        let mut k = 0;
+        loop {
+            let m = l + (r - l) / 2;
+            let cur = &mut self.nodes[k].line;
+            // Keep the better line at `m` in the node.
+            if line.eval(m) < cur.eval(m) {
+                core::mem::swap(&mut line, cur);
+            }
+            if r - l == 1 {
+                return;
+            }
+            // The worse line can be better only on one side.
+            let (side, nl, nr) = if line.eval(l) < cur.eval(l) {
+                (0, l, m)
+            } else if line.eval(r - 1) < cur.eval(r - 1) {
+                (1, m, r)
+            } else {
+                return;
+            };
+            match self.nodes[k].children[side] {
+                Some(child) => {
+                    k = child;
+                    l = nl;
+                    r = nr;
+                }
+                None => {
+                    self.nodes[k].children[side] = Some(self.nodes.len());
+                    self.nodes.push(Node {
+                        line,
+                        children: [None, None],
+                    });
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns the minimum value of the lines at `x`, or `None` if there is no line.
+    ///
+    /// This operation is O(log(*W*)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of `[x_lo, x_hi)`.
+    pub fn query_min(&self, x: i64) -> Option<i64> {
+        assert!(self.x_lo <= x && x < self.x_hi);
+        let (mut l, mut r) = (self.x_lo, self.x_hi);
+        let mut k = if self.nodes.is_empty() { None } else { Some(0) };
+        let mut ret: Option<i64> = None;
+        while let Some(i) = k {
+            let y = self.nodes[i].line.eval(x);
+            ret = Some(ret.map_or(y, |z| z.min(y)));
+            let m = l + (r - l) / 2;
+            if x < m {
+                r = m;
+                k = self.nodes[i].children[0];
+            } else {
+                l = m;
+                k = self.nodes[i].children[1];
+            }
+        }
+        ret
+    }
+}
+
+/// A convex hull trick for the lines added in the order of the slopes and the monotone queries.
+///
+/// For the minimum, the slopes must be non-increasing, and for the maximum, non-decreasing.
+/// The queries must be non-decreasing.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::convex_hull_trick::CHT;
+///
+/// let mut cht = CHT::new(true);
+/// cht.add_line(2, 0);
+/// cht.add_line(0, 1);
+/// cht.add_line(-1, 5);
+/// assert_eq!(cht.query(-1), Some(-2));
+/// assert_eq!(cht.query(2), Some(1));
+/// assert_eq!(cht.query(10), Some(-5));
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm    | Amortized |
+/// | ------------ | --------- |
+/// | [`add_line`] | O(1)      |
+/// | [`query`]    | O(1)      |
+///
+/// [`add_line`]: CHT::add_line
+/// [`query`]: CHT::query
+#[derive(Clone, Debug)]
+pub struct CHT {
+    minimize: bool,
+    // lines for the minimum with strictly decreasing slopes
+    lines: Vec<Line>,
+    head: usize,
+    last_query: Option<i64>,
+}
+
+impl CHT {
+    /// Creates an empty convex hull trick for the minimum if `minimize` is `true`, otherwise for the maximum.
+    pub fn new(minimize: bool) -> Self {
+        Self {
+            minimize,
+            lines: vec![],
+            head: 0,
+            last_query: None,
+        }
+    }
+
+    /// Returns `true` if `mid` is unnecessary for the minimum.
+    fn is_redundant(left: Line, mid: Line, right: Line) -> bool {
+        // the intersection of `left` and `right` is not to the right of the one of `left` and `mid`
+        let lhs = i128::from(right.intercept - left.intercept) * i128::from(left.slope - mid.slope);
+        let rhs = i128::from(mid.intercept - left.intercept) * i128::from(left.slope - right.slope);
+        lhs <= rhs
+    }
+
+    /// Adds the line `y = slope * x + intercept`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slope is not in the order.
+    pub fn add_line(&mut self, slope: i64, intercept: i64) {
+        let line = if self.minimize {
+            Line { slope, intercept }
+        } else {
+            Line {
+                slope: -slope,
+                intercept: -intercept,
+            }
+        };
+
+        if let Some(last) = self.lines.last_mut() {
+            assert!(line.slope <= last.slope, "the slopes must be monotone");
+            if line.slope == last.slope {
+                if line.intercept >= last.intercept {
+                    return;
+                }
+                self.lines.pop();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let n = self.lines.len();
+            if Self::is_redundant(self.lines[n - 2], self.lines[n - 1], line) {
+                self.lines.pop();
+            } else {
+                break;
+            }
+        }
+        self.lines.push(line);
+        self.head = self.head.min(self.lines.len() - 1);
+    }
+
+    /// Returns the minimum (or maximum) value of the lines at `x`, or `None` if there is no line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is smaller than the previous query.
+    pub fn query(&mut self, x: i64) -> Option<i64> {
+        assert!(
+            !matches!(self.last_query, Some(last) if last > x),
+            "the queries must be non-decreasing"
+        );
+        self.last_query = Some(x);
+        if self.lines.is_empty() {
+            return None;
+        }
+        while self.head + 1 < self.lines.len()
+            && self.lines[self.head + 1].eval(x) <= self.lines[self.head].eval(x)
+        {
+            self.head += 1;
+        }
+        let y = self.lines[self.head].eval(x);
+        Some(if self.minimize { y } else { -y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn li_chao_tree() {
+        let mut rand = xorshift(0x1357_9BDF, |seed| seed);
+        for _ in 0..50 {
+            let (lo, hi) = (-((rand() % 50) as i64), (rand() % 50) as i64 + 1);
+            let mut tree = LiChaoTree::new(lo, hi);
+            let mut lines = vec![];
+            for _ in 0..20 {
+                let a = (rand() % 21) as i64 - 10;
+                let b = (rand() % 201) as i64 - 100;
+                tree.add_line(a, b);
+                lines.push((a, b));
+                for x in lo..hi {
+                    let naive = lines.iter().map(|&(a, b)| a * x + b).min();
+                    assert_eq!(tree.query_min(x), naive);
+                }
+            }
+        }
+        assert_eq!(LiChaoTree::new(0, 1).query_min(0), None);
+    }
+
+    #[test]
+    fn cht() {
+        let mut rand = xorshift(0x2468_ACE0, |seed| seed);
+        for &minimize in &[true, false] {
+            for _ in 0..50 {
+                let mut slopes = (0..20)
+                    .map(|_| (rand() % 21) as i64 - 10)
+                    .collect::<Vec<_>>();
+                slopes.sort_unstable();
+                if minimize {
+                    slopes.reverse();
+                }
+                let mut cht = CHT::new(minimize);
+                let mut lines = vec![];
+                let mut x = -60;
+                for a in slopes {
+                    let b = (rand() % 201) as i64 - 100;
+                    cht.add_line(a, b);
+                    lines.push((a, b));
+                    x += (rand() % 6) as i64;
+                    let values = lines.iter().map(|&(a, b)| a * x + b);
+                    let naive = if minimize { values.min() } else { values.max() };
+                    assert_eq!(cht.query(x), naive);
+                }
+            }
+        }
+        assert_eq!(CHT::new(true).query(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cht_unsorted_slopes() {
+        let mut cht = CHT::new(true);
+        cht.add_line(0, 0);
+        cht.add_line(1, 0);
+    }
+}
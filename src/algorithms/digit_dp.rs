@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn count<S, F, G>(n: u64, initial_state: &S, transition: &F, accept: &G) -> u64
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S, u32) -> S,
+    G: Fn(&S) -> bool,
+{
+    let digits = n
+        .to_string()
+        .bytes()
+        .map(|b| (b - b'0') as u32)
+        .collect::<Vec<_>>();
+    let step = |s: &S, leading_zero: bool, d: u32| {
+        if leading_zero && d == 0 {
+            (s.clone(), true)
+        } else {
+            (transition(s, d), false)
+        }
+    };
+
+    // the states of the prefixes less than the prefix of `n`, as (state, leading_zero) -> count
+    let mut loose = HashMap::<(S, bool), u64>::new();
+    // the state of the prefix equal to the prefix of `n`
+    let mut tight = (initial_state.clone(), true);
+    for &digit in &digits {
+        let mut next = HashMap::new();
+        for ((s, leading_zero), c) in loose {
+            for d in 0..10 {
+                *next.entry(step(&s, leading_zero, d)).or_insert(0) += c;
+            }
+        }
+        for d in 0..digit {
+            *next.entry(step(&tight.0, tight.1, d)).or_insert(0) += 1;
+        }
+        tight = step(&tight.0, tight.1, digit);
+        loose = next;
+    }
+    *loose.entry(tight).or_insert(0) += 1;
+
+    loose
+        .into_iter()
+        .filter(|((s, leading_zero), _)| {
+            if *leading_zero {
+                // the number 0 is the digit `0`
+                accept(&transition(s, 0))
+            } else {
+                accept(s)
+            }
+        })
+        .map(|(_, c)| c)
+        .sum()
+}
+
+/// Counts the integers in `[0, n]` whose decimal representations are accepted by an automaton.
+///
+/// The automaton starts at `initial_state`, reads the digits from the most significant one with `transition`,
+/// and accepts the final state if `accept` returns `true`.
+/// The leading zeros are not read, and `0` is read as the single digit `0`.
+///
+/// The count overflows `u64` only if `n` is `u64::MAX` and all the integers are accepted,
+/// since there are 2⁶⁴ integers in `[0, u64::MAX]`.
+///
+/// This operation is O(*DS*), where *D* is the number of digits of `n` and *S* is the number of the reachable states.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::digit_dp::digit_dp;
+///
+/// // multiples of 3 in [0, 100]
+/// assert_eq!(digit_dp(100, 0, |&s, d| (s + d) % 3, |&s| s == 0), 34);
+/// // integers in [0, 1000] containing the digit 7
+/// assert_eq!(digit_dp(1000, false, |&s, d| s || d == 7, |&s| s), 271);
+/// ```
+pub fn digit_dp<S, F, G>(n: u64, initial_state: S, transition: F, accept: G) -> u64
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S, u32) -> S,
+    G: Fn(&S) -> bool,
+{
+    count(n, &initial_state, &transition, &accept)
+}
+
+/// Counts the integers in `[lo, hi]` whose decimal representations are accepted by an automaton.
+///
+/// See [`digit_dp`] for the details, where the same overflow applies to `hi`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::digit_dp::digit_dp_range;
+///
+/// // the digit sums are 10 in [100, 200]: 109, 118, …, 190
+/// assert_eq!(digit_dp_range(100, 200, 0, |&s, d| s + d, |&s| s == 10), 10);
+/// assert_eq!(digit_dp_range(5, 4, 0, |&s, d| s + d, |_| true), 0);
+/// ```
+pub fn digit_dp_range<S, F, G>(lo: u64, hi: u64, initial_state: S, transition: F, accept: G) -> u64
+where
+    S: Clone + Eq + Hash,
+    F: Fn(&S, u32) -> S,
+    G: Fn(&S) -> bool,
+{
+    if lo > hi {
+        return 0;
+    }
+    let upper = count(hi, &initial_state, &transition, &accept);
+    if lo == 0 {
+        upper
+    } else {
+        upper - count(lo - 1, &initial_state, &transition, &accept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brute_force() {
+        let digit_sum = |mut x: u64| {
+            let mut s = 0;
+            loop {
+                s += x % 10;
+                x /= 10;
+                if x == 0 {
+                    return s;
+                }
+            }
+        };
+        // the number of digits, where 0 has one digit
+        let len = |x: u64| x.to_string().len() as u32;
+
+        for n in 0..1200 {
+            let expected = (0..=n).filter(|&x| digit_sum(x) % 7 == 3).count() as u64;
+            assert_eq!(digit_dp(n, 0, |&s, d| (s + d) % 7, |&s| s == 3), expected);
+
+            let expected = (0..=n).filter(|&x| len(x) == 2).count() as u64;
+            assert_eq!(digit_dp(n, 0, |&s, _| s + 1, |&s| s == 2), expected);
+
+            let lo = n / 3;
+            let expected = (lo..=n).filter(|&x| x % 2 == 1).count() as u64;
+            assert_eq!(
+                digit_dp_range(lo, n, 0, |_, d| d % 2, |&s| s == 1),
+                expected
+            );
+        }
+        let max = 18_446_744_073_709_551_615;
+        assert_eq!(
+            digit_dp(max, 0, |&s, d| (s + d) % 9, |&s| s == 0),
+            max / 9 + 1
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use super::BoundedAbove;
+
+/// Computes `dp[k][i] = min { dp[k - 1][j] + cost(j, i) | j < i }` for `k ∈ [1, m]` and `i ∈ [0, n]`
+/// with divide-and-conquer optimization,
+/// where `dp[0][0] = 0` and `dp[0][i] = ∞` for `i > 0`.
+///
+/// `dp[k][i]` is the minimum cost to split `[0, i)` into `k` non-empty segments, where the segment `[j, i)` costs `cost(j, i)`.
+/// Unreachable states are `i64::upper_bound()`.
+///
+/// # Precondition
+///
+/// The smallest optimal `j` for each `i` must be monotone (non-decreasing) in `i`.
+/// It holds if `cost` satisfies the quadrangle inequality
+/// `cost(a, c) + cost(b, d) ≤ cost(a, d) + cost(b, c)` for `a ≤ b ≤ c ≤ d`.
+/// Common costs which satisfy it are:
+///
+/// - `(sum of [j, i))²` for non-negative values,
+/// - `(i - j)²` or any convex function of the length,
+/// - the number of pairs of equal values in `[j, i)`.
+///
+/// If the precondition is not satisfied, the result may be wrong.
+///
+/// This operation is O(*mn* log(*n*)) calls of `cost`, instead of O(*mn*²).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::dp_optimization::dc_dp;
+///
+/// // split `a` into segments minimizing the sum of the squared sums
+/// let a = [1_i64, 3, 2, 4];
+/// let mut prefix = vec![0];
+/// for x in &a {
+///     prefix.push(prefix.last().unwrap() + x);
+/// }
+/// let dp = dc_dp(a.len(), 2, |j, i| (prefix[i] - prefix[j]).pow(2));
+/// // [1, 3] [2, 4]
+/// assert_eq!(dp[2][4], 4 * 4 + 6 * 6);
+/// assert_eq!(dp[1][4], 10 * 10);
+/// ```
+pub fn dc_dp<F: Fn(usize, usize) -> i64>(n: usize, m: usize, cost: F) -> Vec<Vec<i64>> {
+    // Computes `cur[i]` for `i ∈ [l, r)`, knowing the optimal `j` is in `[opt_l, opt_r]`.
+    fn solve<F: Fn(usize, usize) -> i64>(
+        prev: &[i64],
+        cur: &mut [i64],
+        cost: &F,
+        (l, r): (usize, usize),
+        (opt_l, opt_r): (usize, usize),
+    ) {
+        if l >= r {
+            return;
+        }
+        let inf = i64::upper_bound();
+        let mid = (l + r) / 2;
+        let mut best = (inf, opt_l);
+        if mid > 0 {
+            let hi = opt_r.min(mid - 1);
+            for (j, &p) in prev.iter().enumerate().take(hi + 1).skip(opt_l) {
+                if p == inf {
+                    continue;
+                }
+                let value = p + cost(j, mid);
+                if value < best.0 {
+                    best = (value, j);
+                }
+            }
+        }
+        cur[mid] = best.0;
+        solve(prev, cur, cost, (l, mid), (opt_l, best.1));
+        solve(prev, cur, cost, (mid + 1, r), (best.1, opt_r));
+    }
+
+    let inf = i64::upper_bound();
+    let mut dp = Vec::with_capacity(m + 1);
+    let mut first = vec![inf; n + 1];
+    first[0] = 0;
+    dp.push(first);
+    for k in 1..=m {
+        let mut cur = vec![inf; n + 1];
+        solve(&dp[k - 1], &mut cur, &cost, (0, n + 1), (0, n));
+        dp.push(cur);
+    }
+    dp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn naive<F: Fn(usize, usize) -> i64>(n: usize, m: usize, cost: F) -> Vec<Vec<i64>> {
+        let inf = i64::upper_bound();
+        let mut dp = vec![vec![inf; n + 1]; m + 1];
+        dp[0][0] = 0;
+        for k in 1..=m {
+            for i in 1..=n {
+                for j in 0..i {
+                    if dp[k - 1][j] != inf {
+                        dp[k][i] = dp[k][i].min(dp[k - 1][j] + cost(j, i));
+                    }
+                }
+            }
+        }
+        dp
+    }
+
+    #[test]
+    fn squared_sum() {
+        let mut rand = xorshift(0xACE1_u64, |seed| seed);
+        for n in 0..30 {
+            let a = (0..n).map(|_| (rand() % 10) as i64).collect::<Vec<_>>();
+            let mut prefix = vec![0];
+            for x in &a {
+                prefix.push(prefix.last().unwrap() + x);
+            }
+            let cost = |j: usize, i: usize| (prefix[i] - prefix[j]).pow(2);
+            let m = (rand() % 6) as usize;
+            assert_eq!(dc_dp(n, m, cost), naive(n, m, cost));
+        }
+    }
+
+    #[test]
+    fn equal_pairs() {
+        let a = [1, 2, 1, 1, 3, 2, 2, 3, 1, 1, 2];
+        let cost = |j: usize, i: usize| {
+            let mut count = 0;
+            for x in j..i {
+                for y in x + 1..i {
+                    if a[x] == a[y] {
+                        count += 1;
+                    }
+                }
+            }
+            count
+        };
+        for m in 0..5 {
+            assert_eq!(dc_dp(a.len(), m, cost), naive(a.len(), m, cost));
+        }
+    }
+}
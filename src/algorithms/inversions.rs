@@ -0,0 +1,147 @@
+use super::{AddMonoid, BinaryIndexedTree};
+use core::cmp::Ordering::{Greater, Less};
+
+/// Counts the pairs `i < j` such that `arr[i] > arr[j]`.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::inversions::inversion_count;
+///
+/// assert_eq!(inversion_count(&[3, 1, 2]), 2);
+/// assert_eq!(inversion_count(&["b", "a", "a"]), 2);
+/// assert_eq!(inversion_count::<i32>(&[]), 0);
+/// ```
+pub fn inversion_count<T: Ord + Clone>(arr: &[T]) -> u64 {
+    let mut sorted = arr.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut bit = BinaryIndexedTree::<AddMonoid<u64>>::with_size(sorted.len());
+    let mut count = 0;
+    for x in arr.iter().rev() {
+        let c = sorted.binary_search(x).unwrap();
+        // the number of smaller elements on the right
+        count += bit.fold(..c);
+        bit.operate(c, &1);
+    }
+    count
+}
+
+/// Returns the inversion count after each update `arr[i] = x` of `updates`.
+///
+/// The array is split into the blocks of O(√*n*) elements, each of which is kept sorted,
+/// so that each update is handled in O(√*n* log(*n*)).
+///
+/// This operation is O(*n* log(*n*) + *q*√*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::inversions::inversions_with_updates;
+///
+/// let counts = inversions_with_updates(vec![1, 2, 3], &[(0, 5), (2, 0)]);
+/// // [5, 2, 3] -> [5, 2, 0]
+/// assert_eq!(counts, vec![2, 3]);
+/// ```
+pub fn inversions_with_updates<T: Ord + Clone>(
+    mut arr: Vec<T>,
+    updates: &[(usize, T)],
+) -> Vec<u64> {
+    // the number of inversions involving `arr[i]` replaced by `x`
+    fn involved<T: Ord>(arr: &[T], blocks: &[Vec<T>], size: usize, i: usize, x: &T) -> u64 {
+        let b = i / size;
+        let mut count = 0;
+        for (k, block) in blocks.iter().enumerate() {
+            if k < b {
+                // the number of the elements greater than `x`
+                let le = block
+                    .binary_search_by(|y| if y <= x { Less } else { Greater })
+                    .unwrap_err();
+                count += block.len() - le;
+            } else if k > b {
+                // the number of the elements less than `x`
+                count += block
+                    .binary_search_by(|y| if y < x { Less } else { Greater })
+                    .unwrap_err();
+            }
+        }
+        let (lo, hi) = (b * size, ((b + 1) * size).min(arr.len()));
+        count += arr[lo..i].iter().filter(|y| *y > x).count();
+        count += arr[i + 1..hi].iter().filter(|y| *y < x).count();
+        count as u64
+    }
+
+    let size = ((arr.len() as f64).sqrt() as usize).max(1);
+    let mut blocks = arr
+        .chunks(size)
+        .map(|block| {
+            let mut block = block.to_vec();
+            block.sort_unstable();
+            block
+        })
+        .collect::<Vec<_>>();
+
+    let mut count = inversion_count(&arr);
+    updates
+        .iter()
+        .map(|(i, x)| {
+            let i = *i;
+            count = count - involved(&arr, &blocks, size, i, &arr[i])
+                + involved(&arr, &blocks, size, i, x);
+
+            let block = &mut blocks[i / size];
+            let old = block.binary_search(&arr[i]).unwrap();
+            block.remove(old);
+            let new = block.binary_search(x).unwrap_or_else(|p| p);
+            block.insert(new, x.clone());
+            arr[i] = x.clone();
+            count
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn brute_force<T: Ord>(arr: &[T]) -> u64 {
+        let mut count = 0;
+        for (i, x) in arr.iter().enumerate() {
+            count += arr[i + 1..].iter().filter(|y| x > *y).count() as u64;
+        }
+        count
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(0xDEAD_BEEF_u64, |seed| seed);
+        for n in 1..50 {
+            let arr = (0..n).map(|_| rand() % 10).collect::<Vec<_>>();
+            assert_eq!(inversion_count(&arr), brute_force(&arr));
+
+            let updates = (0..20)
+                .map(|_| ((rand() % n) as usize, rand() % 10))
+                .collect::<Vec<_>>();
+            let counts = inversions_with_updates(arr.clone(), &updates);
+            let mut cur = arr;
+            for ((i, x), count) in updates.into_iter().zip(counts) {
+                cur[i] = x;
+                assert_eq!(count, brute_force(&cur));
+            }
+        }
+    }
+
+    #[test]
+    fn reversed() {
+        let arr = (0..1000).rev().collect::<Vec<_>>();
+        assert_eq!(inversion_count(&arr), 1000 * 999 / 2);
+    }
+}
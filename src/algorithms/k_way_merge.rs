@@ -0,0 +1,116 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// The iterator returned by [`k_way_merge`].
+pub struct KWayMerge<'a, T> {
+    iters: Vec<Box<dyn Iterator<Item = T> + 'a>>,
+    // the head of each iterator with its index, so the ties are broken by the order of the iterators
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+}
+
+impl<'a, T: Ord> Iterator for KWayMerge<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let Reverse((x, i)) = self.heap.pop()?;
+        if let Some(y) = self.iters[i].next() {
+            self.heap.push(Reverse((y, i)));
+        }
+        Some(x)
+    }
+}
+
+/// Merges the sorted iterators into one sorted iterator with a binary heap.
+///
+/// The equal elements are yielded in the order of `iters`, so the merge is stable.
+///
+/// This operation is O(*n* log(*k*)) in total, where *k* is the number of the iterators.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::k_way_merge::k_way_merge;
+///
+/// let merged = k_way_merge(vec![
+///     Box::new(vec![1, 4, 7].into_iter()),
+///     Box::new((2..6).step_by(3)),
+///     Box::new(std::iter::empty()),
+///     Box::new(vec![0, 4].into_iter()),
+/// ]);
+/// assert_eq!(merged.collect::<Vec<_>>(), vec![0, 1, 2, 4, 4, 5, 7]);
+/// ```
+pub fn k_way_merge<'a, T: Ord>(
+    mut iters: Vec<Box<dyn Iterator<Item = T> + 'a>>,
+) -> KWayMerge<'a, T> {
+    let heap = iters
+        .iter_mut()
+        .enumerate()
+        .filter_map(|(i, it)| it.next().map(|x| Reverse((x, i))))
+        .collect();
+    KWayMerge { iters, heap }
+}
+
+/// Merges the sorted vectors into one sorted vector.
+///
+/// This operation is O(*n* log(*k*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::k_way_merge::k_way_merge_sorted_vecs;
+///
+/// let vecs = vec![vec![3, 5], vec![1, 2, 9], vec![], vec![4]];
+/// assert_eq!(k_way_merge_sorted_vecs(&vecs), vec![1, 2, 3, 4, 5, 9]);
+/// ```
+pub fn k_way_merge_sorted_vecs<T: Ord + Clone>(vecs: &[Vec<T>]) -> Vec<T> {
+    let iters = vecs
+        .iter()
+        .map(|v| Box::new(v.iter().cloned()) as Box<dyn Iterator<Item = T>>)
+        .collect();
+    let mut res = Vec::with_capacity(vecs.iter().map(|v| v.len()).sum());
+    res.extend(k_way_merge(iters));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for k in 0..10 {
+            for _ in 0..10 {
+                let vecs = (0..k)
+                    .map(|_| {
+                        let len = rand() % 10;
+                        let mut v = (0..len).map(|_| rand() % 20).collect::<Vec<_>>();
+                        v.sort_unstable();
+                        v
+                    })
+                    .collect::<Vec<_>>();
+                let mut expected = vecs.concat();
+                expected.sort_unstable();
+                assert_eq!(k_way_merge_sorted_vecs(&vecs), expected);
+
+                // stable: compare only the keys
+                let iters = vecs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        Box::new(v.iter().map(move |&x| (x, i))) as Box<dyn Iterator<Item = _>>
+                    })
+                    .collect::<Vec<_>>();
+                let merged = k_way_merge(iters).collect::<Vec<_>>();
+                let mut expected = vecs
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, v)| v.iter().map(move |&x| (x, i)))
+                    .collect::<Vec<_>>();
+                expected.sort_by_key(|&(x, _)| x);
+                assert_eq!(merged, expected);
+            }
+        }
+    }
+}
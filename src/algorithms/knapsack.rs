@@ -0,0 +1,154 @@
+use super::{ModInt, Modulo, Zero};
+use core::ops::Add;
+
+/// Solves the 0-1 knapsack problem, and returns the maximum total value of the items
+/// `(weight, value)` whose total weight is at most `capacity`.
+///
+/// Each item is used at most once.
+///
+/// This operation is O(*nW*), where *W* is `capacity`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::knapsack::knapsack_01;
+///
+/// let items = [(2, 3), (1, 2), (3, 4), (2, 2)];
+/// assert_eq!(knapsack_01(5, &items), 7);
+/// ```
+pub fn knapsack_01<V>(capacity: usize, items: &[(usize, V)]) -> V
+where
+    V: Ord + Copy + Zero + Add<Output = V>,
+{
+    // dp[w] is the maximum value with the total weight at most `w`
+    let mut dp = vec![V::zero(); capacity + 1];
+    for &(weight, value) in items {
+        if weight > capacity {
+            continue;
+        }
+        for w in (weight..=capacity).rev() {
+            dp[w] = dp[w].max(dp[w - weight] + value);
+        }
+    }
+    dp[capacity]
+}
+
+/// Solves the unbounded knapsack problem, and returns the maximum total value of the items
+/// `(weight, value)` whose total weight is at most `capacity`.
+///
+/// Each item can be used any number of times.
+///
+/// This operation is O(*nW*), where *W* is `capacity`.
+///
+/// # Panics
+///
+/// Panics if an item has the weight 0.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::knapsack::knapsack_unbounded;
+///
+/// let items = [(3, 4), (4, 5), (2, 3)];
+/// assert_eq!(knapsack_unbounded(7, &items), 10);
+/// ```
+pub fn knapsack_unbounded<V>(capacity: usize, items: &[(usize, V)]) -> V
+where
+    V: Ord + Copy + Zero + Add<Output = V>,
+{
+    let mut dp = vec![V::zero(); capacity + 1];
+    for &(weight, value) in items {
+        assert!(weight > 0, "the weight must be positive");
+        for w in weight..=capacity {
+            dp[w] = dp[w].max(dp[w - weight] + value);
+        }
+    }
+    dp[capacity]
+}
+
+/// Counts the subsets of `items` whose sum is exactly `capacity`.
+///
+/// The items are distinguished even if they have the same weight.
+///
+/// This operation is O(*nW*), where *W* is `capacity`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::knapsack::knapsack_count;
+/// use lib_rust::math::modint::types::ModInt1e9_7;
+///
+/// // {1, 4}, {2, 3}, {1, 4'}
+/// let count: ModInt1e9_7 = knapsack_count(5, &[1, 2, 3, 4, 4]);
+/// assert_eq!(count, 3);
+/// ```
+pub fn knapsack_count<M>(capacity: usize, items: &[usize]) -> ModInt<M>
+where
+    M: Modulo<Set = usize> + Copy,
+{
+    let mut dp = vec![ModInt::zero(); capacity + 1];
+    dp[0] = ModInt::one();
+    for &weight in items {
+        if weight > capacity {
+            continue;
+        }
+        for w in (weight..=capacity).rev() {
+            let add = dp[w - weight];
+            dp[w] += add;
+        }
+    }
+    dp[capacity]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::ModInt1e9_7;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..10 {
+            let items = (0..n)
+                .map(|_| (rand() % 6 + 1, (rand() % 10) as i64))
+                .collect::<Vec<_>>();
+            for capacity in 0..20 {
+                let mut best = 0;
+                let mut count = 0;
+                for s in 0..1_usize << n {
+                    let (mut w, mut v) = (0, 0);
+                    for (i, &(wi, vi)) in items.iter().enumerate() {
+                        if s >> i & 1 == 1 {
+                            w += wi;
+                            v += vi;
+                        }
+                    }
+                    if w <= capacity {
+                        best = best.max(v);
+                    }
+                    if w == capacity {
+                        count += 1;
+                    }
+                }
+                assert_eq!(knapsack_01(capacity, &items), best);
+                let weights = items.iter().map(|&(w, _)| w).collect::<Vec<_>>();
+                let c: ModInt1e9_7 = knapsack_count(capacity, &weights);
+                assert_eq!(c, count);
+
+                // the unbounded version with at most `capacity` copies of each item
+                let mut copies = vec![];
+                for &item in &items {
+                    for _ in 0..capacity / item.0 {
+                        copies.push(item);
+                    }
+                }
+                assert_eq!(
+                    knapsack_unbounded(capacity, &items),
+                    knapsack_01(capacity, &copies)
+                );
+            }
+        }
+    }
+}
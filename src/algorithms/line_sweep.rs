@@ -0,0 +1,83 @@
+/// Splits the union of the half-open intervals `[l, r)` into the disjoint intervals
+/// annotated with the number of the intervals covering them.
+///
+/// Returns `(l, r, depth)` in ascending order, where `depth > 0`
+/// and the adjacent intervals of the same depth are merged.
+/// An interval with `l >= r` is empty and ignored.
+/// See [`area_union_of_rectangles`] for the two-dimensional sweep.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::line_sweep::count_overlapping_intervals;
+///
+/// let intervals = [(0, 4), (2, 6), (3, 5), (8, 9), (6, 8)];
+/// assert_eq!(
+///     count_overlapping_intervals(&intervals),
+///     vec![(0, 2, 1), (2, 3, 2), (3, 4, 3), (4, 5, 2), (5, 9, 1)]
+/// );
+/// ```
+///
+/// [`area_union_of_rectangles`]: crate::geometry::rectangle_union::area_union_of_rectangles
+pub fn count_overlapping_intervals(intervals: &[(i64, i64)]) -> Vec<(i64, i64, usize)> {
+    let mut events = Vec::with_capacity(2 * intervals.len());
+    for &(l, r) in intervals {
+        if l < r {
+            events.push((l, 1));
+            events.push((r, -1));
+        }
+    }
+    events.sort_unstable();
+
+    let mut ret: Vec<(i64, i64, usize)> = vec![];
+    let mut depth = 0_isize;
+    let mut i = 0;
+    while i < events.len() {
+        let x = events[i].0;
+        while i < events.len() && events[i].0 == x {
+            depth += events[i].1;
+            i += 1;
+        }
+        if depth == 0 {
+            continue;
+        }
+        // `depth > 0` means that another event follows
+        let (next, depth) = (events[i].0, depth as usize);
+        match ret.last_mut() {
+            Some(last) if last.1 == x && last.2 == depth => last.1 = next,
+            _ => ret.push((x, next, depth)),
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| (seed % 20) as i64);
+
+        for n in 0..30 {
+            let intervals = (0..n).map(|_| (rand(), rand())).collect::<Vec<_>>();
+            let depth = |x: i64| intervals.iter().filter(|&&(l, r)| l <= x && x < r).count();
+
+            let mut expected: Vec<(i64, i64, usize)> = vec![];
+            for x in 0..20 {
+                let d = depth(x);
+                if d == 0 {
+                    continue;
+                }
+                match expected.last_mut() {
+                    Some(last) if last.1 == x && last.2 == d => last.1 = x + 1,
+                    _ => expected.push((x, x + 1, d)),
+                }
+            }
+            assert_eq!(count_overlapping_intervals(&intervals), expected);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{BinaryIndexedTree, MaxMonoid};
+
+/// Returns `(length, previous index)` of the longest strictly increasing subsequence ending at each index.
+fn lis_table(seq: &[i64]) -> Vec<(usize, Option<usize>)> {
+    let mut sorted = seq.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    // The best `(length, index)` of subsequences ending with each compressed value.
+    let mut bit = BinaryIndexedTree::<MaxMonoid<(usize, usize)>>::with_size(sorted.len());
+    seq.iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let c = sorted.binary_search(x).unwrap();
+            let (len, prev) = bit.fold(..c);
+            let prev = if len == 0 { None } else { Some(prev) };
+            bit.operate(c, &(len + 1, i));
+            (len + 1, prev)
+        })
+        .collect()
+}
+
+/// Returns the length of the longest strictly increasing subsequence.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::lis::lis_length;
+///
+/// assert_eq!(lis_length(&[3, 1, 4, 1, 5, 9, 2, 6]), 4);
+/// assert_eq!(lis_length(&[2, 2, 2]), 1);
+/// assert_eq!(lis_length(&[]), 0);
+/// ```
+pub fn lis_length(seq: &[i64]) -> usize {
+    lis_table(seq)
+        .into_iter()
+        .map(|(len, _)| len)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the indices of one of the longest strictly increasing subsequences in ascending order.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::lis::lis_indices;
+///
+/// let seq = [3, 1, 4, 1, 5, 9, 2, 6];
+/// let lis = lis_indices(&seq);
+/// assert_eq!(lis.len(), 4);
+/// assert!(lis.windows(2).all(|w| w[0] < w[1] && seq[w[0]] < seq[w[1]]));
+/// ```
+pub fn lis_indices(seq: &[i64]) -> Vec<usize> {
+    let table = lis_table(seq);
+    let last = (0..seq.len()).max_by_key(|&i| table[i].0);
+
+    let mut indices = vec![];
+    let mut cur = last;
+    while let Some(i) = cur {
+        indices.push(i);
+        cur = table[i].1;
+    }
+    indices.reverse();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn brute_force(seq: &[i64]) -> usize {
+        let mut dp = vec![1; seq.len()];
+        for i in 0..seq.len() {
+            for j in 0..i {
+                if seq[j] < seq[i] {
+                    dp[i] = dp[i].max(dp[j] + 1);
+                }
+            }
+        }
+        dp.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(0x2545_F491_u64, |seed| seed);
+        for n in 0..60 {
+            let seq = (0..n)
+                .map(|_| (rand() % 20) as i64 - 10)
+                .collect::<Vec<_>>();
+            let len = brute_force(&seq);
+            assert_eq!(lis_length(&seq), len);
+
+            let lis = lis_indices(&seq);
+            assert_eq!(lis.len(), len);
+            assert!(lis.windows(2).all(|w| w[0] < w[1] && seq[w[0]] < seq[w[1]]));
+        }
+    }
+
+    #[test]
+    fn monotone() {
+        let inc = (0..100).collect::<Vec<i64>>();
+        assert_eq!(lis_indices(&inc), (0..100).collect::<Vec<_>>());
+        let dec = (0..100).rev().collect::<Vec<i64>>();
+        assert_eq!(lis_length(&dec), 1);
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+macro_rules! define_sliding_window {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $get:ident, $dominates:tt
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug)]
+        pub struct $name<T: Ord> {
+            deque: VecDeque<(usize, T)>,
+        }
+
+        impl<T: Ord> $name<T> {
+            /// Creates an empty window.
+            pub fn new() -> Self {
+                Self {
+                    deque: VecDeque::new(),
+                }
+            }
+
+            /// Pushes `value` at `index` to the back of the window.
+            ///
+            /// `index` must be greater than the indices pushed before.
+            ///
+            /// This operation is amortized O(1).
+            pub fn push(&mut self, index: usize, value: T) {
+                debug_assert!(self.deque.back().map_or(true, |&(i, _)| i < index));
+                while matches!(self.deque.back(), Some((_, v)) if value $dominates *v) {
+                    self.deque.pop_back();
+                }
+                self.deque.push_back((index, value));
+            }
+
+            /// Removes the elements whose index is less than `expire_before`.
+            ///
+            /// This operation is amortized O(1).
+            pub fn pop_front(&mut self, expire_before: usize) {
+                while matches!(self.deque.front(), Some(&(i, _)) if i < expire_before) {
+                    self.deque.pop_front();
+                }
+            }
+
+            /// Returns the value in the window, or `None` if the window is empty.
+            ///
+            /// This operation is O(1).
+            pub fn $get(&self) -> Option<&T> {
+                self.deque.front().map(|(_, v)| v)
+            }
+        }
+
+        impl<T: Ord> Default for $name<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+define_sliding_window! {
+    /// The maximum of a sliding window, backed by a monotonic deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::algorithms::monotonic_deque::SlidingWindowMax;
+    ///
+    /// let mut window = SlidingWindowMax::new();
+    /// window.push(0, 3);
+    /// window.push(1, 1);
+    /// assert_eq!(window.max(), Some(&3));
+    /// window.pop_front(1);
+    /// assert_eq!(window.max(), Some(&1));
+    /// window.pop_front(2);
+    /// assert_eq!(window.max(), None);
+    /// ```
+    SlidingWindowMax, max, >=
+}
+
+define_sliding_window! {
+    /// The minimum of a sliding window, backed by a monotonic deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::algorithms::monotonic_deque::SlidingWindowMin;
+    ///
+    /// let mut window = SlidingWindowMin::new();
+    /// window.push(0, 1);
+    /// window.push(1, 3);
+    /// assert_eq!(window.min(), Some(&1));
+    /// window.pop_front(1);
+    /// assert_eq!(window.min(), Some(&3));
+    /// ```
+    SlidingWindowMin, min, <=
+}
+
+/// Returns the maximum of each window `arr[i..i + k]`.
+///
+/// The result is empty if `k > arr.len()`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if `k` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_deque::sliding_window_max;
+///
+/// assert_eq!(sliding_window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3), vec![3, 3, 5, 5, 6, 7]);
+/// ```
+pub fn sliding_window_max<T: Ord + Clone>(arr: &[T], k: usize) -> Vec<T> {
+    assert!(k > 0);
+    let mut window = SlidingWindowMax::new();
+    let mut ret = Vec::with_capacity((arr.len() + 1).saturating_sub(k));
+    for (i, x) in arr.iter().enumerate() {
+        window.push(i, x.clone());
+        if i + 1 >= k {
+            window.pop_front(i + 1 - k);
+            ret.extend(window.max().cloned());
+        }
+    }
+    ret
+}
+
+/// Returns the minimum of each window `arr[i..i + k]`.
+///
+/// The result is empty if `k > arr.len()`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if `k` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_deque::sliding_window_min;
+///
+/// assert_eq!(sliding_window_min(&[1, 3, -1, -3, 5, 3, 6, 7], 3), vec![-1, -3, -3, -3, 3, 3]);
+/// ```
+pub fn sliding_window_min<T: Ord + Clone>(arr: &[T], k: usize) -> Vec<T> {
+    assert!(k > 0);
+    let mut window = SlidingWindowMin::new();
+    let mut ret = Vec::with_capacity((arr.len() + 1).saturating_sub(k));
+    for (i, x) in arr.iter().enumerate() {
+        window.push(i, x.clone());
+        if i + 1 >= k {
+            window.pop_front(i + 1 - k);
+            ret.extend(window.min().cloned());
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..30 {
+            let a = (0..n).map(|_| (rand() % 10) as i32).collect::<Vec<_>>();
+            for k in 1..=n + 1 {
+                let max = a
+                    .windows(k)
+                    .map(|w| *w.iter().max().unwrap())
+                    .collect::<Vec<_>>();
+                let min = a
+                    .windows(k)
+                    .map(|w| *w.iter().min().unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(sliding_window_max(&a, k), max);
+                assert_eq!(sliding_window_min(&a, k), min);
+            }
+        }
+    }
+
+    #[test]
+    fn variable_window() {
+        // two pointers with a window whose size changes
+        let a = [5, 2, 8, 1, 9, 3];
+        let mut window = SlidingWindowMax::new();
+        let mut l = 0;
+        for (r, &x) in a.iter().enumerate() {
+            window.push(r, x);
+            if r % 2 == 1 {
+                l += 1;
+                window.pop_front(l);
+            }
+            assert_eq!(window.max(), a[l..=r].iter().max());
+        }
+    }
+}
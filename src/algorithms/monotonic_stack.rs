@@ -0,0 +1,135 @@
+/// For each `i` in `order`, finds the last visited index `j` such that `beats(&arr[j], &arr[i])`.
+fn nearest<T, I, F>(arr: &[T], order: I, beats: F) -> Vec<Option<usize>>
+where
+    I: Iterator<Item = usize>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut ret = vec![None; arr.len()];
+    let mut stack: Vec<usize> = Vec::new();
+    for i in order {
+        while matches!(stack.last(), Some(&j) if !beats(&arr[j], &arr[i])) {
+            stack.pop();
+        }
+        ret[i] = stack.last().copied();
+        stack.push(i);
+    }
+    ret
+}
+
+/// Returns the smallest `j > i` such that `arr[j] > arr[i]` for each `i`, or `None` if it does not exist.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_stack::next_greater_element;
+///
+/// assert_eq!(
+///     next_greater_element(&[2, 1, 2, 4, 3]),
+///     vec![Some(3), Some(2), Some(3), None, None]
+/// );
+/// ```
+pub fn next_greater_element<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    nearest(arr, (0..arr.len()).rev(), |a, b| a > b)
+}
+
+/// Returns the smallest `j > i` such that `arr[j] < arr[i]` for each `i`, or `None` if it does not exist.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_stack::next_smaller_element;
+///
+/// assert_eq!(
+///     next_smaller_element(&[2, 1, 2, 4, 3]),
+///     vec![Some(1), None, None, Some(4), None]
+/// );
+/// ```
+pub fn next_smaller_element<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    nearest(arr, (0..arr.len()).rev(), |a, b| a < b)
+}
+
+/// Returns the largest `j < i` such that `arr[j] > arr[i]` for each `i`, or `None` if it does not exist.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_stack::previous_greater_element;
+///
+/// // stock span: `i - previous_greater_element[i]`
+/// assert_eq!(
+///     previous_greater_element(&[2, 1, 2, 4, 3]),
+///     vec![None, Some(0), None, None, Some(3)]
+/// );
+/// ```
+pub fn previous_greater_element<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    nearest(arr, 0..arr.len(), |a, b| a > b)
+}
+
+/// Returns the largest `j < i` such that `arr[j] < arr[i]` for each `i`, or `None` if it does not exist.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::monotonic_stack::previous_smaller_element;
+///
+/// assert_eq!(
+///     previous_smaller_element(&[2, 1, 2, 4, 3]),
+///     vec![None, None, Some(1), Some(2), Some(2)]
+/// );
+/// ```
+pub fn previous_smaller_element<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    nearest(arr, 0..arr.len(), |a, b| a < b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..40 {
+            let a = (0..n).map(|_| rand() % 8).collect::<Vec<_>>();
+            let next = |f: fn(&u64, &u64) -> bool| {
+                (0..n)
+                    .map(|i| (i + 1..n).find(|&j| f(&a[j], &a[i])))
+                    .collect::<Vec<_>>()
+            };
+            let prev = |f: fn(&u64, &u64) -> bool| {
+                (0..n)
+                    .map(|i| (0..i).rev().find(|&j| f(&a[j], &a[i])))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(next_greater_element(&a), next(|x, y| x > y));
+            assert_eq!(next_smaller_element(&a), next(|x, y| x < y));
+            assert_eq!(previous_greater_element(&a), prev(|x, y| x > y));
+            assert_eq!(previous_smaller_element(&a), prev(|x, y| x < y));
+        }
+    }
+
+    #[test]
+    fn largest_rectangle() {
+        let h = [2, 1, 5, 6, 2, 3];
+        let n = h.len();
+        let left = previous_smaller_element(&h);
+        let right = next_smaller_element(&h);
+        let area = (0..n)
+            .map(|i| {
+                let l = left[i].map_or(0, |j| j + 1);
+                let r = right[i].unwrap_or(n);
+                h[i] * (r - l)
+            })
+            .max();
+        assert_eq!(area, Some(10));
+    }
+}
@@ -0,0 +1,72 @@
+use super::{AddMonoid, BinaryIndexedTree};
+
+/// Counts the elements `<= v` in `arr[l..r]` for each query `(l, r, v)`.
+///
+/// The queries are processed offline in ascending order of `v`,
+/// adding the positions of the elements `<= v` to a BIT.
+///
+/// This operation is O((*n* + *q*) log(*n* + *q*)).
+///
+/// # Panics
+///
+/// Panics if a query range is out of `arr`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::offline_rank::offline_rank_queries;
+///
+/// let arr = [3, 1, 4, 1, 5, 9, 2];
+/// let counts = offline_rank_queries(&arr, &[(0, 7, 3), (1, 4, 1), (2, 6, 10), (3, 3, 5)]);
+/// assert_eq!(counts, vec![4, 2, 4, 0]);
+/// ```
+pub fn offline_rank_queries<T: Ord>(arr: &[T], queries: &[(usize, usize, T)]) -> Vec<usize> {
+    let mut order = (0..arr.len()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| arr[i].cmp(&arr[j]));
+    let mut query_order = (0..queries.len()).collect::<Vec<_>>();
+    query_order.sort_by(|&i, &j| queries[i].2.cmp(&queries[j].2));
+
+    let mut bit = BinaryIndexedTree::<AddMonoid<usize>>::with_size(arr.len());
+    let mut ret = vec![0; queries.len()];
+    let mut elements = order.into_iter().peekable();
+    for qi in query_order {
+        let (l, r, ref v) = queries[qi];
+        assert!(l <= r && r <= arr.len());
+        while let Some(&i) = elements.peek() {
+            if &arr[i] > v {
+                break;
+            }
+            bit.operate(i, &1);
+            elements.next();
+        }
+        ret[qi] = bit.fold(..r) - bit.fold(..l);
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..30 {
+            let arr = (0..n).map(|_| rand() % 10).collect::<Vec<_>>();
+            let queries = (0..30)
+                .map(|_| {
+                    let l = rand() % (n + 1);
+                    let r = l + rand() % (n + 1 - l);
+                    (l, r, rand() % 12)
+                })
+                .collect::<Vec<_>>();
+            let expected = queries
+                .iter()
+                .map(|&(l, r, v)| arr[l..r].iter().filter(|&&x| x <= v).count())
+                .collect::<Vec<_>>();
+            assert_eq!(offline_rank_queries(&arr, &queries), expected);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::{AddMonoid, BinaryIndexedTree};
+
+use core::cmp::Ordering::{Greater, Less};
+
+/// Counts the points in the rectangle `[x1, x2] × [y1, y2]` for each query `(x1, x2, y1, y2)`.
+///
+/// The y-coordinates are compressed and the points are added to a BIT in ascending order of x.
+/// Each query is the count of the points with `x <= x2` minus the count of those with `x < x1`,
+/// and the two prefixes are swept separately.
+/// An empty rectangle, i.e. `x1 > x2` or `y1 > y2`, has no points.
+///
+/// This operation is O((*n* + *q*) log(*n* + *q*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::range_count_2d::offline_2d_range_count;
+///
+/// let points = [(1, 1), (2, 3), (3, 2), (3, 3), (5, 0)];
+/// let queries = [(1, 3, 1, 3), (2, 5, 0, 2), (3, 3, 3, 3), (4, 4, 0, 9), (3, 1, 0, 9)];
+/// assert_eq!(offline_2d_range_count(&points, &queries), vec![4, 2, 1, 0, 0]);
+/// ```
+pub fn offline_2d_range_count<T: Ord>(points: &[(T, T)], queries: &[(T, T, T, T)]) -> Vec<usize> {
+    // the distinct y-coordinates in ascending order
+    let mut ys = points.iter().map(|p| &p.1).collect::<Vec<_>>();
+    ys.sort();
+    ys.dedup();
+    // the number of the distinct y-coordinates less than (or equal to) `y`
+    let rank = |y: &T, inclusive: bool| {
+        ys.binary_search_by(|&v| {
+            if v < y || (inclusive && v == y) {
+                Less
+            } else {
+                Greater
+            }
+        })
+        .unwrap_err()
+    };
+
+    let mut order = (0..points.len()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| points[i].0.cmp(&points[j].0));
+    let point_ranks = points.iter().map(|p| rank(&p.1, false)).collect::<Vec<_>>();
+    let y_ranges = queries
+        .iter()
+        .map(|q| (rank(&q.2, false), rank(&q.3, true)))
+        .collect::<Vec<_>>();
+
+    // counts the points with `x < bounds[q]` or `x <= bounds[q]` in the y-range of each query
+    let sweep = |bounds: Vec<&T>, inclusive: bool| {
+        let mut query_order = (0..queries.len()).collect::<Vec<_>>();
+        query_order.sort_by(|&i, &j| bounds[i].cmp(bounds[j]));
+
+        let mut bit = BinaryIndexedTree::<AddMonoid<usize>>::with_size(ys.len());
+        let mut ret = vec![0; queries.len()];
+        let mut it = order.iter().peekable();
+        for qi in query_order {
+            while let Some(&&i) = it.peek() {
+                let x = &points[i].0;
+                if x > bounds[qi] || (!inclusive && x == bounds[qi]) {
+                    break;
+                }
+                bit.operate(point_ranks[i], &1);
+                it.next();
+            }
+            let (lo, hi) = y_ranges[qi];
+            if lo < hi {
+                ret[qi] = bit.fold(..hi) - bit.fold(..lo);
+            }
+        }
+        ret
+    };
+
+    let upper = sweep(queries.iter().map(|q| &q.1).collect(), true);
+    let lower = sweep(queries.iter().map(|q| &q.0).collect(), false);
+    queries
+        .iter()
+        .zip(upper.into_iter().zip(lower))
+        .map(|(q, (u, l))| if q.0 <= q.1 { u - l } else { 0 })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as i64 % 10);
+
+        for n in 0..30 {
+            let points = (0..n).map(|_| (rand(), rand())).collect::<Vec<_>>();
+            let queries = (0..30)
+                .map(|_| (rand(), rand(), rand(), rand()))
+                .collect::<Vec<_>>();
+            let expected = queries
+                .iter()
+                .map(|&(x1, x2, y1, y2)| {
+                    points
+                        .iter()
+                        .filter(|&&(x, y)| x1 <= x && x <= x2 && y1 <= y && y <= y2)
+                        .count()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(offline_2d_range_count(&points, &queries), expected);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+/// Returns `true` if the matrix `f(row, col)` is totally monotone for the row minima:
+/// for any rows `r1 < r2` and columns `c1 < c2`, `f(r1, c1) > f(r1, c2)` implies `f(r2, c1) > f(r2, c2)`.
+///
+/// It is enough to check the adjacent rows, since the implication is transitive.
+/// It is meant for `debug_assert!` before [`smawk`].
+///
+/// This operation is O(*nm*²).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::smawk::is_totally_monotone;
+///
+/// assert!(is_totally_monotone(3, 3, |i, j| (i as i64 - j as i64).pow(2)));
+/// assert!(!is_totally_monotone(2, 2, |i, j| if i + j == 1 { 0 } else { 1 }));
+/// ```
+pub fn is_totally_monotone<F: Fn(usize, usize) -> i64>(n_rows: usize, n_cols: usize, f: F) -> bool {
+    (1..n_rows).all(|r| {
+        (0..n_cols).all(|c1| {
+            (c1 + 1..n_cols).all(|c2| f(r - 1, c1) <= f(r - 1, c2) || f(r, c1) > f(r, c2))
+        })
+    })
+}
+
+/// Returns the column of the leftmost minimum in each row of the totally monotone matrix `f(row, col)`
+/// with the SMAWK algorithm.
+///
+/// # Precondition
+///
+/// The matrix must be totally monotone, which is checked by [`is_totally_monotone`].
+/// Then the leftmost minima are non-decreasing in the rows, even in any submatrix.
+/// It holds for Monge matrices, i.e. `f(i, j) + f(i + 1, j + 1) ≤ f(i, j + 1) + f(i + 1, j)`,
+/// such as the costs satisfying the quadrangle inequality in the DP optimizations.
+///
+/// If the precondition is not satisfied, the result may be wrong.
+///
+/// This operation is O(*n* + *m*) calls of `f`.
+///
+/// # Panics
+///
+/// Panics if there is a row but no column.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::algorithms::smawk::{is_totally_monotone, smawk};
+///
+/// let x = [1_i64, 4, 6, 9];
+/// let y = [0_i64, 3, 5, 7, 10];
+/// let f = |i: usize, j: usize| (x[i] - y[j]).pow(2);
+/// debug_assert!(is_totally_monotone(4, 5, f));
+/// assert_eq!(smawk(4, 5, f), vec![0, 1, 2, 4]);
+/// ```
+pub fn smawk<F: Fn(usize, usize) -> i64>(n_rows: usize, n_cols: usize, f: F) -> Vec<usize> {
+    let mut res = vec![0; n_rows];
+    if n_rows > 0 {
+        assert!(n_cols > 0);
+        let rows = (0..n_rows).collect::<Vec<_>>();
+        let cols = (0..n_cols).collect::<Vec<_>>();
+        smawk_rec(&rows, &cols, &f, &mut res);
+    }
+    res
+}
+
+fn smawk_rec<F: Fn(usize, usize) -> i64>(rows: &[usize], cols: &[usize], f: &F, res: &mut [usize]) {
+    if rows.is_empty() {
+        return;
+    }
+
+    // reduce the columns to at most the number of the rows, keeping the ones which can be a leftmost minimum
+    let mut stack: Vec<usize> = Vec::with_capacity(rows.len());
+    for &c in cols {
+        while let Some(&top) = stack.last() {
+            let r = rows[stack.len() - 1];
+            if f(r, top) <= f(r, c) {
+                break;
+            }
+            stack.pop();
+        }
+        if stack.len() < rows.len() {
+            stack.push(c);
+        }
+    }
+
+    let odd = rows.iter().skip(1).step_by(2).copied().collect::<Vec<_>>();
+    smawk_rec(&odd, &stack, f, res);
+
+    // the minimum of an even row is between the ones of the adjacent odd rows
+    let mut j = 0;
+    for i in (0..rows.len()).step_by(2) {
+        let r = rows[i];
+        let last = match rows.get(i + 1) {
+            Some(&next) => res[next],
+            None => *stack.last().unwrap(),
+        };
+        let mut best = stack[j];
+        let mut best_value = f(r, best);
+        while stack[j] != last {
+            j += 1;
+            let value = f(r, stack[j]);
+            if value < best_value {
+                best = stack[j];
+                best_value = value;
+            }
+        }
+        res[r] = best;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random_monge() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as i64 & 0xff);
+
+        for n in 1..12 {
+            for m in 1..12 {
+                // `f[i + 1][j + 1] - f[i][j + 1] - f[i + 1][j] + f[i][j] ≤ 0`, with many ties
+                let mut f = vec![vec![0; m]; n];
+                for i in 0..n {
+                    for j in 0..m {
+                        f[i][j] = match (i, j) {
+                            (0, _) | (_, 0) => rand() % 8,
+                            _ => f[i - 1][j] + f[i][j - 1] - f[i - 1][j - 1] - rand() % 2,
+                        };
+                    }
+                }
+                let g = |i: usize, j: usize| f[i][j];
+                assert!(is_totally_monotone(n, m, g));
+
+                let expected = (0..n)
+                    .map(|i| (0..m).min_by_key(|&j| (f[i][j], j)).unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(smawk(n, m, g), expected);
+            }
+        }
+        assert!(smawk(0, 0, |_, _| 0).is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+use crate::math::GcdLcm;
+
+#[cfg_attr(nightly, codesnip::entry("Point"))]
+pub mod point;
+#[codesnip::entry("Point")]
+pub use point::Point;
+
+#[cfg_attr(nightly, codesnip::entry("ConvexHull", include("Point", "GcdLcm")))]
+pub mod convex_hull;
+
+#[cfg_attr(nightly, codesnip::entry("EnclosingCircle", include("Point")))]
+pub mod enclosing_circle;
+
+#[cfg_attr(nightly, codesnip::entry("Segment", include("Point")))]
+pub mod segment;
+
+#[cfg_attr(nightly, codesnip::entry("RectangleUnion"))]
+pub mod rectangle_union;
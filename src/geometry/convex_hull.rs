@@ -0,0 +1,374 @@
+use super::{GcdLcm, Point};
+use core::cmp::Ordering;
+
+fn cross(o: (i64, i64), a: (i64, i64), b: (i64, i64)) -> i64 {
+    (Point::from(a) - Point::from(o)).cross(Point::from(b) - Point::from(o))
+}
+
+/// Computes the convex hull with Andrew's monotone chain algorithm.
+///
+/// Returns the indices of the hull points in counterclockwise order,
+/// starting from the point with the smallest `(x, y)`.
+/// If `include_collinear` is `true`, the points on the edges of the hull are also included.
+/// Duplicated points are reported only once.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::convex_hull;
+///
+/// let points = vec![(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)];
+/// assert_eq!(convex_hull(&points, false), vec![0, 1, 3, 4]);
+/// assert_eq!(convex_hull(&points, true), vec![0, 5, 1, 3, 4]);
+/// ```
+pub fn convex_hull(points: &[(i64, i64)], include_collinear: bool) -> Vec<usize> {
+    let mut idx = (0..points.len()).collect::<Vec<_>>();
+    idx.sort_by_key(|&i| (points[i], i));
+    idx.dedup_by_key(|i| points[*i]);
+    if idx.len() <= 2 {
+        return idx;
+    }
+
+    let removable = |o: usize, a: usize, b: usize| {
+        let c = cross(points[o], points[a], points[b]);
+        if include_collinear {
+            c < 0
+        } else {
+            c <= 0
+        }
+    };
+
+    let mut hull: Vec<usize> = Vec::with_capacity(idx.len() * 2);
+    // lower hull
+    for &i in &idx {
+        while hull.len() >= 2 && removable(hull[hull.len() - 2], hull[hull.len() - 1], i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    if hull.len() == idx.len() && include_collinear {
+        // all points are on the lower hull
+        let first = points[idx[0]];
+        let last = points[idx[idx.len() - 1]];
+        if idx.iter().all(|&i| cross(first, last, points[i]) == 0) {
+            return idx;
+        }
+    }
+    // upper hull
+    let lower_len = hull.len();
+    for &i in idx.iter().rev().skip(1) {
+        while hull.len() > lower_len && removable(hull[hull.len() - 2], hull[hull.len() - 1], i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    // the first point is pushed twice
+    hull.pop();
+    hull
+}
+
+/// Computes the convex hull with Graham scan.
+///
+/// The points are sorted by the polar angle around the point with the smallest `(x, y)`,
+/// and scanned keeping only the left turns.
+/// Returns the indices of the hull points in counterclockwise order starting from that point,
+/// which is the same as [`convex_hull`] without the collinear points.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::convex_hull_graham;
+///
+/// let points = vec![(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)];
+/// assert_eq!(convex_hull_graham(&points), vec![0, 1, 3, 4]);
+/// ```
+pub fn convex_hull_graham(points: &[(i64, i64)]) -> Vec<usize> {
+    let mut idx = (0..points.len()).collect::<Vec<_>>();
+    idx.sort_by_key(|&i| (points[i], i));
+    idx.dedup_by_key(|i| points[*i]);
+    if idx.len() <= 2 {
+        return idx;
+    }
+
+    // all the other points are in the half plane on the right of the pivot, so the angles are comparable
+    let o = points[idx[0]];
+    idx[1..].sort_by(|&a, &b| {
+        let (pa, pb) = (points[a], points[b]);
+        match cross(o, pa, pb) {
+            c if c > 0 => Ordering::Less,
+            c if c < 0 => Ordering::Greater,
+            _ => (Point::from(pa) - Point::from(o))
+                .norm_sq()
+                .cmp(&(Point::from(pb) - Point::from(o)).norm_sq()),
+        }
+    });
+
+    let mut hull: Vec<usize> = Vec::with_capacity(idx.len());
+    for &i in &idx {
+        while hull.len() >= 2
+            && cross(
+                points[hull[hull.len() - 2]],
+                points[hull[hull.len() - 1]],
+                points[i],
+            ) <= 0
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull
+}
+
+/// Returns twice the area of the polygon with the shoelace formula.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::convex_hull_area_2x;
+///
+/// assert_eq!(convex_hull_area_2x(&[(0, 0), (2, 0), (0, 1)]), 2);
+/// ```
+pub fn convex_hull_area_2x(hull: &[(i64, i64)]) -> i64 {
+    let n = hull.len();
+    (0..n)
+        .map(|i| Point::from(hull[i]).cross(Point::from(hull[(i + 1) % n])))
+        .sum::<i64>()
+        .abs()
+}
+
+/// Returns the perimeter of the polygon.
+///
+/// A hull of two points is a degenerate polygon, so its perimeter is twice the length of the segment.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::convex_hull_perimeter;
+///
+/// assert_eq!(convex_hull_perimeter(&[(0, 0), (3, 0), (0, 4)]), 12.0);
+/// ```
+pub fn convex_hull_perimeter(hull: &[(i64, i64)]) -> f64 {
+    let n = hull.len();
+    if n <= 1 {
+        return 0.0;
+    }
+    (0..n)
+        .map(|i| (Point::from(hull[(i + 1) % n]) - Point::from(hull[i])).norm_sq() as f64)
+        .map(f64::sqrt)
+        .sum()
+}
+
+/// Returns the number of the lattice points on the boundary of the polygon.
+///
+/// With Pick's theorem, the number of the lattice points strictly inside is `(area_2x - boundary + 2) / 2`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::{convex_hull_area_2x, convex_hull_boundary_points};
+///
+/// let hull = [(0, 0), (4, 0), (0, 4)];
+/// let boundary = convex_hull_boundary_points(&hull);
+/// assert_eq!(boundary, 12);
+/// assert_eq!((convex_hull_area_2x(&hull) - boundary + 2) / 2, 3);
+/// ```
+pub fn convex_hull_boundary_points(hull: &[(i64, i64)]) -> i64 {
+    let n = hull.len();
+    if n <= 1 {
+        return n as i64;
+    }
+    if n == 2 {
+        let (dx, dy) = (hull[1].0 - hull[0].0, hull[1].1 - hull[0].1);
+        return dx.abs().gcd(dy.abs()) + 1;
+    }
+    (0..n)
+        .map(|i| {
+            let (dx, dy) = (
+                hull[(i + 1) % n].0 - hull[i].0,
+                hull[(i + 1) % n].1 - hull[i].1,
+            );
+            dx.abs().gcd(dy.abs())
+        })
+        .sum()
+}
+
+/// Returns `true` if `p` is inside or on the boundary of the convex polygon `hull`.
+///
+/// `hull` must be in counterclockwise order like the result of [`convex_hull`].
+///
+/// This operation is O(log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::convex_hull::is_point_in_convex_hull;
+///
+/// let hull = vec![(0, 0), (4, 0), (4, 4), (0, 4)];
+/// assert!(is_point_in_convex_hull(&hull, (2, 2)));
+/// assert!(is_point_in_convex_hull(&hull, (4, 1)));
+/// assert!(!is_point_in_convex_hull(&hull, (5, 1)));
+/// ```
+pub fn is_point_in_convex_hull(hull: &[(i64, i64)], p: (i64, i64)) -> bool {
+    let n = hull.len();
+    match n {
+        0 => return false,
+        1 => return hull[0] == p,
+        2 => {
+            let (a, b) = (Point::from(hull[0]), Point::from(hull[1]));
+            let p = Point::from(p);
+            return (b - a).cross(p - a) == 0 && (p - a).dot(p - b) <= 0;
+        }
+        _ => {}
+    }
+
+    let o = hull[0];
+    if cross(o, hull[1], p) < 0 || cross(o, hull[n - 1], p) > 0 {
+        return false;
+    }
+    // the last `i` such that `p` is counterclockwise from `hull[i]`
+    let (mut ok, mut ng) = (1, n - 1);
+    while ng - ok > 1 {
+        let mid = (ok + ng) / 2;
+        if cross(o, hull[mid], p) >= 0 {
+            ok = mid;
+        } else {
+            ng = mid;
+        }
+    }
+    cross(hull[ok], hull[ok + 1], p) >= 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_points(seed: &mut u64, n: usize, range: i64) -> Vec<(i64, i64)> {
+        let mut rand = || {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            (*seed % (range as u64)) as i64
+        };
+        (0..n).map(|_| (rand(), rand())).collect()
+    }
+
+    #[test]
+    fn contains_all_points() {
+        let mut seed = 0xdead_beef;
+        for _ in 0..200 {
+            let points = random_points(&mut seed, 30, 10);
+            for &inclusive in &[false, true] {
+                let hull = convex_hull(&points, inclusive)
+                    .into_iter()
+                    .map(|i| points[i])
+                    .collect::<Vec<_>>();
+                let n = hull.len();
+                for i in 0..n {
+                    let c = cross(hull[i], hull[(i + 1) % n], hull[(i + 2) % n]);
+                    if inclusive {
+                        assert!(c >= 0);
+                    } else {
+                        assert!(c > 0, "{:?}", hull);
+                    }
+                }
+                for &p in &points {
+                    assert!(is_point_in_convex_hull(&hull, p));
+                    // every point on the boundary is a vertex of the inclusive hull
+                    let on_boundary = (0..n).any(|i| cross(hull[i], hull[(i + 1) % n], p) == 0);
+                    if inclusive && on_boundary {
+                        assert!(hull.contains(&p));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn point_in_hull_naive() {
+        let mut seed = 0x1357_9bdf;
+        for _ in 0..100 {
+            let points = random_points(&mut seed, 10, 20);
+            let hull = convex_hull(&points, false)
+                .into_iter()
+                .map(|i| points[i])
+                .collect::<Vec<_>>();
+            let n = hull.len();
+            if n < 3 {
+                continue;
+            }
+            for x in -2..22 {
+                for y in -2..22 {
+                    let naive = (0..n).all(|i| cross(hull[i], hull[(i + 1) % n], (x, y)) >= 0);
+                    assert_eq!(is_point_in_convex_hull(&hull, (x, y)), naive);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(convex_hull(&[], false).is_empty());
+        assert_eq!(convex_hull(&[(1, 1), (1, 1)], false), vec![0]);
+        let line = vec![(0, 0), (2, 2), (1, 1), (3, 3)];
+        assert_eq!(convex_hull(&line, false), vec![0, 3]);
+        assert_eq!(convex_hull(&line, true), vec![0, 2, 1, 3]);
+        assert!(is_point_in_convex_hull(&[(0, 0), (3, 3)], (2, 2)));
+        assert!(!is_point_in_convex_hull(&[(0, 0), (3, 3)], (4, 4)));
+        assert_eq!(convex_hull_area_2x(&[(0, 0), (3, 3)]), 0);
+    }
+
+    #[test]
+    fn graham_matches_monotone_chain() {
+        let mut seed = 0x2468_ace0;
+        for n in 0..40 {
+            let points = random_points(&mut seed, n, 8);
+            assert_eq!(convex_hull_graham(&points), convex_hull(&points, false));
+        }
+    }
+
+    #[test]
+    fn perimeter_and_boundary() {
+        let square = [(0, 0), (3, 0), (3, 3), (0, 3)];
+        assert_eq!(convex_hull_perimeter(&square), 12.0);
+        assert_eq!(convex_hull_boundary_points(&square), 12);
+        assert_eq!(convex_hull_perimeter(&[(0, 0), (3, 4)]), 10.0);
+        assert_eq!(convex_hull_boundary_points(&[(0, 0), (3, 3)]), 4);
+
+        // Pick's theorem against counting
+        let mut seed = 0x1234_5678;
+        for _ in 0..50 {
+            let points = random_points(&mut seed, 10, 10);
+            let hull = convex_hull(&points, false)
+                .into_iter()
+                .map(|i| points[i])
+                .collect::<Vec<_>>();
+            if hull.len() < 3 {
+                continue;
+            }
+            let n = hull.len();
+            let (mut inside, mut boundary) = (0, 0);
+            for x in 0..10 {
+                for y in 0..10 {
+                    if (0..n).any(|i| {
+                        let (a, b) = (hull[i], hull[(i + 1) % n]);
+                        cross(a, b, (x, y)) == 0
+                            && a.0.min(b.0) <= x
+                            && x <= a.0.max(b.0)
+                            && a.1.min(b.1) <= y
+                            && y <= a.1.max(b.1)
+                    }) {
+                        boundary += 1;
+                    } else if is_point_in_convex_hull(&hull, (x, y)) {
+                        inside += 1;
+                    }
+                }
+            }
+            assert_eq!(convex_hull_boundary_points(&hull), boundary);
+            assert_eq!(convex_hull_area_2x(&hull), 2 * inside + boundary - 2);
+        }
+    }
+}
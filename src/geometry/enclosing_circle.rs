@@ -0,0 +1,165 @@
+use super::Point;
+
+const EPS: f64 = 1e-9;
+
+/// Returns `true` if `(px, py)` is inside or on the circle centered at `(cx, cy)` with the radius `r`.
+///
+/// A small relative error is allowed.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::enclosing_circle::point_in_circle;
+///
+/// assert!(point_in_circle(0.0, 0.0, 1.0, 0.6, 0.8));
+/// assert!(!point_in_circle(0.0, 0.0, 1.0, 1.0, 1.0));
+/// ```
+pub fn point_in_circle(cx: f64, cy: f64, r: f64, px: f64, py: f64) -> bool {
+    (Point::new(px, py) - Point::new(cx, cy)).norm() <= r + EPS * r.max(1.0)
+}
+
+/// A circle as `(center, radius)`.
+type Circle = (Point<f64>, f64);
+
+fn contains((c, r): Circle, p: Point<f64>) -> bool {
+    point_in_circle(c.x, c.y, r, p.x, p.y)
+}
+
+fn circle2(a: Point<f64>, b: Point<f64>) -> Circle {
+    let c = Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+    (c, (a - c).norm())
+}
+
+fn circle3(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> Circle {
+    let (ab, ac) = (b - a, c - a);
+    let d = 2.0 * ab.cross(ac);
+    if d.abs() < EPS {
+        // collinear: the farthest pair is the diameter
+        let candidates = [circle2(a, b), circle2(a, c), circle2(b, c)];
+        return candidates
+            .iter()
+            .copied()
+            .fold(candidates[0], |x, y| if y.1 > x.1 { y } else { x });
+    }
+    let (nb, nc) = (ab.norm_sq(), ac.norm_sq());
+    let center = Point::new(
+        a.x + (ac.y * nb - ab.y * nc) / d,
+        a.y + (ab.x * nc - ac.x * nb) / d,
+    );
+    (center, (a - center).norm())
+}
+
+/// Computes the smallest circle containing all `points` with Welzl's algorithm,
+/// and returns `(center_x, center_y, radius)`.
+///
+/// The points are shuffled with a fixed seed to avoid the worst case.
+/// The result is `(0.0, 0.0, 0.0)` for no points.
+///
+/// This operation is expected O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::enclosing_circle::minimum_enclosing_circle;
+///
+/// let (x, y, r) = minimum_enclosing_circle(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.5)]);
+/// assert!((x - 1.0).abs() < 1e-9 && y.abs() < 1e-9 && (r - 1.0).abs() < 1e-9);
+///
+/// // equilateral triangle with the circumradius 1
+/// let s = 3_f64.sqrt() / 2.0;
+/// let (x, y, r) = minimum_enclosing_circle(&[(0.0, 1.0), (-s, -0.5), (s, -0.5)]);
+/// assert!(x.abs() < 1e-9 && y.abs() < 1e-9 && (r - 1.0).abs() < 1e-9);
+/// ```
+pub fn minimum_enclosing_circle(points: &[(f64, f64)]) -> (f64, f64, f64) {
+    let mut ps = points.iter().map(|&p| Point::from(p)).collect::<Vec<_>>();
+    if ps.is_empty() {
+        return (0.0, 0.0, 0.0);
+    }
+
+    // Fisher-Yates shuffle with xorshift
+    let mut seed = 88_172_645_463_325_252_u64;
+    for i in (1..ps.len()).rev() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        ps.swap(i, (seed % (i as u64 + 1)) as usize);
+    }
+
+    let mut circle = (ps[0], 0.0);
+    for i in 1..ps.len() {
+        if contains(circle, ps[i]) {
+            continue;
+        }
+        // `ps[i]` is on the boundary
+        circle = (ps[i], 0.0);
+        for j in 0..i {
+            if contains(circle, ps[j]) {
+                continue;
+            }
+            // `ps[i]` and `ps[j]` are on the boundary
+            circle = circle2(ps[i], ps[j]);
+            for k in 0..j {
+                if !contains(circle, ps[k]) {
+                    circle = circle3(ps[i], ps[j], ps[k]);
+                }
+            }
+        }
+    }
+    let (c, r) = circle;
+    (c.x, c.y, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(minimum_enclosing_circle(&[]), (0.0, 0.0, 0.0));
+        assert_eq!(minimum_enclosing_circle(&[(3.0, -2.0)]), (3.0, -2.0, 0.0));
+        let (x, y, r) = minimum_enclosing_circle(&[(0.0, 0.0), (4.0, 2.0)]);
+        assert!((x - 2.0).abs() < 1e-9 && (y - 1.0).abs() < 1e-9);
+        assert!((r - 5_f64.sqrt()).abs() < 1e-9);
+
+        // collinear and duplicated points
+        let points = [(1.0, 1.0), (3.0, 3.0), (2.0, 2.0), (1.0, 1.0), (0.0, 0.0)];
+        let (x, y, r) = minimum_enclosing_circle(&points);
+        assert!((x - 1.5).abs() < 1e-9 && (y - 1.5).abs() < 1e-9);
+        assert!((r - 4.5_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(0x2545_f491_4f6c_dd1d_u64, |seed| {
+            (seed % 2001) as f64 / 100.0 - 10.0
+        });
+
+        for n in 1..40 {
+            let points = (0..n).map(|_| (rand(), rand())).collect::<Vec<_>>();
+            let (x, y, r) = minimum_enclosing_circle(&points);
+            for &(px, py) in &points {
+                assert!(point_in_circle(x, y, r, px, py));
+            }
+
+            // the circle is minimal: no candidate from 2 or 3 points is smaller and contains all
+            let ps = points.iter().map(|&p| Point::from(p)).collect::<Vec<_>>();
+            let mut best: Option<f64> = None;
+            for i in 0..n {
+                for j in i..n {
+                    let mut candidates = vec![circle2(ps[i], ps[j])];
+                    for k in j..n {
+                        candidates.push(circle3(ps[i], ps[j], ps[k]));
+                    }
+                    for c in candidates {
+                        if ps.iter().all(|&p| contains(c, p)) {
+                            best = Some(best.map_or(c.1, |b| b.min(c.1)));
+                        }
+                    }
+                }
+            }
+            let best = best.unwrap();
+            assert!((r - best).abs() < 1e-6, "{} {}", r, best);
+        }
+    }
+}
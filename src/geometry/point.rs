@@ -0,0 +1,177 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point (or a vector) on the 2D plane.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::Point;
+///
+/// let a = Point::new(1, 2);
+/// let b = Point::new(3, -1);
+/// assert_eq!(a + b, Point::new(4, 1));
+/// assert_eq!(a - b, Point::new(-2, 3));
+/// assert_eq!(a * 3, Point::new(3, 6));
+/// assert_eq!(a.dot(b), 1);
+/// assert_eq!(a.cross(b), -7);
+/// assert_eq!(a.norm_sq(), 5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the dot product `self.x * other.x + self.y * other.y`.
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the z-component of the cross product `self.x * other.y - self.y * other.x`.
+    ///
+    /// It is positive if `other` is counterclockwise from `self`.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared norm `x² + y²`.
+    pub fn norm_sq(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl Point<f64> {
+    /// Returns the euclidean norm.
+    pub fn norm(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns the angle from the positive x-axis in `(-π, π]`.
+    pub fn arg(self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Creates a point from the polar coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::geometry::Point;
+    /// use std::f64::consts::PI;
+    ///
+    /// let p = Point::from_polar(2.0, PI / 2.0);
+    /// assert!(p.x.abs() < 1e-9);
+    /// assert!((p.y - 2.0).abs() < 1e-9);
+    /// assert!((p.norm() - 2.0).abs() < 1e-9);
+    /// assert!((p.arg() - PI / 2.0).abs() < 1e-9);
+    /// ```
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ops() {
+        let mut a = Point::new(2_i64, 3);
+        let b: Point<i64> = (5, -7).into();
+        assert_eq!(-a, Point::new(-2, -3));
+        a += b;
+        assert_eq!(a, Point::new(7, -4));
+        a -= b;
+        assert_eq!(<(i64, i64)>::from(a), (2, 3));
+        assert_eq!(a.cross(b), -b.cross(a));
+        assert_eq!(a.cross(a), 0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Point::new(1, -2).to_string(), "(1, -2)");
+    }
+
+    #[test]
+    fn float() {
+        let p = Point::new(3.0, 4.0);
+        assert!((p.norm() - 5.0).abs() < 1e-9);
+        let q = Point::from_polar(p.norm(), p.arg());
+        assert!((q - p).norm() < 1e-9);
+    }
+}
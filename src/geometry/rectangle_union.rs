@@ -0,0 +1,124 @@
+/// A segment tree over the elementary intervals `[ys[i], ys[i + 1])`,
+/// which keeps the total length covered by at least one interval.
+struct CoverTree<'a> {
+    ys: &'a [i64],
+    // the number of intervals covering the whole node, which are not pushed down
+    count: Vec<usize>,
+    covered: Vec<i64>,
+}
+
+impl<'a> CoverTree<'a> {
+    fn new(ys: &'a [i64]) -> Self {
+        let n = ys.len().saturating_sub(1).max(1);
+        Self {
+            ys,
+            count: vec![0; 4 * n],
+            covered: vec![0; 4 * n],
+        }
+    }
+
+    /// Adds `delta` to the cover counts of the elementary intervals in `[l, r)`.
+    fn add(&mut self, i: usize, lo: usize, hi: usize, l: usize, r: usize, delta: isize) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            self.count[i] = (self.count[i] as isize + delta) as usize;
+        } else {
+            let mid = (lo + hi) / 2;
+            self.add(2 * i, lo, mid, l, r, delta);
+            self.add(2 * i + 1, mid, hi, l, r, delta);
+        }
+        self.covered[i] = if self.count[i] > 0 {
+            self.ys[hi] - self.ys[lo]
+        } else if hi - lo == 1 {
+            0
+        } else {
+            self.covered[2 * i] + self.covered[2 * i + 1]
+        };
+    }
+}
+
+/// Computes the area of the union of the rectangles `(x1, y1, x2, y2)`,
+/// where `(x1, y1)` and `(x2, y2)` are opposite corners.
+///
+/// It sweeps the x-axis over the compressed y-coordinates
+/// with a segment tree keeping the covered length.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::rectangle_union::area_union_of_rectangles;
+///
+/// assert_eq!(area_union_of_rectangles(&[(0, 0, 2, 2), (1, 1, 3, 3)]), 7);
+/// assert_eq!(area_union_of_rectangles(&[(0, 0, 4, 1), (3, 4, 0, 0), (5, 5, 5, 9)]), 13);
+/// ```
+pub fn area_union_of_rectangles(rects: &[(i64, i64, i64, i64)]) -> i64 {
+    let rects = rects
+        .iter()
+        .map(|&(x1, y1, x2, y2)| (x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)))
+        .filter(|&(x1, y1, x2, y2)| x1 < x2 && y1 < y2)
+        .collect::<Vec<_>>();
+    let mut ys = rects
+        .iter()
+        .flat_map(|&(_, y1, _, y2)| vec![y1, y2])
+        .collect::<Vec<_>>();
+    ys.sort_unstable();
+    ys.dedup();
+    if ys.len() < 2 {
+        return 0;
+    }
+
+    // (x, y1, y2, delta)
+    let mut events = Vec::with_capacity(2 * rects.len());
+    for &(x1, y1, x2, y2) in &rects {
+        let l = ys.binary_search(&y1).unwrap();
+        let r = ys.binary_search(&y2).unwrap();
+        events.push((x1, l, r, 1));
+        events.push((x2, l, r, -1));
+    }
+    events.sort_unstable();
+
+    let m = ys.len() - 1;
+    let mut tree = CoverTree::new(&ys);
+    let mut area = 0;
+    let mut prev_x = events[0].0;
+    for (x, l, r, delta) in events {
+        area += tree.covered[1] * (x - prev_x);
+        prev_x = x;
+        tree.add(1, 0, m, l, r, delta);
+    }
+    area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| (seed % 11) as i64 - 5);
+
+        for n in 0..10 {
+            for _ in 0..20 {
+                let rects = (0..n)
+                    .map(|_| (rand(), rand(), rand(), rand()))
+                    .collect::<Vec<_>>();
+                let mut expected = 0;
+                for x in -5..5 {
+                    for y in -5..5 {
+                        if rects.iter().any(|&(x1, y1, x2, y2)| {
+                            x1.min(x2) <= x && x < x1.max(x2) && y1.min(y2) <= y && y < y1.max(y2)
+                        }) {
+                            expected += 1;
+                        }
+                    }
+                }
+                assert_eq!(area_union_of_rectangles(&rects), expected, "{:?}", rects);
+            }
+        }
+    }
+}
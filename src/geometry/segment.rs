@@ -0,0 +1,95 @@
+use super::Point;
+
+/// Returns the sign of the cross product of `a - o` and `b - o`.
+fn ccw(o: (i64, i64), a: (i64, i64), b: (i64, i64)) -> i64 {
+    (Point::from(a) - Point::from(o))
+        .cross(Point::from(b) - Point::from(o))
+        .signum()
+}
+
+/// Returns `true` if `p` is in the bounding box of `a` and `b`.
+fn in_box(a: (i64, i64), b: (i64, i64), p: (i64, i64)) -> bool {
+    a.0.min(b.0) <= p.0 && p.0 <= a.0.max(b.0) && a.1.min(b.1) <= p.1 && p.1 <= a.1.max(b.1)
+}
+
+/// Returns `true` if the closed segments `p1p2` and `p3p4` share a point.
+///
+/// Touching at an endpoint and overlapping collinear segments are intersections.
+/// It is exact as long as the cross products do not overflow `i64`.
+///
+/// This operation is O(1).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::geometry::segment::segments_intersect;
+///
+/// assert!(segments_intersect((0, 0), (2, 2), (0, 2), (2, 0)));
+/// assert!(segments_intersect((0, 0), (2, 0), (2, 0), (3, 5)));
+/// assert!(segments_intersect((0, 0), (2, 0), (1, 0), (3, 0)));
+/// assert!(!segments_intersect((0, 0), (1, 0), (2, 0), (3, 0)));
+/// assert!(!segments_intersect((0, 0), (2, 2), (1, 2), (2, 3)));
+/// ```
+pub fn segments_intersect(p1: (i64, i64), p2: (i64, i64), p3: (i64, i64), p4: (i64, i64)) -> bool {
+    let d1 = ccw(p3, p4, p1);
+    let d2 = ccw(p3, p4, p2);
+    let d3 = ccw(p1, p2, p3);
+    let d4 = ccw(p1, p2, p4);
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    (d1 == 0 && in_box(p3, p4, p1))
+        || (d2 == 0 && in_box(p3, p4, p2))
+        || (d3 == 0 && in_box(p1, p2, p3))
+        || (d4 == 0 && in_box(p1, p2, p4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| (seed % 4) as i64);
+
+        // the intersection of two lines through the points in [0, 3]² has the coordinates `x / den`
+        // with `den <= 18`, so it suffices to check such rational points
+        for _ in 0..500 {
+            let p = [
+                (rand(), rand()),
+                (rand(), rand()),
+                (rand(), rand()),
+                (rand(), rand()),
+            ];
+            let on = |a: (i64, i64), b: (i64, i64), x: (i64, i64), den: i64| {
+                // x / den is on the segment ab
+                let (ax, ay, bx, by) = (a.0 * den, a.1 * den, b.0 * den, b.1 * den);
+                (bx - ax) * (x.1 - ay) == (by - ay) * (x.0 - ax)
+                    && ax.min(bx) <= x.0
+                    && x.0 <= ax.max(bx)
+                    && ay.min(by) <= x.1
+                    && x.1 <= ay.max(by)
+            };
+            let mut expected = false;
+            for den in 1..=18 {
+                for x in 0..=3 * den {
+                    for y in 0..=3 * den {
+                        if on(p[0], p[1], (x, y), den) && on(p[2], p[3], (x, y), den) {
+                            expected = true;
+                        }
+                    }
+                }
+                if expected {
+                    break;
+                }
+            }
+            assert_eq!(
+                segments_intersect(p[0], p[1], p[2], p[3]),
+                expected,
+                "{:?}",
+                p
+            );
+        }
+    }
+}
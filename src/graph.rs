@@ -0,0 +1,61 @@
+use crate::math::num::{BoundedAbove, One, Zero};
+use crate::structs::union_find::UnionFind;
+use lca::LCA;
+use tree_dp::{bfs_order, bfs_visit};
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("ShortestPath", include("BoundedAbove", "One", "Zero"))
+)]
+pub mod shortest_path;
+
+#[cfg_attr(nightly, codesnip::entry("Scc"))]
+pub mod scc;
+
+#[cfg_attr(nightly, codesnip::entry("Kruskal", include("UnionFind")))]
+pub mod mst;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("Lca", include("UnionFind", "Zero", "TreeDp"))
+)]
+pub mod lca;
+
+#[cfg_attr(nightly, codesnip::entry("Dinic", include("BoundedAbove", "Zero")))]
+pub mod max_flow;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("MinCostFlow", include("BoundedAbove", "Zero"))
+)]
+pub mod mcmf;
+
+#[cfg_attr(nightly, codesnip::entry("Bipartite"))]
+pub mod bipartite;
+
+#[cfg_attr(nightly, codesnip::entry("Bridges"))]
+pub mod bridges;
+
+#[cfg_attr(nightly, codesnip::entry("Hld", include("TreeDp")))]
+pub mod hld;
+
+#[cfg_attr(nightly, codesnip::entry("CentroidDecomposition", include("TreeDp")))]
+pub mod centroid;
+
+#[cfg_attr(nightly, codesnip::entry("EulerPath"))]
+pub mod euler_path;
+
+#[cfg_attr(nightly, codesnip::entry("Bfs"))]
+pub mod bfs;
+
+#[cfg_attr(nightly, codesnip::entry("EulerTour", include("TreeDp")))]
+pub mod euler_tour;
+
+#[cfg_attr(nightly, codesnip::entry("TreeIsomorphism", include("TreeDp")))]
+pub mod tree_iso;
+
+#[cfg_attr(nightly, codesnip::entry("TreeUtils", include("Lca", "TreeDp")))]
+pub mod tree_utils;
+
+#[cfg_attr(nightly, codesnip::entry("TreeDp"))]
+pub mod tree_dp;
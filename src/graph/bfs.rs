@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+/// Computes the distance from the nearest source to each node with breadth-first search.
+///
+/// `adj` is the adjacency list of the graph with `n` nodes.
+/// Unreachable nodes get `None`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bfs::multi_source_bfs;
+///
+/// // 0 - 1 - 2 - 3 - 4   5
+/// let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3], vec![]];
+/// assert_eq!(
+///     multi_source_bfs(6, &adj, &[0, 4]),
+///     vec![Some(0), Some(1), Some(2), Some(1), Some(0), None]
+/// );
+/// ```
+pub fn multi_source_bfs(n: usize, adj: &[Vec<usize>], sources: &[usize]) -> Vec<Option<usize>> {
+    assert_eq!(adj.len(), n);
+    let mut dist = vec![None; n];
+    let mut queue = VecDeque::new();
+    for &s in sources {
+        if dist[s].is_none() {
+            dist[s] = Some(0);
+            queue.push_back(s);
+        }
+    }
+    while let Some(v) = queue.pop_front() {
+        let d = dist[v].unwrap() + 1;
+        for &u in &adj[v] {
+            if dist[u].is_none() {
+                dist[u] = Some(d);
+                queue.push_back(u);
+            }
+        }
+    }
+    dist
+}
+
+/// Computes the distance from `start` to each node with breadth-first search.
+///
+/// Unreachable nodes get `None`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bfs::single_source_bfs;
+///
+/// let adj = vec![vec![1, 2], vec![3], vec![3], vec![], vec![0]];
+/// assert_eq!(single_source_bfs(5, &adj, 0), vec![Some(0), Some(1), Some(1), Some(2), None]);
+/// ```
+pub fn single_source_bfs(n: usize, adj: &[Vec<usize>], start: usize) -> Vec<Option<usize>> {
+    multi_source_bfs(n, adj, &[start])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn multi_source_is_min_of_single_sources() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 1..20 {
+            let mut adj = vec![vec![]; n];
+            for _ in 0..n {
+                let u = rand() as usize % n;
+                let v = rand() as usize % n;
+                adj[u].push(v);
+            }
+            let sources = (0..n).filter(|_| rand() % 4 == 0).collect::<Vec<_>>();
+            let singles = sources
+                .iter()
+                .map(|&s| single_source_bfs(n, &adj, s))
+                .collect::<Vec<_>>();
+            let expected = (0..n)
+                .map(|v| singles.iter().filter_map(|d| d[v]).min())
+                .collect::<Vec<_>>();
+            assert_eq!(multi_source_bfs(n, &adj, &sources), expected);
+        }
+    }
+
+    #[test]
+    fn no_source() {
+        let adj = vec![vec![1], vec![0]];
+        assert_eq!(multi_source_bfs(2, &adj, &[]), vec![None, None]);
+        assert_eq!(multi_source_bfs(2, &adj, &[1, 1]), vec![Some(1), Some(0)]);
+    }
+}
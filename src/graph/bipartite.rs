@@ -0,0 +1,455 @@
+use std::collections::VecDeque;
+
+/// Computes a maximum matching of a bipartite graph with the Hopcroft-Karp algorithm.
+///
+/// The left side has `n` nodes and the right side has `m` nodes.
+/// `adj[l]` is the list of right nodes adjacent to the left node `l`.
+///
+/// Returns `(matching_size, left_match, right_match)`,
+/// where `left_match[l]` is the right node matched with `l`, and vice versa.
+///
+/// This operation is O(*E*√*V*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::hopcroft_karp;
+///
+/// let adj = vec![vec![0, 1], vec![0], vec![1]];
+/// let (size, left, right) = hopcroft_karp(3, 2, &adj);
+/// assert_eq!(size, 2);
+/// assert_eq!(left[2], Some(1));
+/// assert_eq!(right[1], Some(2));
+/// assert_eq!(left.iter().filter(|x| x.is_some()).count(), 2);
+/// ```
+pub fn hopcroft_karp(
+    n: usize,
+    m: usize,
+    adj: &[Vec<usize>],
+) -> (usize, Vec<Option<usize>>, Vec<Option<usize>>) {
+    const INF: usize = !0;
+
+    assert_eq!(adj.len(), n);
+    assert!(adj.iter().flatten().all(|&r| r < m));
+
+    let mut left_match = vec![None; n];
+    let mut right_match: Vec<Option<usize>> = vec![None; m];
+    let mut dist = vec![INF; n];
+    let mut iter = vec![0; n];
+    let mut size = 0;
+
+    loop {
+        // BFS from the free left nodes builds the layers.
+        let mut que = VecDeque::new();
+        for l in 0..n {
+            if left_match[l].is_none() {
+                dist[l] = 0;
+                que.push_back(l);
+            } else {
+                dist[l] = INF;
+            }
+        }
+        let mut found = false;
+        while let Some(l) = que.pop_front() {
+            for &r in &adj[l] {
+                match right_match[r] {
+                    None => found = true,
+                    Some(l2) if dist[l2] == INF => {
+                        dist[l2] = dist[l] + 1;
+                        que.push_back(l2);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        // DFS along the layers finds vertex-disjoint shortest augmenting paths.
+        for i in iter.iter_mut() {
+            *i = 0;
+        }
+        for s in 0..n {
+            if left_match[s].is_some() {
+                continue;
+            }
+            let mut path = vec![s];
+            while let Some(&l) = path.last() {
+                if iter[l] == adj[l].len() {
+                    // dead end
+                    dist[l] = INF;
+                    path.pop();
+                    continue;
+                }
+                let r = adj[l][iter[l]];
+                iter[l] += 1;
+                match right_match[r] {
+                    None => {
+                        // augment along `path`
+                        let mut r = r;
+                        while let Some(l) = path.pop() {
+                            let prev = left_match[l];
+                            left_match[l] = Some(r);
+                            right_match[r] = Some(l);
+                            if let Some(prev) = prev {
+                                r = prev;
+                            }
+                        }
+                        size += 1;
+                    }
+                    Some(l2) if dist[l2] == dist[l] + 1 => path.push(l2),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    (size, left_match, right_match)
+}
+
+/// Computes a maximum matching of a bipartite graph with Kuhn's algorithm.
+///
+/// The left side has `n` nodes and the right side has `m` nodes.
+/// `adj[l]` is the list of right nodes adjacent to the left node `l`.
+///
+/// Returns `(matching_size, left_match)`, where `left_match[l]` is the right node matched with `l`.
+/// It is simpler than [`hopcroft_karp`] but slower in the worst case.
+///
+/// This operation is O(*VE*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::kuhn_matching;
+///
+/// let adj = vec![vec![0, 1], vec![0], vec![1]];
+/// let (size, left) = kuhn_matching(3, 2, &adj);
+/// assert_eq!(size, 2);
+/// assert_eq!(left[1], Some(0));
+/// assert_eq!(left.iter().filter(|x| x.is_some()).count(), 2);
+/// ```
+pub fn kuhn_matching(n: usize, m: usize, adj: &[Vec<usize>]) -> (usize, Vec<Option<usize>>) {
+    // Tries to find an augmenting path from the left node `l`.
+    fn augment(
+        l: usize,
+        adj: &[Vec<usize>],
+        visited: &mut [bool],
+        right_match: &mut [Option<usize>],
+    ) -> bool {
+        for &r in &adj[l] {
+            if visited[r] {
+                continue;
+            }
+            visited[r] = true;
+            let free = match right_match[r] {
+                None => true,
+                Some(l2) => augment(l2, adj, visited, right_match),
+            };
+            if free {
+                right_match[r] = Some(l);
+                return true;
+            }
+        }
+        false
+    }
+
+    assert_eq!(adj.len(), n);
+    assert!(adj.iter().flatten().all(|&r| r < m));
+    let mut right_match = vec![None; m];
+    let mut visited = vec![false; m];
+    let mut size = 0;
+    for l in 0..n {
+        for v in visited.iter_mut() {
+            *v = false;
+        }
+        if augment(l, adj, &mut visited, &mut right_match) {
+            size += 1;
+        }
+    }
+
+    let mut left_match = vec![None; n];
+    for (r, l) in right_match.into_iter().enumerate() {
+        if let Some(l) = l {
+            left_match[l] = Some(r);
+        }
+    }
+    (size, left_match)
+}
+
+/// Returns the left and right nodes reachable from the free left nodes by alternating paths.
+fn alternating_reachable(
+    n: usize,
+    m: usize,
+    adj: &[Vec<usize>],
+    left_match: &[Option<usize>],
+    right_match: &[Option<usize>],
+) -> (Vec<bool>, Vec<bool>) {
+    let mut left = vec![false; n];
+    let mut right = vec![false; m];
+    let mut stack = (0..n)
+        .filter(|&l| left_match[l].is_none())
+        .collect::<Vec<_>>();
+    for &l in &stack {
+        left[l] = true;
+    }
+    while let Some(l) = stack.pop() {
+        for &r in &adj[l] {
+            if right[r] || left_match[l] == Some(r) {
+                continue;
+            }
+            right[r] = true;
+            if let Some(l2) = right_match[r] {
+                if !left[l2] {
+                    left[l2] = true;
+                    stack.push(l2);
+                }
+            }
+        }
+    }
+    (left, right)
+}
+
+/// Computes a minimum vertex cover from a maximum matching with König's theorem.
+///
+/// `adj[l]` is the list of right nodes adjacent to the left node `l`,
+/// and the matching should be the result of [`hopcroft_karp`] on the same graph.
+/// Returns the left and right nodes in the cover, and its size equals the matching size.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::{hopcroft_karp, minimum_vertex_cover};
+///
+/// let adj = vec![vec![0], vec![0], vec![0, 1]];
+/// let (_, left, right) = hopcroft_karp(3, 2, &adj);
+/// assert_eq!(minimum_vertex_cover(3, 2, &adj, &left, &right), (vec![2], vec![0]));
+/// ```
+pub fn minimum_vertex_cover(
+    n: usize,
+    m: usize,
+    adj: &[Vec<usize>],
+    left_match: &[Option<usize>],
+    right_match: &[Option<usize>],
+) -> (Vec<usize>, Vec<usize>) {
+    let (left, right) = alternating_reachable(n, m, adj, left_match, right_match);
+    (
+        (0..n).filter(|&l| !left[l]).collect(),
+        (0..m).filter(|&r| right[r]).collect(),
+    )
+}
+
+/// Computes a maximum independent set as the complement of [`minimum_vertex_cover`].
+///
+/// `adj[l]` is the list of right nodes adjacent to the left node `l`,
+/// and the matching should be the result of [`hopcroft_karp`] on the same graph.
+/// Returns the left and right nodes in the set.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::{hopcroft_karp, maximum_independent_set};
+///
+/// let adj = vec![vec![0], vec![0], vec![0, 1]];
+/// let (_, left, right) = hopcroft_karp(3, 2, &adj);
+/// assert_eq!(maximum_independent_set(3, 2, &adj, &left, &right), (vec![0, 1], vec![1]));
+/// ```
+pub fn maximum_independent_set(
+    n: usize,
+    m: usize,
+    adj: &[Vec<usize>],
+    left_match: &[Option<usize>],
+    right_match: &[Option<usize>],
+) -> (Vec<usize>, Vec<usize>) {
+    let (left, right) = alternating_reachable(n, m, adj, left_match, right_match);
+    (
+        (0..n).filter(|&l| left[l]).collect(),
+        (0..m).filter(|&r| !right[r]).collect(),
+    )
+}
+
+/// Checks whether an undirected graph is bipartite by 2-coloring it with breadth-first search.
+///
+/// `adj` is the adjacency list containing both directions of each edge.
+/// Returns the colors, where each component starts from `false` at its smallest node,
+/// or `None` if the graph has an odd cycle.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::is_bipartite;
+///
+/// // square and isolated node
+/// let adj = vec![vec![1, 3], vec![0, 2], vec![1, 3], vec![2, 0], vec![]];
+/// assert_eq!(is_bipartite(5, &adj), Some(vec![false, true, false, true, false]));
+///
+/// // triangle
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1]];
+/// assert_eq!(is_bipartite(3, &adj), None);
+/// ```
+pub fn is_bipartite(n: usize, adj: &[Vec<usize>]) -> Option<Vec<bool>> {
+    assert_eq!(adj.len(), n);
+    let mut color = vec![None; n];
+    let mut que = VecDeque::new();
+    for s in 0..n {
+        if color[s].is_some() {
+            continue;
+        }
+        color[s] = Some(false);
+        que.push_back(s);
+        while let Some(v) = que.pop_front() {
+            let c = color[v].unwrap();
+            for &u in &adj[v] {
+                match color[u] {
+                    None => {
+                        color[u] = Some(!c);
+                        que.push_back(u);
+                    }
+                    Some(cu) if cu == c => return None,
+                    _ => {}
+                }
+            }
+        }
+    }
+    Some(color.into_iter().map(Option::unwrap).collect())
+}
+
+/// Splits the nodes of a bipartite graph into two sides with [`is_bipartite`].
+///
+/// Returns `(left, right)` in ascending order, or `None` if the graph is not bipartite.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bipartite::bipartite_components;
+///
+/// let adj = vec![vec![1], vec![0, 2], vec![1], vec![]];
+/// assert_eq!(bipartite_components(4, &adj), Some((vec![0, 2, 3], vec![1])));
+/// ```
+pub fn bipartite_components(n: usize, adj: &[Vec<usize>]) -> Option<(Vec<usize>, Vec<usize>)> {
+    let color = is_bipartite(n, adj)?;
+    Some((0..n).partition(|&v| !color[v]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn brute_force(n: usize, adj: &[Vec<usize>]) -> usize {
+        fn rec(l: usize, n: usize, used: u32, adj: &[Vec<usize>]) -> usize {
+            if l == n {
+                return 0;
+            }
+            let mut best = rec(l + 1, n, used, adj);
+            for &r in &adj[l] {
+                if used >> r & 1 == 0 {
+                    best = best.max(1 + rec(l + 1, n, used | 1 << r, adj));
+                }
+            }
+            best
+        }
+        rec(0, n, 0, adj)
+    }
+
+    #[test]
+    fn random_small() {
+        let mut rand = xorshift(0x9e37_79b9_u64, |seed| seed as usize);
+        for _ in 0..300 {
+            let n = rand() % 7;
+            let m = rand() % 7;
+            let edges = if n == 0 || m == 0 {
+                vec![]
+            } else {
+                (0..rand() % 15)
+                    .map(|_| (rand() % n, rand() % m))
+                    .collect::<Vec<_>>()
+            };
+            let mut adj = vec![vec![]; n];
+            for &(l, r) in &edges {
+                adj[l].push(r);
+            }
+            let (size, left, right) = hopcroft_karp(n, m, &adj);
+            assert_eq!(size, brute_force(n, &adj), "{:?}", edges);
+
+            // the matching is consistent and consists of edges
+            assert_eq!(left.iter().filter(|x| x.is_some()).count(), size);
+            for (l, &r) in left.iter().enumerate() {
+                if let Some(r) = r {
+                    assert_eq!(right[r], Some(l));
+                    assert!(edges.contains(&(l, r)));
+                }
+            }
+
+            // Kuhn's algorithm finds a matching of the same size
+            let (kuhn_size, kuhn_left) = kuhn_matching(n, m, &adj);
+            assert_eq!(kuhn_size, size);
+            assert_eq!(kuhn_left.iter().filter(|x| x.is_some()).count(), size);
+            let mut used = vec![false; m];
+            for (l, &r) in kuhn_left.iter().enumerate() {
+                if let Some(r) = r {
+                    assert!(!used[r]);
+                    used[r] = true;
+                    assert!(adj[l].contains(&r));
+                }
+            }
+
+            // the cover covers all edges
+            let (cl, cr) = minimum_vertex_cover(n, m, &adj, &left, &right);
+            assert_eq!(cl.len() + cr.len(), size);
+            for &(l, r) in &edges {
+                assert!(cl.contains(&l) || cr.contains(&r));
+            }
+
+            // no edges in the independent set
+            let (il, ir) = maximum_independent_set(n, m, &adj, &left, &right);
+            assert_eq!(il.len() + ir.len(), n + m - size);
+            for &(l, r) in &edges {
+                assert!(!(il.contains(&l) && ir.contains(&r)));
+            }
+            assert_eq!(il.len() + ir.len() + cl.len() + cr.len(), n + m);
+        }
+    }
+
+    #[test]
+    fn two_coloring() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..9 {
+            for _ in 0..20 {
+                let mut adj = vec![vec![]; n];
+                let mut edges = vec![];
+                for _ in 0..rand() % (n + 2) {
+                    let (u, v) = (rand() % n, rand() % n);
+                    adj[u].push(v);
+                    adj[v].push(u);
+                    edges.push((u, v));
+                }
+                // brute force over all colorings
+                let expected = (0..1_u32 << n)
+                    .any(|mask| edges.iter().all(|&(u, v)| (mask >> u ^ mask >> v) & 1 == 1));
+                match is_bipartite(n, &adj) {
+                    Some(color) => {
+                        assert!(expected);
+                        assert!(edges.iter().all(|&(u, v)| color[u] != color[v]));
+                        let (l, r) = bipartite_components(n, &adj).unwrap();
+                        assert_eq!(l.len() + r.len(), n);
+                        assert!(l.iter().all(|&v| !color[v]) && r.iter().all(|&v| color[v]));
+                    }
+                    None => {
+                        assert!(!expected);
+                        assert_eq!(bipartite_components(n, &adj), None);
+                    }
+                }
+            }
+        }
+    }
+}
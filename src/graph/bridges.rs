@@ -0,0 +1,472 @@
+/// The results of the low-link DFS.
+struct LowLink {
+    bridges: Vec<(usize, usize)>,
+    articulation: Vec<bool>,
+    components: Vec<Vec<usize>>,
+}
+
+/// Runs a DFS computing the low-links, and collects the bridges,
+/// the articulation points and the biconnected components.
+fn lowlink(n: usize, adj: &[Vec<usize>]) -> LowLink {
+    const NONE: usize = !0;
+    assert_eq!(adj.len(), n);
+
+    let mut ord = vec![NONE; n];
+    let mut low = vec![NONE; n];
+    let mut parent = vec![NONE; n];
+    // whether the edge to the parent is already skipped, to handle multiple edges
+    let mut skipped = vec![false; n];
+    let mut ret = LowLink {
+        bridges: vec![],
+        articulation: vec![false; n],
+        components: vec![],
+    };
+    let mut time = 0;
+    let mut stack = vec![];
+    let mut visited_stack = vec![];
+
+    for root in 0..n {
+        if ord[root] != NONE {
+            continue;
+        }
+        ord[root] = time;
+        low[root] = time;
+        time += 1;
+        stack.push((root, 0));
+        visited_stack.push(root);
+        let mut root_children = 0;
+
+        while let Some(&mut (v, ref mut i)) = stack.last_mut() {
+            if let Some(&u) = adj[v].get(*i) {
+                *i += 1;
+                if u == parent[v] && !skipped[v] {
+                    skipped[v] = true;
+                } else if ord[u] == NONE {
+                    parent[u] = v;
+                    ord[u] = time;
+                    low[u] = time;
+                    time += 1;
+                    stack.push((u, 0));
+                    visited_stack.push(u);
+                } else {
+                    low[v] = low[v].min(ord[u]);
+                }
+                continue;
+            }
+
+            stack.pop();
+            let p = parent[v];
+            if p == NONE {
+                continue;
+            }
+            low[p] = low[p].min(low[v]);
+            if low[v] > ord[p] {
+                ret.bridges.push((p.min(v), p.max(v)));
+            }
+            if low[v] >= ord[p] {
+                if p == root {
+                    root_children += 1;
+                } else {
+                    ret.articulation[p] = true;
+                }
+                // `p` separates the subtree of `v`
+                let mut component = vec![p];
+                while let Some(w) = visited_stack.pop() {
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                ret.components.push(component);
+            }
+        }
+
+        ret.articulation[root] = root_children >= 2;
+        // only the root is left
+        visited_stack.pop();
+        if root_children == 0 {
+            ret.components.push(vec![root]);
+        }
+    }
+    ret
+}
+
+/// Returns the articulation points of an undirected graph in ascending order.
+///
+/// `adj` is the adjacency list containing both directions of each edge.
+/// An articulation point is a node whose removal increases the number of connected components.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bridges::articulation_points;
+///
+/// // triangle 0-1-2 and path 2-3-4
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2, 4], vec![3]];
+/// assert_eq!(articulation_points(5, &adj), vec![2, 3]);
+/// ```
+pub fn articulation_points(n: usize, adj: &[Vec<usize>]) -> Vec<usize> {
+    let articulation = lowlink(n, adj).articulation;
+    (0..n).filter(|&v| articulation[v]).collect()
+}
+
+/// Returns the bridges of an undirected graph as sorted pairs `(u, v)` with `u < v`, in ascending order.
+///
+/// `adj` is the adjacency list containing both directions of each edge.
+/// A bridge is an edge whose removal increases the number of connected components.
+/// Multiple edges between the same nodes are never bridges.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bridges::bridges;
+///
+/// // triangle 0-1-2 and path 2-3-4
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2, 4], vec![3]];
+/// assert_eq!(bridges(5, &adj), vec![(2, 3), (3, 4)]);
+/// ```
+pub fn bridges(n: usize, adj: &[Vec<usize>]) -> Vec<(usize, usize)> {
+    let mut bridges = lowlink(n, adj).bridges;
+    bridges.sort();
+    bridges
+}
+
+/// Returns the biconnected components (blocks) of an undirected graph.
+///
+/// `adj` is the adjacency list containing both directions of each edge.
+/// Each component is a list of nodes, and an articulation point belongs to several components.
+/// An isolated node forms a component by itself.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bridges::biconnected_components;
+///
+/// // triangle 0-1-2 and path 2-3-4
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2, 4], vec![3]];
+/// let mut components = biconnected_components(5, &adj);
+/// for c in components.iter_mut() {
+///     c.sort();
+/// }
+/// components.sort();
+/// assert_eq!(components, vec![vec![0, 1, 2], vec![2, 3], vec![3, 4]]);
+/// ```
+pub fn biconnected_components(n: usize, adj: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    lowlink(n, adj).components
+}
+
+/// Returns the biconnected components of an undirected graph as lists of edges, with Tarjan's algorithm.
+///
+/// `adj[v]` has `(u, id)` for each edge `id` between `v` and `u`, in both directions.
+/// Each edge is reported as `(u, v)` where `u` is visited first by the DFS.
+/// Every edge except self-loops belongs to exactly one component, and self-loops are ignored.
+/// Multiple edges are distinguished by their IDs.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bridges::biconnected_edge_components;
+///
+/// // triangle 0-1-2 and path 2-3
+/// let edges = [(0, 1), (1, 2), (2, 0), (2, 3)];
+/// let mut adj = vec![vec![]; 4];
+/// for (id, &(u, v)) in edges.iter().enumerate() {
+///     adj[u].push((v, id));
+///     adj[v].push((u, id));
+/// }
+/// let mut components = biconnected_edge_components(4, &adj);
+/// for c in components.iter_mut() {
+///     c.sort();
+/// }
+/// components.sort();
+/// assert_eq!(components, vec![vec![(0, 1), (1, 2), (2, 0)], vec![(2, 3)]]);
+/// ```
+pub fn biconnected_edge_components(
+    n: usize,
+    adj: &[Vec<(usize, usize)>],
+) -> Vec<Vec<(usize, usize)>> {
+    const NONE: usize = !0;
+    assert_eq!(adj.len(), n);
+
+    let mut ord = vec![NONE; n];
+    let mut low = vec![NONE; n];
+    // the edge ID to the parent
+    let mut parent_edge = vec![NONE; n];
+    let mut time = 0;
+    let mut components = vec![];
+    let mut edge_stack = vec![];
+
+    for root in 0..n {
+        if ord[root] != NONE {
+            continue;
+        }
+        ord[root] = time;
+        low[root] = time;
+        time += 1;
+        let mut stack = vec![(root, 0)];
+
+        while let Some(&mut (v, ref mut i)) = stack.last_mut() {
+            if let Some(&(u, id)) = adj[v].get(*i) {
+                *i += 1;
+                if id == parent_edge[v] {
+                    continue;
+                }
+                if ord[u] == NONE {
+                    edge_stack.push((v, u));
+                    parent_edge[u] = id;
+                    ord[u] = time;
+                    low[u] = time;
+                    time += 1;
+                    stack.push((u, 0));
+                } else if ord[u] < ord[v] {
+                    // a back edge, which is seen from the descendant first
+                    edge_stack.push((v, u));
+                    low[v] = low[v].min(ord[u]);
+                }
+                continue;
+            }
+
+            stack.pop();
+            if let Some(&(p, _)) = stack.last() {
+                low[p] = low[p].min(low[v]);
+                if low[v] >= ord[p] {
+                    let mut component = vec![];
+                    while let Some(e) = edge_stack.pop() {
+                        component.push(e);
+                        if e == (p, v) {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+    }
+    components
+}
+
+/// Builds the block-cut tree of an undirected graph, and returns `(B, tree)`.
+///
+/// `adj` is the adjacency list containing both directions of each edge.
+/// The node `i < B` of the tree is the `i`-th component of [`biconnected_components`],
+/// and the node `B + j` is the `j`-th articulation point of [`articulation_points`].
+/// A block and an articulation point are adjacent if the block contains the point.
+/// The result is a forest if the graph is not connected.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::bridges::{biconnected_components, block_cut_tree};
+///
+/// // triangle 0-1-2 and path 2-3-4
+/// let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1, 3], vec![2, 4], vec![3]];
+/// let (blocks, tree) = block_cut_tree(5, &adj);
+/// assert_eq!(blocks, 3);
+/// // the articulation points 2 and 3 are the nodes 3 and 4
+/// assert_eq!(tree.len(), 5);
+/// assert_eq!(tree[3].len(), 2);
+/// assert_eq!(tree[4].len(), 2);
+/// let triangle = biconnected_components(5, &adj).iter().position(|c| c.len() == 3).unwrap();
+/// assert_eq!(tree[triangle], vec![3]);
+/// ```
+pub fn block_cut_tree(n: usize, adj: &[Vec<usize>]) -> (usize, Vec<Vec<usize>>) {
+    let LowLink {
+        articulation,
+        components,
+        ..
+    } = lowlink(n, adj);
+    let blocks = components.len();
+    let mut id = vec![!0; n];
+    let mut count = 0;
+    for v in 0..n {
+        if articulation[v] {
+            id[v] = blocks + count;
+            count += 1;
+        }
+    }
+
+    let mut tree = vec![vec![]; blocks + count];
+    for (b, component) in components.iter().enumerate() {
+        for &v in component {
+            if articulation[v] {
+                tree[b].push(id[v]);
+                tree[id[v]].push(b);
+            }
+        }
+    }
+    (blocks, tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn count_components(n: usize, edges: &[(usize, usize)], removed: Option<usize>) -> usize {
+        let mut uf = (0..n).collect::<Vec<_>>();
+        fn find(uf: &mut [usize], x: usize) -> usize {
+            if uf[x] != x {
+                uf[x] = find(uf, uf[x]);
+            }
+            uf[x]
+        }
+        for &(u, v) in edges {
+            if Some(u) != removed && Some(v) != removed {
+                let (a, b) = (find(&mut uf, u), find(&mut uf, v));
+                uf[a] = b;
+            }
+        }
+        (0..n)
+            .filter(|&v| Some(v) != removed && find(&mut uf, v) == v)
+            .count()
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..10 {
+            for _ in 0..30 {
+                let mut adj = vec![vec![]; n];
+                let mut edges = vec![];
+                for _ in 0..rand() % (2 * n) {
+                    let (u, v) = (rand() % n, rand() % n);
+                    if u != v {
+                        adj[u].push(v);
+                        adj[v].push(u);
+                        edges.push((u, v));
+                    }
+                }
+                let base = count_components(n, &edges, None);
+
+                let expected = (0..n)
+                    .filter(|&v| count_components(n, &edges, Some(v)) > base)
+                    .collect::<Vec<_>>();
+                assert_eq!(articulation_points(n, &adj), expected, "{:?}", edges);
+
+                let mut expected = (0..edges.len())
+                    .filter(|&i| {
+                        let mut rest = edges.clone();
+                        rest.remove(i);
+                        count_components(n, &rest, None) > base
+                    })
+                    .map(|i| (edges[i].0.min(edges[i].1), edges[i].0.max(edges[i].1)))
+                    .collect::<Vec<_>>();
+                expected.sort();
+                assert_eq!(bridges(n, &adj), expected, "{:?}", edges);
+
+                // every edge is in exactly one component, and the components share articulation points only
+                let components = biconnected_components(n, &adj);
+                for &(u, v) in &edges {
+                    let count = components
+                        .iter()
+                        .filter(|c| c.contains(&u) && c.contains(&v))
+                        .count();
+                    assert_eq!(count, 1);
+                }
+                let articulation = articulation_points(n, &adj);
+                for v in 0..n {
+                    let count = components.iter().filter(|c| c.contains(&v)).count();
+                    assert_eq!(count > 1, articulation.contains(&v));
+                    assert!(count >= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edge_components_and_block_cut_tree() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..10 {
+            for _ in 0..30 {
+                let mut adj = vec![vec![]; n];
+                let mut adj_id = vec![vec![]; n];
+                let mut edges = vec![];
+                for _ in 0..rand() % (2 * n) {
+                    let (u, v) = (rand() % n, rand() % n);
+                    if u != v {
+                        adj[u].push(v);
+                        adj[v].push(u);
+                        adj_id[u].push((v, edges.len()));
+                        adj_id[v].push((u, edges.len()));
+                        edges.push((u, v));
+                    }
+                }
+
+                // the vertex sets match the vertex components with at least one edge
+                let edge_components = biconnected_edge_components(n, &adj_id);
+                assert_eq!(
+                    edge_components.iter().map(|c| c.len()).sum::<usize>(),
+                    edges.len()
+                );
+                let mut from_edges = edge_components
+                    .iter()
+                    .map(|c| {
+                        let mut vs = c.iter().flat_map(|&(u, v)| vec![u, v]).collect::<Vec<_>>();
+                        vs.sort_unstable();
+                        vs.dedup();
+                        vs
+                    })
+                    .collect::<Vec<_>>();
+                from_edges.sort();
+                let mut expected = biconnected_components(n, &adj)
+                    .into_iter()
+                    .filter(|c| c.len() > 1)
+                    .map(|mut c| {
+                        c.sort_unstable();
+                        c
+                    })
+                    .collect::<Vec<_>>();
+                expected.sort();
+                assert_eq!(from_edges, expected, "{:?}", edges);
+
+                // the block-cut tree is a forest with the same connected components
+                let (blocks, tree) = block_cut_tree(n, &adj);
+                let articulation = articulation_points(n, &adj);
+                assert_eq!(tree.len(), blocks + articulation.len());
+                let tree_edges = tree.iter().map(|a| a.len()).sum::<usize>() / 2;
+                let tree_edge_list = (0..tree.len())
+                    .flat_map(|v| tree[v].iter().map(move |&u| (v, u)))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    tree.len() - tree_edges,
+                    count_components(tree.len(), &tree_edge_list, None)
+                );
+                assert_eq!(
+                    count_components(tree.len(), &tree_edge_list, None),
+                    count_components(n, &edges, None)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn multiple_edges() {
+        let adj = vec![vec![1, 1], vec![0, 0, 2], vec![1]];
+        assert_eq!(bridges(3, &adj), vec![(1, 2)]);
+        assert_eq!(articulation_points(3, &adj), vec![1]);
+
+        let adj = vec![
+            vec![(1, 0), (1, 1)],
+            vec![(0, 0), (0, 1), (2, 2)],
+            vec![(1, 2)],
+        ];
+        let mut components = biconnected_edge_components(3, &adj);
+        for c in components.iter_mut() {
+            c.sort();
+        }
+        components.sort();
+        assert_eq!(components, vec![vec![(0, 1), (1, 0)], vec![(1, 2)]]);
+    }
+}
@@ -0,0 +1,268 @@
+use super::bfs_visit;
+
+/// Centroid decomposition of a tree.
+///
+/// The centroid tree has depth O(log(*n*)),
+/// so the path between any two nodes passes through the centroid of their lowest common ancestor in the centroid tree.
+/// It is used to solve the problems about all paths of a tree in O(*n* log(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::centroid::CentroidDecomposition;
+///
+/// // 0 - 1 - 2 - 3 - 4
+/// let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+/// let cd = CentroidDecomposition::new(&adj);
+/// assert_eq!(cd.root(), Some(2));
+/// assert_eq!(cd.centroid_parent(2), None);
+/// assert_eq!(cd.depth_in_centroid_tree(2), 0);
+/// assert_eq!(cd.depth_in_centroid_tree(4), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CentroidDecomposition {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    // centroids in BFS order of the centroid tree
+    order: Vec<usize>,
+}
+
+impl CentroidDecomposition {
+    /// Decomposes the tree given by the adjacency list.
+    ///
+    /// This operation is O(*n* log(*n*)).
+    pub fn new(adj: &[Vec<usize>]) -> Self {
+        let n = adj.len();
+        let mut parent = vec![None; n];
+        let mut depth = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        let mut removed = vec![false; n];
+        let mut size = vec![0; n];
+        let mut tree_parent = vec![0; n];
+
+        let mut que = std::collections::VecDeque::new();
+        if n > 0 {
+            que.push_back((0, None));
+        }
+        while let Some((start, cpar)) = que.pop_front() {
+            // collect the component
+            let comp = bfs_visit(adj, start, &mut tree_parent, |&u| {
+                if removed[u] {
+                    None
+                } else {
+                    Some(u)
+                }
+            });
+            for &v in comp.iter().rev() {
+                size[v] = 1 + adj[v]
+                    .iter()
+                    .filter(|&&u| !removed[u] && u != tree_parent[v])
+                    .map(|&u| size[u])
+                    .sum::<usize>();
+            }
+
+            // find the centroid
+            let total = comp.len();
+            let centroid = *comp
+                .iter()
+                .find(|&&v| {
+                    let max_child = adj[v]
+                        .iter()
+                        .filter(|&&u| !removed[u] && u != tree_parent[v])
+                        .map(|&u| size[u])
+                        .max()
+                        .unwrap_or(0);
+                    max_child.max(total - size[v]) * 2 <= total
+                })
+                .unwrap();
+
+            removed[centroid] = true;
+            parent[centroid] = cpar;
+            depth[centroid] = cpar.map_or(0, |p| depth[p] + 1);
+            order.push(centroid);
+            for &u in &adj[centroid] {
+                if !removed[u] {
+                    que.push_back((u, Some(centroid)));
+                }
+            }
+        }
+
+        Self {
+            parent,
+            depth,
+            order,
+        }
+    }
+
+    /// Returns the root of the centroid tree, or `None` if the tree is empty.
+    pub fn root(&self) -> Option<usize> {
+        self.order.first().copied()
+    }
+
+    /// Returns the parent of `v` in the centroid tree.
+    pub fn centroid_parent(&self, v: usize) -> Option<usize> {
+        self.parent[v]
+    }
+
+    /// Returns the depth of `v` in the centroid tree. The depth of the root is `0`.
+    pub fn depth_in_centroid_tree(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// Calls `process_centroid` for each centroid and folds the results with `combine`.
+    ///
+    /// `process_centroid(c, removed)` handles the component whose centroid is `c`.
+    /// `removed[v]` is `true` if `v` is outside of the component of `c`,
+    /// so the component consists of the nodes reachable from `c` without passing through the removed nodes.
+    /// Since the total size of the components is O(*n* log(*n*)),
+    /// the whole process is O(*n* log(*n*)) if `process_centroid` is linear in the component size.
+    ///
+    /// Returns `None` if the tree is empty.
+    ///
+    /// # Examples
+    ///
+    /// Count the pairs of nodes with distance `2`.
+    ///
+    /// ```
+    /// use lib_rust::graph::centroid::CentroidDecomposition;
+    ///
+    /// //     0
+    /// //   / | \
+    /// //  1  2  3
+    /// //  |
+    /// //  4
+    /// let adj = vec![vec![1, 2, 3], vec![0, 4], vec![0], vec![0], vec![1]];
+    /// let cd = CentroidDecomposition::new(&adj);
+    ///
+    /// let count = cd.solve_with_centroid(
+    ///     |c, removed| {
+    ///         // `cnt[d]` is the number of nodes with distance `d` from `c` in the component
+    ///         let mut total = [0_usize; 3];
+    ///         let mut pairs = 0;
+    ///         total[0] = 1;
+    ///         for &s in &adj[c] {
+    ///             if removed[s] {
+    ///                 continue;
+    ///             }
+    ///             let mut cnt = [0_usize; 3];
+    ///             let mut stack = vec![(s, c, 1)];
+    ///             while let Some((v, p, d)) = stack.pop() {
+    ///                 cnt[d] += 1;
+    ///                 for &u in &adj[v] {
+    ///                     if u != p && !removed[u] && d < 2 {
+    ///                         stack.push((u, v, d + 1));
+    ///                     }
+    ///                 }
+    ///             }
+    ///             // pairs through `c` between this subtree and the previous ones
+    ///             pairs += (0..=2).map(|d| cnt[d] * total[2 - d]).sum::<usize>();
+    ///             for d in 0..3 {
+    ///                 total[d] += cnt[d];
+    ///             }
+    ///         }
+    ///         pairs
+    ///     },
+    ///     |a, b| a + b,
+    /// );
+    /// // (1, 2), (1, 3), (2, 3), (0, 4)
+    /// assert_eq!(count, Some(4));
+    /// ```
+    pub fn solve_with_centroid<T, F, G>(&self, mut process_centroid: F, mut combine: G) -> Option<T>
+    where
+        F: FnMut(usize, &[bool]) -> T,
+        G: FnMut(T, T) -> T,
+    {
+        let mut removed = vec![false; self.depth.len()];
+        let mut acc: Option<T> = None;
+        let mut i = 0;
+        while i < self.order.len() {
+            // the centroids of the same depth have disjoint components
+            let d = self.depth[self.order[i]];
+            let mut k = i;
+            while k < self.order.len() && self.depth[self.order[k]] == d {
+                let res = process_centroid(self.order[k], &removed);
+                acc = Some(match acc {
+                    Some(acc) => combine(acc, res),
+                    None => res,
+                });
+                k += 1;
+            }
+            for &c in &self.order[i..k] {
+                removed[c] = true;
+            }
+            i = k;
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random_tree() {
+        let mut rand = xorshift(0x8765_4321_u64, |seed| seed as usize);
+        for &n in &[1, 2, 3, 10, 100, 1000] {
+            let mut adj = vec![vec![]; n];
+            for v in 1..n {
+                let p = rand() % v;
+                adj[v].push(p);
+                adj[p].push(v);
+            }
+            let cd = CentroidDecomposition::new(&adj);
+
+            // depth is O(log n)
+            let max_depth = (0..n).map(|v| cd.depth_in_centroid_tree(v)).max().unwrap();
+            assert!(1 << max_depth <= n);
+
+            // every component size is at most a half of the parent component
+            let sizes = cd
+                .solve_with_centroid(
+                    |c, removed| {
+                        let mut size = 0;
+                        let mut stack = vec![(c, c)];
+                        while let Some((v, p)) = stack.pop() {
+                            size += 1;
+                            for &u in &adj[v] {
+                                if u != p && !removed[u] {
+                                    stack.push((u, v));
+                                }
+                            }
+                        }
+                        vec![(c, size)]
+                    },
+                    |mut a, b| {
+                        a.extend(b);
+                        a
+                    },
+                )
+                .unwrap();
+            assert_eq!(sizes.len(), n);
+            let mut size_of = vec![0; n];
+            for &(c, s) in &sizes {
+                size_of[c] = s;
+            }
+            for v in 0..n {
+                match cd.centroid_parent(v) {
+                    Some(p) => {
+                        assert_eq!(
+                            cd.depth_in_centroid_tree(v),
+                            cd.depth_in_centroid_tree(p) + 1
+                        );
+                        assert!(size_of[v] * 2 <= size_of[p]);
+                    }
+                    None => assert_eq!(size_of[v], n),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let cd = CentroidDecomposition::new(&[]);
+        assert_eq!(cd.root(), None);
+        assert_eq!(cd.solve_with_centroid(|c, _| c, |a, b| a + b), None);
+    }
+}
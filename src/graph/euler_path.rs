@@ -0,0 +1,243 @@
+/// Walks all the edges reachable from `start` with Hierholzer's algorithm and returns the visited nodes.
+///
+/// An edge ID can appear twice for undirected graphs, and it is used only once.
+fn hierholzer(adj: &[Vec<(usize, usize)>], start: usize) -> Vec<usize> {
+    let num_ids = adj
+        .iter()
+        .flatten()
+        .map(|&(_, id)| id + 1)
+        .max()
+        .unwrap_or(0);
+    let mut used = vec![false; num_ids];
+    let mut iter = vec![0; adj.len()];
+    let mut path = vec![];
+    let mut stack = vec![start];
+    while let Some(&v) = stack.last() {
+        // skip the used edges
+        while iter[v] < adj[v].len() && used[adj[v][iter[v]].1] {
+            iter[v] += 1;
+        }
+        if let Some(&(to, id)) = adj[v].get(iter[v]) {
+            used[id] = true;
+            stack.push(to);
+        } else {
+            path.push(v);
+            stack.pop();
+        }
+    }
+    path.reverse();
+    path
+}
+
+/// Finds an Eulerian path of a directed graph with Hierholzer's algorithm.
+///
+/// `adj[v]` is a list of `(to, edge_id)`, where the edge IDs are distinct.
+/// Returns the nodes of a path which uses every edge exactly once,
+/// or `None` if there is no such path.
+/// If there is an Eulerian circuit, the path starts and ends at the same node.
+/// If there is no edge, it returns the path which consists of the node `0`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::euler_path::eulerian_path;
+///
+/// // 0 -> 1 -> 2 -> 0 -> 3
+/// let adj = vec![vec![(1, 0), (3, 3)], vec![(2, 1)], vec![(0, 2)], vec![]];
+/// assert_eq!(eulerian_path(4, &adj), Some(vec![0, 1, 2, 0, 3]));
+///
+/// let adj = vec![vec![(1, 0), (2, 1)], vec![], vec![]];
+/// assert_eq!(eulerian_path(3, &adj), None);
+/// ```
+pub fn eulerian_path(n: usize, adj: &[Vec<(usize, usize)>]) -> Option<Vec<usize>> {
+    assert_eq!(n, adj.len());
+    if n == 0 {
+        return Some(vec![]);
+    }
+
+    // out-degree minus in-degree
+    let mut diff = vec![0_i64; n];
+    let mut num_edges = 0;
+    for (v, edges) in adj.iter().enumerate() {
+        for &(to, _) in edges {
+            diff[v] += 1;
+            diff[to] -= 1;
+            num_edges += 1;
+        }
+    }
+
+    let mut start = (0..n).find(|&v| !adj[v].is_empty()).unwrap_or(0);
+    let mut num_starts = 0;
+    for (v, &d) in diff.iter().enumerate() {
+        match d {
+            0 | -1 => {}
+            1 => {
+                start = v;
+                num_starts += 1;
+            }
+            _ => return None,
+        }
+    }
+    if num_starts > 1 {
+        return None;
+    }
+
+    let path = hierholzer(adj, start);
+    // All the edges must be reachable.
+    if path.len() == num_edges + 1 {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Finds an Eulerian path of an undirected graph with Hierholzer's algorithm.
+///
+/// `adj[v]` is a list of `(to, edge_id)`.
+/// Each edge appears in the lists of both endpoints with the same ID,
+/// and a self-loop appears twice in the list of its node.
+/// Returns the nodes of a path which uses every edge exactly once,
+/// or `None` if there is no such path.
+/// If there is no edge, it returns the path which consists of the node `0`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::euler_path::eulerian_path_undirected;
+///
+/// // edges: 0-1, 1-2, 2-0, 2-3
+/// let adj = vec![
+///     vec![(1, 0), (2, 2)],
+///     vec![(0, 0), (2, 1)],
+///     vec![(1, 1), (0, 2), (3, 3)],
+///     vec![(2, 3)],
+/// ];
+/// let path = eulerian_path_undirected(4, &adj).unwrap();
+/// assert_eq!(path.len(), 5);
+/// assert!(path[0] == 3 || path[0] == 2);
+/// ```
+pub fn eulerian_path_undirected(n: usize, adj: &[Vec<(usize, usize)>]) -> Option<Vec<usize>> {
+    assert_eq!(n, adj.len());
+    if n == 0 {
+        return Some(vec![]);
+    }
+
+    let odd = (0..n)
+        .filter(|&v| adj[v].len() % 2 == 1)
+        .collect::<Vec<_>>();
+    let start = match odd.len() {
+        0 => (0..n).find(|&v| !adj[v].is_empty()).unwrap_or(0),
+        2 => odd[0],
+        _ => return None,
+    };
+    let num_edges = adj.iter().map(Vec::len).sum::<usize>() / 2;
+
+    let path = hierholzer(adj, start);
+    if path.len() == num_edges + 1 {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if a directed graph has an Eulerian circuit.
+///
+/// See [`eulerian_path`] for the format of `adj`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::euler_path::has_eulerian_circuit;
+///
+/// let adj = vec![vec![(1, 0)], vec![(0, 1)]];
+/// assert!(has_eulerian_circuit(2, &adj));
+/// let adj = vec![vec![(1, 0)], vec![]];
+/// assert!(!has_eulerian_circuit(2, &adj));
+/// ```
+pub fn has_eulerian_circuit(n: usize, adj: &[Vec<(usize, usize)>]) -> bool {
+    matches!(eulerian_path(n, adj), Some(path) if path.first() == path.last())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+        let mut adj = vec![vec![]; n];
+        for (id, &(u, v)) in edges.iter().enumerate() {
+            adj[u].push((v, id));
+        }
+        adj
+    }
+
+    fn undirected(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+        let mut adj = vec![vec![]; n];
+        for (id, &(u, v)) in edges.iter().enumerate() {
+            adj[u].push((v, id));
+            adj[v].push((u, id));
+        }
+        adj
+    }
+
+    // Checks that `path` uses every edge exactly once.
+    fn is_valid(path: &[usize], edges: &[(usize, usize)], directed: bool) -> bool {
+        let mut rest = edges.to_vec();
+        for w in path.windows(2) {
+            let pos = rest.iter().position(|&(u, v)| {
+                (u, v) == (w[0], w[1]) || (!directed && (v, u) == (w[0], w[1]))
+            });
+            match pos {
+                Some(i) => {
+                    rest.swap_remove(i);
+                }
+                None => return false,
+            }
+        }
+        rest.is_empty()
+    }
+
+    #[test]
+    fn directed_graphs() {
+        // multi-edges and a self-loop
+        let edges = vec![(0, 1), (1, 0), (0, 1), (1, 1), (1, 2)];
+        let path = eulerian_path(3, &directed(3, &edges)).unwrap();
+        assert_eq!(path[0], 0);
+        assert!(is_valid(&path, &edges, true));
+        assert!(!has_eulerian_circuit(3, &directed(3, &edges)));
+
+        let edges = vec![(0, 1), (1, 2), (2, 0), (0, 2), (2, 0)];
+        let path = eulerian_path(3, &directed(3, &edges)).unwrap();
+        assert_eq!(path.first(), path.last());
+        assert!(is_valid(&path, &edges, true));
+        assert!(has_eulerian_circuit(3, &directed(3, &edges)));
+
+        // disconnected
+        let edges = vec![(0, 1), (1, 0), (2, 3), (3, 2)];
+        assert_eq!(eulerian_path(4, &directed(4, &edges)), None);
+
+        // no edges
+        assert_eq!(eulerian_path(2, &directed(2, &[])), Some(vec![0]));
+        assert!(has_eulerian_circuit(2, &directed(2, &[])));
+    }
+
+    #[test]
+    fn undirected_graphs() {
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 1)];
+        let path = eulerian_path_undirected(4, &undirected(4, &edges)).unwrap();
+        assert!(path[0] == 0 || path[0] == 2);
+        assert!(is_valid(&path, &edges, false));
+
+        // four odd nodes
+        let edges = vec![(0, 1), (2, 3)];
+        assert_eq!(eulerian_path_undirected(4, &undirected(4, &edges)), None);
+        // disconnected
+        let edges = vec![(0, 1), (1, 0), (2, 3), (3, 2)];
+        assert_eq!(eulerian_path_undirected(4, &undirected(4, &edges)), None);
+    }
+}
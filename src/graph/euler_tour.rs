@@ -0,0 +1,97 @@
+use super::bfs_order;
+
+/// Traverses the tree rooted at `root` in DFS preorder, and returns `(order, tin, tout)`.
+///
+/// `order[t]` is the node visited at the time `t`, `tin[v]` is the time when `v` is entered,
+/// and `tout[v]` is the last time in the subtree of `v`.
+/// Hence the subtree of `v` is `order[tin[v]..=tout[v]]`,
+/// and a subtree query becomes a range query on the array indexed by `tin`.
+///
+/// The children are visited in the order of `adj`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::euler_tour::euler_tour;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// let (order, tin, tout) = euler_tour(&adj, 0);
+/// assert_eq!(order, vec![0, 1, 3, 4, 2]);
+/// assert_eq!(tin, vec![0, 1, 4, 2, 3]);
+/// assert_eq!(tout, vec![4, 3, 4, 2, 3]);
+/// assert_eq!(&order[tin[1]..=tout[1]], &[1, 3, 4]);
+/// ```
+pub fn euler_tour(adj: &[Vec<usize>], root: usize) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+    let n = adj.len();
+    assert!(root < n);
+
+    let (bfs, parent) = bfs_order(adj, root, |&u| u);
+    let mut size = vec![1; n];
+    for &v in bfs.iter().rev() {
+        if v != root {
+            size[parent[v]] += size[v];
+        }
+    }
+
+    // the children of `v` are entered one after another from `tin[v] + 1`
+    let mut tin = vec![0; n];
+    let mut order = vec![root; n];
+    for &v in &bfs {
+        order[tin[v]] = v;
+        let mut t = tin[v] + 1;
+        for &u in &adj[v] {
+            if u != parent[v] {
+                tin[u] = t;
+                t += size[u];
+            }
+        }
+    }
+    // the subtree of `v` ends at `tin[v] + size[v] - 1`
+    let tout = (0..n).map(|v| tin[v] + size[v] - 1).collect();
+    (order, tin, tout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..30 {
+            let mut adj = vec![vec![]; n];
+            let mut parent = vec![None; n];
+            for v in 1..n {
+                let p = rand() % v;
+                adj[p].push(v);
+                adj[v].push(p);
+                parent[v] = Some(p);
+            }
+            let (order, tin, tout) = euler_tour(&adj, 0);
+            for v in 0..n {
+                assert_eq!(order[tin[v]], v);
+                // `u` is in the subtree of `v` iff `v` is an ancestor of `u`
+                for u in 0..n {
+                    let mut w = Some(u);
+                    while w.is_some() && w != Some(v) {
+                        w = parent[w.unwrap()];
+                    }
+                    assert_eq!(tin[v] <= tin[u] && tin[u] <= tout[v], w == Some(v));
+                }
+            }
+        }
+    }
+}
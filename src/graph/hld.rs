@@ -0,0 +1,247 @@
+use super::bfs_order;
+
+/// Heavy-light decomposition of a rooted tree.
+///
+/// Each node is assigned an index in `0..n` so that every heavy path and every subtree is contiguous.
+/// A path query is split into O(log(*n*)) intervals of the indices,
+/// which can be folded by a range query structure such as `SegmentTree`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::hld::HLD;
+/// use lib_rust::structs::segment_tree::types::AddSegTree;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// let value = vec![1, 2, 4, 8, 16];
+///
+/// let hld = HLD::new(&adj, 0);
+/// let mut seg = AddSegTree::new(5);
+/// for v in 0..5 {
+///     seg.set(hld.index(v), value[v]);
+/// }
+///
+/// // sum on the path `3 - 1 - 0 - 2`
+/// let sum: i32 = hld
+///     .path_intervals(3, 2)
+///     .into_iter()
+///     .map(|(l, r)| seg.query(l..r))
+///     .sum();
+/// assert_eq!(sum, 15);
+///
+/// // sum on the subtree of `1`
+/// let (l, r) = hld.subtree_interval(1);
+/// assert_eq!(seg.query(l..r), 26);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HLD {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    head: Vec<usize>,
+    index: Vec<usize>,
+}
+
+impl HLD {
+    /// Decomposes the tree rooted at `root`.
+    ///
+    /// This operation is O(*n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not a connected tree.
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n);
+
+        // first pass: parents, depths and subtree sizes
+        let (order, mut parent) = bfs_order(adj, root, |&u| u);
+        parent[root] = root;
+        let mut depth = vec![0; n];
+        for &v in &order[1..] {
+            depth[v] = depth[parent[v]] + 1;
+        }
+        let mut size = vec![1; n];
+        let mut heavy = vec![None; n];
+        for &v in order.iter().rev() {
+            if v != root {
+                size[parent[v]] += size[v];
+            }
+        }
+        for &v in order.iter().rev() {
+            if v != root {
+                let p = parent[v];
+                match heavy[p] {
+                    Some(h) if size[h] >= size[v] => {}
+                    _ => heavy[p] = Some(v),
+                }
+            }
+        }
+
+        // second pass: assign indices visiting the heavy child first
+        let mut head = vec![root; n];
+        let mut index = vec![0; n];
+        let mut next = 0;
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            index[v] = next;
+            next += 1;
+            for &u in &adj[v] {
+                if u != parent[v] && Some(u) != heavy[v] {
+                    head[u] = u;
+                    stack.push(u);
+                }
+            }
+            if let Some(h) = heavy[v] {
+                head[h] = head[v];
+                stack.push(h);
+            }
+        }
+
+        Self {
+            parent,
+            depth,
+            size,
+            head,
+            index,
+        }
+    }
+
+    /// Returns the index of `v` in the decomposed order.
+    pub fn index(&self, v: usize) -> usize {
+        self.index[v]
+    }
+
+    /// Returns the intervals `[l, r)` of indices covering the path between `u` and `v`.
+    ///
+    /// The number of intervals is O(log(*n*)), and they are not sorted by the path order.
+    pub fn path_intervals(&self, mut u: usize, mut v: usize) -> Vec<(usize, usize)> {
+        let mut ret = vec![];
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                core::mem::swap(&mut u, &mut v);
+            }
+            ret.push((self.index[self.head[u]], self.index[u] + 1));
+            u = self.parent[self.head[u]];
+        }
+        let (l, r) = if self.index[u] <= self.index[v] {
+            (self.index[u], self.index[v])
+        } else {
+            (self.index[v], self.index[u])
+        };
+        ret.push((l, r + 1));
+        ret
+    }
+
+    /// Returns the interval `[l, r)` of indices covering the subtree of `v`.
+    pub fn subtree_interval(&self, v: usize) -> (usize, usize) {
+        (self.index[v], self.index[v] + self.size[v])
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    ///
+    /// This operation is O(log(*n*)).
+    pub fn path_lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                core::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]];
+        }
+        if self.depth[u] <= self.depth[v] {
+            u
+        } else {
+            v
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn random_tree(n: usize, seed: u64) -> (Vec<usize>, Vec<Vec<usize>>) {
+        let mut rand = xorshift(seed, |seed| seed as usize);
+        let mut parent = vec![0; n];
+        let mut adj = vec![vec![]; n];
+        for v in 1..n {
+            let p = rand() % v;
+            parent[v] = p;
+            adj[v].push(p);
+            adj[p].push(v);
+        }
+        (parent, adj)
+    }
+
+    fn path(parent: &[usize], mut u: usize, mut v: usize, depth: &[usize]) -> Vec<usize> {
+        let mut ret = vec![];
+        while depth[u] > depth[v] {
+            ret.push(u);
+            u = parent[u];
+        }
+        while depth[v] > depth[u] {
+            ret.push(v);
+            v = parent[v];
+        }
+        while u != v {
+            ret.push(u);
+            ret.push(v);
+            u = parent[u];
+            v = parent[v];
+        }
+        ret.push(u);
+        ret.sort();
+        ret
+    }
+
+    #[test]
+    fn path_and_subtree() {
+        let n = 100;
+        let (parent, adj) = random_tree(n, 0x1234_5678);
+        let hld = HLD::new(&adj, 0);
+
+        let mut depth = vec![0; n];
+        for v in 1..n {
+            depth[v] = depth[parent[v]] + 1;
+        }
+
+        // indices are a permutation
+        let mut idx = (0..n).map(|v| hld.index(v)).collect::<Vec<_>>();
+        idx.sort();
+        assert_eq!(idx, (0..n).collect::<Vec<_>>());
+
+        for u in 0..n {
+            for v in 0..n {
+                let expected = path(&parent, u, v, &depth);
+                let mut nodes = hld
+                    .path_intervals(u, v)
+                    .into_iter()
+                    .flat_map(|(l, r)| l..r)
+                    .map(|i| (0..n).find(|&x| hld.index(x) == i).unwrap())
+                    .collect::<Vec<_>>();
+                nodes.sort();
+                assert_eq!(nodes, expected, "{} {}", u, v);
+
+                let lca = expected.iter().min_by_key(|&&x| depth[x]).unwrap();
+                assert_eq!(hld.path_lca(u, v), *lca);
+            }
+        }
+
+        for v in 0..n {
+            let (l, r) = hld.subtree_interval(v);
+            for x in 0..n {
+                let mut y = x;
+                while y != v && y != 0 {
+                    y = parent[y];
+                }
+                assert_eq!((l..r).contains(&hld.index(x)), y == v);
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use super::{bfs_order, UnionFind, Zero};
+use core::ops::{Add, Sub};
+
+/// Lowest common ancestor on a rooted tree with binary lifting.
+///
+/// # Time complexity
+///
+/// | Algorithm           | Worst case        |
+/// | ------------------- | ----------------- |
+/// | [`new`]             | O(*n* log(*n*))   |
+/// | [`lca`]             | O(log(*n*))       |
+/// | [`dist`]            | O(log(*n*))       |
+/// | [`weighted_depths`] | O(*n*)            |
+/// | [`weighted_dist`]   | O(log(*n*))       |
+///
+/// [`new`]: LCA::new
+/// [`lca`]: LCA::lca
+/// [`dist`]: LCA::dist
+/// [`weighted_depths`]: LCA::weighted_depths
+/// [`weighted_dist`]: LCA::weighted_dist
+#[derive(Clone, Debug)]
+pub struct LCA {
+    depth: Vec<usize>,
+    ancestor: Vec<Vec<usize>>,
+    // the nodes in BFS order, where each parent comes before its children
+    order: Vec<usize>,
+}
+
+impl LCA {
+    const LOG: usize = 20;
+
+    /// Creates a table from the adjacency list of a tree rooted at `root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not a connected tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::graph::lca::LCA;
+    ///
+    /// //     0
+    /// //    / \
+    /// //   1   2
+    /// //  / \
+    /// // 3   4
+    /// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+    /// let lca = LCA::new(&adj, 0);
+    /// assert_eq!(lca.lca(3, 4), 1);
+    /// assert_eq!(lca.lca(3, 2), 0);
+    /// assert_eq!(lca.dist(3, 2), 3);
+    /// ```
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n);
+        let (order, mut parent) = bfs_order(adj, root, |&u| u);
+        parent[root] = root;
+        let mut depth = vec![0; n];
+        for &v in &order[1..] {
+            depth[v] = depth[parent[v]] + 1;
+        }
+
+        let mut ancestor = Vec::with_capacity(Self::LOG);
+        ancestor.push(parent);
+        for k in 1..Self::LOG {
+            let prev = &ancestor[k - 1];
+            let next = (0..n).map(|v| prev[prev[v]]).collect();
+            ancestor.push(next);
+        }
+
+        Self {
+            depth,
+            ancestor,
+            order,
+        }
+    }
+
+    /// Returns the depth of `v`. The depth of the root is `0`.
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// Returns the parent of `v`, or `None` if `v` is the root.
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        if self.depth[v] == 0 {
+            None
+        } else {
+            Some(self.ancestor[0][v])
+        }
+    }
+
+    /// Returns the `k`-th ancestor of `v`, or `None` if it is above the root.
+    pub fn kth_ancestor(&self, mut v: usize, k: usize) -> Option<usize> {
+        if k > self.depth[v] {
+            return None;
+        }
+        for i in 0..Self::LOG {
+            if (k >> i) & 1 == 1 {
+                v = self.ancestor[i][v];
+            }
+        }
+        Some(v)
+    }
+
+    /// Returns the lowest common ancestor of `u` and `v`.
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (u, v) = if self.depth[u] >= self.depth[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        let mut u = self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        let mut v = v;
+        if u == v {
+            return u;
+        }
+        for k in (0..Self::LOG).rev() {
+            if self.ancestor[k][u] != self.ancestor[k][v] {
+                u = self.ancestor[k][u];
+                v = self.ancestor[k][v];
+            }
+        }
+        self.ancestor[0][u]
+    }
+
+    /// Returns the number of edges on the path between `u` and `v`.
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        let w = self.lca(u, v);
+        self.depth[u] + self.depth[v] - 2 * self.depth[w]
+    }
+
+    /// Returns the total weight of the path from the root to each node.
+    ///
+    /// `weight[x]` is the weight of the edge between `x` and its parent.
+    /// The weight of the root is ignored.
+    ///
+    /// This operation is O(*n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` does not have `n` elements.
+    pub fn weighted_depths<W>(&self, weight: &[W]) -> Vec<W>
+    where
+        W: Add<Output = W> + Copy + Zero,
+    {
+        assert_eq!(weight.len(), self.depth.len());
+        let mut ret = vec![W::zero(); weight.len()];
+        for &v in &self.order[1..] {
+            ret[v] = ret[self.ancestor[0][v]] + weight[v];
+        }
+        ret
+    }
+
+    /// Returns the total weight of the path between `u` and `v`.
+    ///
+    /// `weighted_depth` is the result of [`weighted_depths`](LCA::weighted_depths),
+    /// which is computed once for all the queries.
+    ///
+    /// This operation is O(log(*n*)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::graph::lca::LCA;
+    ///
+    /// let adj = vec![vec![1, 2], vec![0, 3], vec![0], vec![1]];
+    /// let lca = LCA::new(&adj, 0);
+    /// let wd = lca.weighted_depths(&[0, 5, 7, 2]);
+    /// assert_eq!(lca.weighted_dist(3, 2, &wd), 14);
+    /// assert_eq!(lca.weighted_dist(3, 1, &wd), 2);
+    /// ```
+    pub fn weighted_dist<W>(&self, u: usize, v: usize, weighted_depth: &[W]) -> W
+    where
+        W: Add<Output = W> + Sub<Output = W> + Copy,
+    {
+        let w = weighted_depth[self.lca(u, v)];
+        weighted_depth[u] - w + (weighted_depth[v] - w)
+    }
+}
+
+/// Answers the lowest common ancestors of `queries` offline with Tarjan's algorithm.
+///
+/// `adj` is the adjacency list of a tree rooted at `root`.
+/// Returns the LCA for each query in the given order.
+///
+/// This operation is O((*n* + *q*) α(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::lca::offline_lca;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(offline_lca(5, 0, &adj, &[(3, 4), (3, 2), (1, 1)]), vec![1, 0, 1]);
+/// ```
+pub fn offline_lca(
+    n: usize,
+    root: usize,
+    adj: &[Vec<usize>],
+    queries: &[(usize, usize)],
+) -> Vec<usize> {
+    assert_eq!(n, adj.len());
+    assert!(root < n);
+    let mut query_list = vec![vec![]; n];
+    for (i, &(u, v)) in queries.iter().enumerate() {
+        query_list[u].push((v, i));
+        query_list[v].push((u, i));
+    }
+
+    let mut uf = UnionFind::new(n);
+    // the ancestor represented by each set of the union-find
+    let mut ancestor = (0..n).collect::<Vec<_>>();
+    let mut finished = vec![false; n];
+    let mut visited = vec![false; n];
+    let mut ans = vec![root; queries.len()];
+
+    visited[root] = true;
+    // (node, parent, next index of adj)
+    let mut stack = vec![(root, root, 0)];
+    while let Some((v, p, i)) = stack.pop() {
+        if let Some(&u) = adj[v].get(i) {
+            stack.push((v, p, i + 1));
+            if !visited[u] {
+                visited[u] = true;
+                stack.push((u, v, 0));
+            }
+            continue;
+        }
+
+        finished[v] = true;
+        for &(u, qi) in &query_list[v] {
+            if finished[u] {
+                ans[qi] = ancestor[uf.find_mut(u)];
+            }
+        }
+        if v != root {
+            uf.union(v, p);
+            let r = uf.find_mut(p);
+            ancestor[r] = p;
+        }
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn naive_lca(parent: &[usize], u: usize, v: usize) -> usize {
+        let path = |mut x: usize| {
+            let mut p = vec![x];
+            while parent[x] != x {
+                x = parent[x];
+                p.push(x);
+            }
+            p
+        };
+        let pu = path(u);
+        let pv = path(v);
+        // walk from the root while the paths are shared
+        pu.iter()
+            .rev()
+            .zip(pv.iter().rev())
+            .take_while(|(a, b)| a == b)
+            .last()
+            .map(|(a, _)| *a)
+            .unwrap()
+    }
+
+    #[test]
+    fn random_tree() {
+        let n = 200;
+        let mut rand = xorshift(0x2545_f491_u64, |seed| seed as usize);
+        let mut parent = vec![0; n];
+        let mut adj = vec![vec![]; n];
+        for v in 1..n {
+            let p = rand() % v;
+            parent[v] = p;
+            adj[v].push(p);
+            adj[p].push(v);
+        }
+        let weight = (0..n).map(|_| (rand() % 100) as u64).collect::<Vec<_>>();
+        let lca = LCA::new(&adj, 0);
+        let wd = lca.weighted_depths(&weight);
+        for u in 0..n {
+            for v in 0..n {
+                let w = naive_lca(&parent, u, v);
+                assert_eq!(lca.lca(u, v), w, "{} {}", u, v);
+
+                let mut expected = 0;
+                for &s in &[u, v] {
+                    let mut x = s;
+                    while x != w {
+                        expected += weight[x];
+                        x = parent[x];
+                    }
+                }
+                assert_eq!(lca.weighted_dist(u, v, &wd), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn offline() {
+        let n = 300;
+        let mut rand = xorshift(0x0BAD_CAFE_u64, |seed| seed as usize);
+        let mut adj = vec![vec![]; n];
+        for v in 1..n {
+            let p = rand() % v;
+            adj[v].push(p);
+            adj[p].push(v);
+        }
+        for &root in &[0, 17, n - 1] {
+            let lca = LCA::new(&adj, root);
+            let queries = (0..1000)
+                .map(|_| (rand() % n, rand() % n))
+                .collect::<Vec<_>>();
+            let ans = offline_lca(n, root, &adj, &queries);
+            for (&(u, v), a) in queries.iter().zip(ans) {
+                assert_eq!(a, lca.lca(u, v));
+            }
+        }
+        assert!(offline_lca(1, 0, &[vec![]], &[]).is_empty());
+    }
+
+    #[test]
+    fn line() {
+        let n = 1000;
+        let adj = (0..n)
+            .map(|v| {
+                let mut e = vec![];
+                if v > 0 {
+                    e.push(v - 1);
+                }
+                if v + 1 < n {
+                    e.push(v + 1);
+                }
+                e
+            })
+            .collect::<Vec<_>>();
+        let lca = LCA::new(&adj, 0);
+        assert_eq!(lca.lca(999, 500), 500);
+        assert_eq!(lca.dist(999, 3), 996);
+        assert_eq!(lca.kth_ancestor(999, 999), Some(0));
+        assert_eq!(lca.kth_ancestor(999, 1000), None);
+        assert_eq!(lca.parent(0), None);
+    }
+}
@@ -0,0 +1,246 @@
+use super::{BoundedAbove, Zero};
+use core::ops::{Add, Sub};
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug)]
+struct Edge<W> {
+    to: usize,
+    cap: W,
+}
+
+/// Maximum flow with Dinic's algorithm.
+///
+/// # Time complexity
+///
+/// | Algorithm    | Worst case                                     |
+/// | ------------ | ---------------------------------------------- |
+/// | [`max_flow`] | O(*V*²*E*), O(*E*√*V*) for unit capacities     |
+///
+/// [`max_flow`]: Dinic::max_flow
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::max_flow::Dinic;
+///
+/// let mut g = Dinic::new(4);
+/// let e = g.add_edge(0, 1, 2);
+/// g.add_edge(0, 2, 1);
+/// g.add_edge(1, 2, 1);
+/// g.add_edge(1, 3, 1);
+/// g.add_edge(2, 3, 2);
+/// assert_eq!(g.max_flow(0, 3), 3);
+/// assert_eq!(g.flow(e), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Dinic<W> {
+    graph: Vec<Vec<usize>>,
+    // `edges[2 * i]` is the `i`-th edge and `edges[2 * i + 1]` is its reverse edge.
+    edges: Vec<Edge<W>>,
+    level: Vec<usize>,
+    iter: Vec<usize>,
+}
+
+impl<W> Dinic<W>
+where
+    W: Ord + Copy + Add<Output = W> + Sub<Output = W> + Zero + BoundedAbove,
+{
+    const UNREACHED: usize = !0;
+
+    /// Creates a graph with `n` nodes and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            graph: vec![vec![]; n],
+            edges: vec![],
+            level: vec![Self::UNREACHED; n],
+            iter: vec![0; n],
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a directed edge with the capacity `cap` and returns its ID.
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: W) -> usize {
+        assert!(from < self.len() && to < self.len());
+        let id = self.edges.len() / 2;
+        self.graph[from].push(self.edges.len());
+        self.edges.push(Edge { to, cap });
+        self.graph[to].push(self.edges.len());
+        self.edges.push(Edge {
+            to: from,
+            cap: W::zero(),
+        });
+        id
+    }
+
+    /// Returns the flow passing through the edge `id`.
+    pub fn flow(&self, id: usize) -> W {
+        self.edges[id * 2 + 1].cap
+    }
+
+    /// Returns the residual capacity of the edge `id`.
+    pub fn residual(&self, id: usize) -> W {
+        self.edges[id * 2].cap
+    }
+
+    fn bfs(&mut self, s: usize) {
+        for l in self.level.iter_mut() {
+            *l = Self::UNREACHED;
+        }
+        self.level[s] = 0;
+        let mut que = VecDeque::new();
+        que.push_back(s);
+        while let Some(v) = que.pop_front() {
+            for &e in &self.graph[v] {
+                let Edge { to, cap } = self.edges[e];
+                if cap > W::zero() && self.level[to] == Self::UNREACHED {
+                    self.level[to] = self.level[v] + 1;
+                    que.push_back(to);
+                }
+            }
+        }
+    }
+
+    fn dfs(&mut self, v: usize, t: usize, limit: W) -> W {
+        if v == t {
+            return limit;
+        }
+        while self.iter[v] < self.graph[v].len() {
+            let e = self.graph[v][self.iter[v]];
+            let Edge { to, cap } = self.edges[e];
+            if cap > W::zero() && self.level[v] < self.level[to] {
+                let d = self.dfs(to, t, limit.min(cap));
+                if d > W::zero() {
+                    self.edges[e].cap = self.edges[e].cap - d;
+                    self.edges[e ^ 1].cap = self.edges[e ^ 1].cap + d;
+                    return d;
+                }
+            }
+            self.iter[v] += 1;
+        }
+        W::zero()
+    }
+
+    /// Sends the maximum flow from `s` to `t` and returns the amount.
+    ///
+    /// The flow is accumulated on the residual graph,
+    /// so calling it again returns the additional flow only.
+    pub fn max_flow(&mut self, s: usize, t: usize) -> W {
+        assert!(s != t);
+        let mut flow = W::zero();
+        loop {
+            self.bfs(s);
+            if self.level[t] == Self::UNREACHED {
+                return flow;
+            }
+            for i in self.iter.iter_mut() {
+                *i = 0;
+            }
+            loop {
+                let f = self.dfs(s, t, W::upper_bound());
+                if f == W::zero() {
+                    break;
+                }
+                flow = flow + f;
+            }
+        }
+    }
+
+    /// Sends the maximum flow and returns the `s` side of a minimum cut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::graph::max_flow::Dinic;
+    ///
+    /// let mut g = Dinic::new(4);
+    /// g.add_edge(0, 1, 3);
+    /// g.add_edge(1, 2, 1);
+    /// g.add_edge(2, 3, 3);
+    /// assert_eq!(g.min_cut(0, 3), vec![true, true, false, false]);
+    /// ```
+    pub fn min_cut(&mut self, s: usize, t: usize) -> Vec<bool> {
+        self.max_flow(s, t);
+        self.bfs(s);
+        self.level.iter().map(|&l| l != Self::UNREACHED).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clrs() {
+        // CLRS Figure 26.1
+        let mut g = Dinic::new(6);
+        let edges = vec![
+            (0, 1, 16),
+            (0, 2, 13),
+            (2, 1, 4),
+            (1, 3, 12),
+            (3, 2, 9),
+            (2, 4, 14),
+            (4, 3, 7),
+            (3, 5, 20),
+            (4, 5, 4),
+        ];
+        let ids = edges
+            .iter()
+            .map(|&(a, b, c)| g.add_edge(a, b, c))
+            .collect::<Vec<_>>();
+        assert_eq!(g.max_flow(0, 5), 23);
+        assert_eq!(g.max_flow(0, 5), 0);
+
+        // flow conservation
+        let mut balance = vec![0_i64; 6];
+        for (&id, &(a, b, c)) in ids.iter().zip(edges.iter()) {
+            let f = g.flow(id);
+            assert!(0 <= f && f <= c);
+            assert_eq!(f + g.residual(id), c);
+            balance[a] -= f;
+            balance[b] += f;
+        }
+        assert_eq!(balance, vec![-23, 0, 0, 0, 0, 23]);
+
+        // capacity of the cut equals the max flow
+        let cut = g.min_cut(0, 5);
+        assert!(cut[0] && !cut[5]);
+        let cap = edges
+            .iter()
+            .filter(|&&(a, b, _)| cut[a] && !cut[b])
+            .map(|e| e.2)
+            .sum::<i64>();
+        assert_eq!(cap, 23);
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut g = Dinic::<u32>::new(3);
+        g.add_edge(0, 1, 5);
+        assert_eq!(g.max_flow(0, 2), 0);
+        assert_eq!(g.min_cut(0, 2), vec![true, true, false]);
+    }
+
+    #[test]
+    fn unit_bipartite() {
+        // left 1..=3, right 4..=6, source 0, sink 7
+        let mut g = Dinic::new(8);
+        for l in 1..=3 {
+            g.add_edge(0, l, 1);
+            g.add_edge(l + 3, 7, 1);
+        }
+        for &(l, r) in &[(1, 4), (1, 5), (2, 4), (3, 4)] {
+            g.add_edge(l, r, 1);
+        }
+        assert_eq!(g.max_flow(0, 7), 2);
+    }
+}
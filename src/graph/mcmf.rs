@@ -0,0 +1,270 @@
+use super::{BoundedAbove, Zero};
+use core::cmp::Reverse;
+use core::ops::{Add, Mul, Neg, Sub};
+use std::collections::{BinaryHeap, VecDeque};
+
+#[derive(Clone, Debug)]
+struct Edge<W> {
+    to: usize,
+    cap: W,
+    cost: W,
+}
+
+/// Minimum cost maximum flow with successive shortest paths.
+///
+/// The graph must not have a cycle of negative cost.
+///
+/// # Time complexity
+///
+/// | Algorithm                     | Worst case       |
+/// | ----------------------------- | ---------------- |
+/// | [`min_cost_flow`]             | O(*FVE*)         |
+/// | [`min_cost_flow_nonnegative`] | O(*FE* log(*V*)) |
+///
+/// *F* is the amount of the flow.
+///
+/// [`min_cost_flow`]: MinCostFlow::min_cost_flow
+/// [`min_cost_flow_nonnegative`]: MinCostFlow::min_cost_flow_nonnegative
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::mcmf::MinCostFlow;
+///
+/// let mut g = MinCostFlow::new(4);
+/// let e = g.add_edge(0, 1, 2, 1);
+/// g.add_edge(0, 2, 1, 2);
+/// g.add_edge(1, 2, 1, 1);
+/// g.add_edge(1, 3, 1, 3);
+/// g.add_edge(2, 3, 2, 1);
+/// assert_eq!(g.min_cost_flow(0, 3), (3, 10));
+/// assert_eq!(g.flow(e), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinCostFlow<W> {
+    graph: Vec<Vec<usize>>,
+    // `edges[2 * i]` is the `i`-th edge and `edges[2 * i + 1]` is its reverse edge.
+    edges: Vec<Edge<W>>,
+}
+
+pub type MCMF<W> = MinCostFlow<W>;
+
+impl<W> MinCostFlow<W>
+where
+    W: Ord
+        + Copy
+        + Add<Output = W>
+        + Sub<Output = W>
+        + Mul<Output = W>
+        + Neg<Output = W>
+        + Zero
+        + BoundedAbove,
+{
+    const NONE: usize = !0;
+
+    /// Creates a graph with `n` nodes and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            graph: vec![vec![]; n],
+            edges: vec![],
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a directed edge with the capacity `cap` and the cost `cost` per unit flow, and returns its ID.
+    pub fn add_edge(&mut self, from: usize, to: usize, cap: W, cost: W) -> usize {
+        assert!(from < self.len() && to < self.len());
+        let id = self.edges.len() / 2;
+        self.graph[from].push(self.edges.len());
+        self.edges.push(Edge { to, cap, cost });
+        self.graph[to].push(self.edges.len());
+        self.edges.push(Edge {
+            to: from,
+            cap: W::zero(),
+            cost: -cost,
+        });
+        id
+    }
+
+    /// Returns the flow passing through the edge `id`.
+    pub fn flow(&self, id: usize) -> W {
+        self.edges[id * 2 + 1].cap
+    }
+
+    /// Sends the bottleneck flow along the path to `t` given by `prev`, and returns it with the cost per unit.
+    fn augment(&mut self, s: usize, t: usize, prev: &[usize]) -> (W, W) {
+        let mut d = W::upper_bound();
+        let mut cost = W::zero();
+        let mut v = t;
+        while v != s {
+            let e = prev[v];
+            d = d.min(self.edges[e].cap);
+            cost = cost + self.edges[e].cost;
+            v = self.edges[e ^ 1].to;
+        }
+        let mut v = t;
+        while v != s {
+            let e = prev[v];
+            self.edges[e].cap = self.edges[e].cap - d;
+            self.edges[e ^ 1].cap = self.edges[e ^ 1].cap + d;
+            v = self.edges[e ^ 1].to;
+        }
+        (d, cost)
+    }
+
+    /// Sends the maximum flow from `s` to `t` with the minimum cost,
+    /// and returns `(total_flow, total_cost)`.
+    ///
+    /// The shortest paths are found with the Bellman-Ford algorithm (SPFA),
+    /// so negative costs are allowed.
+    pub fn min_cost_flow(&mut self, s: usize, t: usize) -> (W, W) {
+        assert!(s != t);
+        let n = self.len();
+        let inf = W::upper_bound();
+        let (mut flow, mut total) = (W::zero(), W::zero());
+        loop {
+            let mut dist = vec![inf; n];
+            let mut prev = vec![Self::NONE; n];
+            let mut in_queue = vec![false; n];
+            let mut que = VecDeque::new();
+            dist[s] = W::zero();
+            que.push_back(s);
+            while let Some(v) = que.pop_front() {
+                in_queue[v] = false;
+                for &e in &self.graph[v] {
+                    let Edge { to, cap, cost } = self.edges[e];
+                    if cap > W::zero() && dist[v] + cost < dist[to] {
+                        dist[to] = dist[v] + cost;
+                        prev[to] = e;
+                        if !in_queue[to] {
+                            in_queue[to] = true;
+                            que.push_back(to);
+                        }
+                    }
+                }
+            }
+            if dist[t] == inf {
+                return (flow, total);
+            }
+            let (d, cost) = self.augment(s, t, &prev);
+            flow = flow + d;
+            total = total + d * cost;
+        }
+    }
+
+    /// Same as [`min_cost_flow`](Self::min_cost_flow),
+    /// but finds the shortest paths with Dijkstra's algorithm on the costs reweighted by potentials.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edge has a negative cost.
+    pub fn min_cost_flow_nonnegative(&mut self, s: usize, t: usize) -> (W, W) {
+        assert!(s != t);
+        assert!(
+            self.edges.iter().step_by(2).all(|e| e.cost >= W::zero()),
+            "the costs must be non-negative"
+        );
+        let n = self.len();
+        let inf = W::upper_bound();
+        let mut potential = vec![W::zero(); n];
+        let (mut flow, mut total) = (W::zero(), W::zero());
+        loop {
+            let mut dist = vec![inf; n];
+            let mut prev = vec![Self::NONE; n];
+            let mut heap = BinaryHeap::new();
+            dist[s] = W::zero();
+            heap.push(Reverse((W::zero(), s)));
+            while let Some(Reverse((d, v))) = heap.pop() {
+                if dist[v] < d {
+                    continue;
+                }
+                for &e in &self.graph[v] {
+                    let Edge { to, cap, cost } = self.edges[e];
+                    // the reduced cost is non-negative
+                    let nd = d + cost + potential[v] - potential[to];
+                    if cap > W::zero() && nd < dist[to] {
+                        dist[to] = nd;
+                        prev[to] = e;
+                        heap.push(Reverse((nd, to)));
+                    }
+                }
+            }
+            if dist[t] == inf {
+                return (flow, total);
+            }
+            for (p, &d) in potential.iter_mut().zip(&dist) {
+                if d != inf {
+                    *p = *p + d;
+                }
+            }
+            let (d, cost) = self.augment(s, t, &prev);
+            flow = flow + d;
+            total = total + d * cost;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::max_flow::Dinic;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as i64 & 0xffff);
+
+        for n in 2..10 {
+            let mut g = MinCostFlow::new(n);
+            let mut dinic = Dinic::new(n);
+            for _ in 0..n * 3 {
+                let u = rand() as usize % n;
+                let v = rand() as usize % n;
+                let cap = rand() % 5;
+                let cost = rand() % 10;
+                g.add_edge(u, v, cap, cost);
+                dinic.add_edge(u, v, cap);
+            }
+            let mut h = g.clone();
+            let (flow, cost) = g.min_cost_flow(0, n - 1);
+            assert_eq!(flow, dinic.max_flow(0, n - 1));
+            assert_eq!(h.min_cost_flow_nonnegative(0, n - 1), (flow, cost));
+        }
+    }
+
+    #[test]
+    fn negative_cost() {
+        // assignment problem maximizing the profit
+        let profit = [[3, 1, 4], [1, 5, 9], [2, 6, 5]];
+        let mut g = MinCostFlow::new(8);
+        for (i, row) in profit.iter().enumerate() {
+            g.add_edge(6, i, 1, 0);
+            g.add_edge(i + 3, 7, 1, 0);
+            for (j, &p) in row.iter().enumerate() {
+                g.add_edge(i, j + 3, 1, -p);
+            }
+        }
+        // 3 + 9 + 6
+        assert_eq!(g.min_cost_flow(6, 7), (3, -18));
+    }
+
+    #[test]
+    fn costs_and_flows() {
+        let mut g = MCMF::new(3);
+        let a = g.add_edge(0, 1, 5, 2);
+        let b = g.add_edge(0, 2, 2, 10);
+        let c = g.add_edge(1, 2, 3, 1);
+        assert_eq!(g.min_cost_flow_nonnegative(0, 2), (5, 3 * 3 + 2 * 10));
+        assert_eq!((g.flow(a), g.flow(b), g.flow(c)), (3, 2, 3));
+        assert_eq!(g.min_cost_flow(0, 2), (0, 0));
+    }
+}
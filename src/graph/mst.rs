@@ -0,0 +1,87 @@
+use super::UnionFind;
+
+/// Computes a minimum spanning tree with Kruskal's algorithm.
+///
+/// `edges` is a list of undirected edges `(u, v, weight)`.
+/// Returns the edges of the tree and `true` if the graph is connected.
+/// If the graph is disconnected, the edges of a minimum spanning forest are returned with `false`.
+///
+/// This operation is O(*E* log(*E*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::mst::kruskal;
+///
+/// let edges = vec![(0, 1, 3), (1, 2, 1), (0, 2, 2), (2, 3, 4)];
+/// let (tree, connected) = kruskal(4, &edges);
+/// assert!(connected);
+/// assert_eq!(tree, vec![(1, 2, 1), (0, 2, 2), (2, 3, 4)]);
+/// assert_eq!(tree.iter().map(|e| e.2).sum::<i32>(), 7);
+/// ```
+pub fn kruskal<W: Ord + Copy>(
+    n: usize,
+    edges: &[(usize, usize, W)],
+) -> (Vec<(usize, usize, W)>, bool) {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|e| e.2);
+
+    let mut uf = UnionFind::new(n);
+    let mut tree = Vec::with_capacity(n.saturating_sub(1));
+    for (u, v, w) in sorted {
+        if uf.union(u, v) {
+            tree.push((u, v, w));
+        }
+    }
+
+    let connected = tree.len() + 1 >= n;
+    (tree, connected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(tree: &[(usize, usize, i64)]) -> i64 {
+        tree.iter().map(|e| e.2).sum()
+    }
+
+    #[test]
+    fn classic() {
+        // https://en.wikipedia.org/wiki/Kruskal%27s_algorithm
+        let (a, b, c, d, e, f, g) = (0, 1, 2, 3, 4, 5, 6);
+        let edges = vec![
+            (a, b, 7),
+            (a, d, 5),
+            (b, c, 8),
+            (b, d, 9),
+            (b, e, 7),
+            (c, e, 5),
+            (d, e, 15),
+            (d, f, 6),
+            (e, f, 8),
+            (e, g, 9),
+            (f, g, 11),
+        ];
+        let (tree, connected) = kruskal(7, &edges);
+        assert!(connected);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(total(&tree), 39);
+    }
+
+    #[test]
+    fn forest() {
+        let edges = vec![(0, 1, 2), (1, 2, 1), (0, 2, 5), (3, 4, -1)];
+        let (tree, connected) = kruskal(5, &edges);
+        assert!(!connected);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(total(&tree), 2);
+    }
+
+    #[test]
+    fn trivial() {
+        assert_eq!(kruskal::<i64>(0, &[]), (vec![], true));
+        assert_eq!(kruskal::<i64>(1, &[]), (vec![], true));
+        assert_eq!(kruskal::<i64>(2, &[]), (vec![], false));
+    }
+}
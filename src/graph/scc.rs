@@ -0,0 +1,182 @@
+/// Decomposes a directed graph into strongly connected components with Kosaraju's algorithm.
+///
+/// Returns `(num_components, component_id_per_node)`.
+/// The component IDs are sorted topologically,
+/// so that an edge `u -> v` satisfies `id[u] <= id[v]` and the source components have smaller IDs.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::scc::scc;
+///
+/// let edges = vec![(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)];
+/// let (num, id) = scc(4, &edges);
+/// assert_eq!(num, 2);
+/// assert_eq!(id, vec![0, 0, 1, 1]);
+/// ```
+pub fn scc(n: usize, edges: &[(usize, usize)]) -> (usize, Vec<usize>) {
+    let mut graph = vec![vec![]; n];
+    let mut rev_graph = vec![vec![]; n];
+    for &(from, to) in edges {
+        graph[from].push(to);
+        rev_graph[to].push(from);
+    }
+
+    // post-order of the first DFS
+    let mut order = Vec::with_capacity(n);
+    let mut visited = vec![false; n];
+    let mut stack = vec![];
+    for s in 0..n {
+        if visited[s] {
+            continue;
+        }
+        visited[s] = true;
+        stack.push((s, 0));
+        while let Some((v, i)) = stack.pop() {
+            if let Some(&u) = graph[v].get(i) {
+                stack.push((v, i + 1));
+                if !visited[u] {
+                    visited[u] = true;
+                    stack.push((u, 0));
+                }
+            } else {
+                order.push(v);
+            }
+        }
+    }
+
+    // second DFS on the reversed graph in decreasing post-order
+    const UNVISITED: usize = !0;
+    let mut id = vec![UNVISITED; n];
+    let mut num = 0;
+    for &s in order.iter().rev() {
+        if id[s] != UNVISITED {
+            continue;
+        }
+        id[s] = num;
+        stack.push((s, 0));
+        while let Some((v, _)) = stack.pop() {
+            for &u in &rev_graph[v] {
+                if id[u] == UNVISITED {
+                    id[u] = num;
+                    stack.push((u, 0));
+                }
+            }
+        }
+        num += 1;
+    }
+
+    (num, id)
+}
+
+/// Decomposes a directed graph into strongly connected components and builds the condensation graph.
+///
+/// Returns `(num_components, component_id_per_node, dag)`,
+/// where `dag[c]` is the sorted list of components reachable from `c` by a single edge.
+/// See [`scc`] for the order of the component IDs.
+///
+/// This operation is O((*V* + *E*) log(*E*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::scc::scc_condensation;
+///
+/// let edges = vec![(0, 1), (1, 0), (1, 2), (0, 2), (2, 3), (3, 2)];
+/// let (num, id, dag) = scc_condensation(4, &edges);
+/// assert_eq!(num, 2);
+/// assert_eq!(id, vec![0, 0, 1, 1]);
+/// assert_eq!(dag, vec![vec![1], vec![]]);
+/// ```
+pub fn scc_condensation(
+    n: usize,
+    edges: &[(usize, usize)],
+) -> (usize, Vec<usize>, Vec<Vec<usize>>) {
+    let (num, id) = scc(n, edges);
+    let mut dag = vec![vec![]; num];
+    for &(from, to) in edges {
+        if id[from] != id[to] {
+            dag[id[from]].push(id[to]);
+        }
+    }
+    for v in dag.iter_mut() {
+        v.sort_unstable();
+        v.dedup();
+    }
+    (num, id, dag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reachable(n: usize, edges: &[(usize, usize)]) -> Vec<Vec<bool>> {
+        let mut reach = vec![vec![false; n]; n];
+        for (i, row) in reach.iter_mut().enumerate() {
+            row[i] = true;
+        }
+        for &(a, b) in edges {
+            reach[a][b] = true;
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if reach[i][k] && reach[k][j] {
+                        reach[i][j] = true;
+                    }
+                }
+            }
+        }
+        reach
+    }
+
+    #[test]
+    fn scc_matches_reachability() {
+        let n = 8;
+        let edges = vec![
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (6, 5),
+            (6, 7),
+            (7, 6),
+        ];
+        let (num, id) = scc(n, &edges);
+        assert_eq!(num, 3);
+
+        let reach = reachable(n, &edges);
+        for i in 0..n {
+            for j in 0..n {
+                assert_eq!(id[i] == id[j], reach[i][j] && reach[j][i], "{} {}", i, j);
+            }
+        }
+        // topological order
+        for &(a, b) in &edges {
+            assert!(id[a] <= id[b]);
+        }
+    }
+
+    #[test]
+    fn condensation() {
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 1), (3, 4), (2, 4)];
+        let (num, id, dag) = scc_condensation(5, &edges);
+        assert_eq!(num, 3);
+        assert_eq!(id, vec![0, 1, 1, 1, 2]);
+        assert_eq!(dag, vec![vec![1], vec![2], vec![]]);
+    }
+
+    #[test]
+    fn no_edges() {
+        let (num, id) = scc(3, &[]);
+        assert_eq!(num, 3);
+        let mut sorted = id.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![0, 1, 2]);
+    }
+}
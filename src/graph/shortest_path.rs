@@ -0,0 +1,451 @@
+use super::{BoundedAbove, One, Zero};
+use core::cmp::Reverse;
+use core::fmt;
+use core::ops::{Add, Sub};
+use std::collections::{BinaryHeap, VecDeque};
+
+/// An error returned when a negative-weight cycle is found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycleError;
+
+impl fmt::Display for NegativeCycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("negative cycle detected")
+    }
+}
+
+impl std::error::Error for NegativeCycleError {}
+
+/// Computes the shortest distances from `start` with the Bellman-Ford algorithm.
+///
+/// `edges` is a list of directed edges `(from, to, weight)`.
+/// The weight type is usually a signed type such as `i64`, since the algorithm is meant for negative weights.
+/// Unreachable nodes get `W::upper_bound()`.
+///
+/// Returns `Err(NegativeCycleError)` if a negative cycle is reachable from `start`.
+///
+/// This operation is O(*VE*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::{bellman_ford, NegativeCycleError};
+///
+/// let edges = vec![(0, 1, 4), (0, 2, 5), (1, 2, -3), (2, 3, 2)];
+/// assert_eq!(bellman_ford(4, &edges, 0), Ok(vec![0, 4, 1, 3]));
+///
+/// let edges = vec![(0, 1, 1), (1, 2, -2), (2, 1, 1)];
+/// assert_eq!(bellman_ford(3, &edges, 0), Err(NegativeCycleError));
+/// ```
+pub fn bellman_ford<W>(
+    n: usize,
+    edges: &[(usize, usize, W)],
+    start: usize,
+) -> Result<Vec<W>, NegativeCycleError>
+where
+    W: Ord + Copy + Add<Output = W> + Zero + BoundedAbove,
+{
+    assert!(start < n);
+    let inf = W::upper_bound();
+    let mut dist = vec![inf; n];
+    dist[start] = W::zero();
+
+    for _ in 0..n {
+        let mut updated = false;
+        for &(from, to, weight) in edges {
+            if dist[from] != inf && dist[from] + weight < dist[to] {
+                dist[to] = dist[from] + weight;
+                updated = true;
+            }
+        }
+        if !updated {
+            return Ok(dist);
+        }
+    }
+
+    // It is still updated at the n-th iteration.
+    Err(NegativeCycleError)
+}
+
+/// Returns `true` if the graph has a negative cycle anywhere.
+///
+/// Unlike [`bellman_ford`], the cycle does not need to be reachable from a specific node.
+///
+/// This operation is O(*VE*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::detect_negative_cycle;
+///
+/// assert!(!detect_negative_cycle(3, &[(0, 1, 1), (1, 2, -1)]));
+/// // The cycle is not reachable from `0`, but it is detected.
+/// assert!(detect_negative_cycle(3, &[(0, 1, 1), (2, 2, -1)]));
+/// ```
+pub fn detect_negative_cycle<W>(n: usize, edges: &[(usize, usize, W)]) -> bool
+where
+    W: Ord + Copy + Add<Output = W> + Zero,
+{
+    potentials(n, edges).is_none()
+}
+
+/// Returns the shortest distances from a virtual source connected to all nodes with weight zero,
+/// or `None` if the graph has a negative cycle.
+///
+/// They are potentials such that `weight + p[from] - p[to]` is non-negative for every edge.
+fn potentials<W>(n: usize, edges: &[(usize, usize, W)]) -> Option<Vec<W>>
+where
+    W: Ord + Copy + Add<Output = W> + Zero,
+{
+    let mut dist = vec![W::zero(); n];
+
+    for _ in 0..n {
+        let mut updated = false;
+        for &(from, to, weight) in edges {
+            if dist[from] + weight < dist[to] {
+                dist[to] = dist[from] + weight;
+                updated = true;
+            }
+        }
+        if !updated {
+            return Some(dist);
+        }
+    }
+    None
+}
+
+/// Computes the shortest distances between all pairs of nodes with Johnson's algorithm.
+///
+/// `edges` is a list of directed edges `(from, to, weight)`, and the weights may be negative.
+/// The edges are reweighted to be non-negative with the potentials from the Bellman-Ford algorithm,
+/// and then Dijkstra's algorithm runs from every node.
+/// Unreachable pairs get `W::upper_bound()`.
+///
+/// Returns `Err(NegativeCycleError)` if the graph has a negative cycle anywhere.
+///
+/// This operation is O(*VE* log(*V*)), which is faster than [`floyd_warshall`] for sparse graphs.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::{johnson, NegativeCycleError};
+///
+/// let edges = vec![(0, 1, 4), (0, 2, 5), (1, 2, -3), (2, 3, 2)];
+/// let dist = johnson(4, &edges).unwrap();
+/// assert_eq!(dist[0], vec![0, 4, 1, 3]);
+/// assert_eq!(dist[1][3], -1);
+/// assert_eq!(dist[3][0], std::i32::MAX);
+///
+/// let edges = vec![(0, 1, 1), (1, 2, -2), (2, 1, 1)];
+/// assert_eq!(johnson(3, &edges), Err(NegativeCycleError));
+/// ```
+pub fn johnson<W>(n: usize, edges: &[(usize, usize, W)]) -> Result<Vec<Vec<W>>, NegativeCycleError>
+where
+    W: Ord + Copy + Add<Output = W> + Sub<Output = W> + Zero + BoundedAbove,
+{
+    let p = potentials(n, edges).ok_or(NegativeCycleError)?;
+    let mut adj = vec![vec![]; n];
+    for &(from, to, weight) in edges {
+        adj[from].push((to, weight + p[from] - p[to]));
+    }
+
+    let inf = W::upper_bound();
+    let mut res = Vec::with_capacity(n);
+    for s in 0..n {
+        let mut dist = vec![inf; n];
+        dist[s] = W::zero();
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((W::zero(), s)));
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if dist[u] < d {
+                continue;
+            }
+            for &(v, weight) in &adj[u] {
+                if d + weight < dist[v] {
+                    dist[v] = d + weight;
+                    heap.push(Reverse((dist[v], v)));
+                }
+            }
+        }
+        // restore the original weights
+        for (v, d) in dist.iter_mut().enumerate() {
+            if *d != inf {
+                *d = *d - p[s] + p[v];
+            }
+        }
+        res.push(dist);
+    }
+    Ok(res)
+}
+
+/// Computes the shortest distances between all pairs of nodes with the Floyd-Warshall algorithm.
+///
+/// `adj[u]` is a list of edges `(v, weight)` from `u`.
+/// The distance from a node to itself starts at zero, and unreachable pairs get `W::upper_bound()`.
+///
+/// If the graph has a negative cycle, the distances are meaningless,
+/// and [`has_negative_cycle`] on the result returns `true`.
+///
+/// This operation is O(*V*³).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::{floyd_warshall, has_negative_cycle};
+///
+/// let adj = vec![vec![(1, 4), (2, 1)], vec![(3, 1)], vec![(1, 2)], vec![]];
+/// let dist = floyd_warshall(4, &adj);
+/// assert_eq!(dist[0], vec![0, 3, 1, 4]);
+/// assert_eq!(dist[3][0], std::i32::MAX);
+/// assert!(!has_negative_cycle(&dist));
+/// ```
+pub fn floyd_warshall<W>(n: usize, adj: &[Vec<(usize, W)>]) -> Vec<Vec<W>>
+where
+    W: Ord + Copy + Add<Output = W> + Zero + BoundedAbove,
+{
+    assert_eq!(adj.len(), n);
+    let inf = W::upper_bound();
+    let mut dist = vec![vec![inf; n]; n];
+    for (u, edges) in adj.iter().enumerate() {
+        dist[u][u] = W::zero();
+        for &(v, weight) in edges {
+            dist[u][v] = dist[u][v].min(weight);
+        }
+    }
+
+    for k in 0..n {
+        let row_k = dist[k].clone();
+        for row in dist.iter_mut() {
+            let d_ik = row[k];
+            if d_ik == inf {
+                continue;
+            }
+            for (d, &d_kj) in row.iter_mut().zip(&row_k) {
+                if d_kj != inf && d_ik + d_kj < *d {
+                    *d = d_ik + d_kj;
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// Returns `true` if the result of [`floyd_warshall`] shows a negative cycle.
+///
+/// A node is on a negative cycle if and only if its distance to itself is negative.
+///
+/// This operation is O(*V*).
+pub fn has_negative_cycle<W: Ord + Zero>(dist: &[Vec<W>]) -> bool {
+    dist.iter().enumerate().any(|(i, row)| row[i] < W::zero())
+}
+
+/// Computes the shortest distances from `start` in a graph whose weights are only 0 or 1 with 0-1 BFS.
+///
+/// `graph[u]` is a list of edges `(v, weight)` from `u`.
+/// The nodes reached by 0-weight edges are pushed to the front of the deque, and the others to the back,
+/// so the deque is always sorted by the distances.
+/// Unreachable nodes get `None`.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Panics
+///
+/// Panics if a weight is neither 0 nor 1.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::bfs_01;
+///
+/// let graph = vec![vec![(1, 1), (2, 0)], vec![(3, 0)], vec![(1, 0), (3, 1)], vec![], vec![]];
+/// assert_eq!(bfs_01(5, &graph, 0), vec![Some(0), Some(0), Some(0), Some(0), None]);
+/// ```
+pub fn bfs_01<W>(n: usize, graph: &[Vec<(usize, W)>], start: usize) -> Vec<Option<W>>
+where
+    W: Ord + Copy + Add<Output = W> + Zero + One,
+{
+    assert!(start < n);
+    let (zero, one) = (W::zero(), W::one());
+    let mut dist = vec![None; n];
+    dist[start] = Some(zero);
+    let mut que = VecDeque::new();
+    que.push_back((zero, start));
+    while let Some((d, u)) = que.pop_front() {
+        if dist[u] != Some(d) {
+            continue;
+        }
+        for &(v, weight) in &graph[u] {
+            assert!(
+                weight == zero || weight == one,
+                "the weights must be 0 or 1"
+            );
+            let nd = d + weight;
+            let improved = match dist[v] {
+                Some(x) => nd < x,
+                None => true,
+            };
+            if improved {
+                dist[v] = Some(nd);
+                if weight == zero {
+                    que.push_front((nd, v));
+                } else {
+                    que.push_back((nd, v));
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// Computes [`bfs_01`] where `true` is the weight 1 and `false` is the weight 0.
+///
+/// This operation is O(*V* + *E*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::shortest_path::bfs_01_bool;
+///
+/// let graph = vec![vec![(1, true), (2, false)], vec![], vec![(1, false)]];
+/// assert_eq!(bfs_01_bool(3, &graph, 0), vec![Some(0), Some(0), Some(0)]);
+/// ```
+pub fn bfs_01_bool(n: usize, graph: &[Vec<(usize, bool)>], start: usize) -> Vec<Option<usize>> {
+    let graph = graph
+        .iter()
+        .map(|edges| edges.iter().map(|&(v, w)| (v, w as usize)).collect())
+        .collect::<Vec<_>>();
+    bfs_01(n, &graph, start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn bellman_ford_distances() {
+        let edges = vec![
+            (0, 1, 6),
+            (0, 2, 7),
+            (1, 2, 8),
+            (1, 3, 5),
+            (1, 4, -4),
+            (2, 3, -3),
+            (2, 4, 9),
+            (3, 1, -2),
+            (4, 0, 2),
+            (4, 3, 7),
+        ];
+        assert_eq!(bellman_ford(5, &edges, 0), Ok(vec![0, 2, 7, 4, -2]));
+    }
+
+    #[test]
+    fn bellman_ford_unreachable() {
+        let edges = vec![(0, 1, 3_i64), (2, 3, -1)];
+        let inf = i64::upper_bound();
+        assert_eq!(bellman_ford(4, &edges, 0), Ok(vec![0, 3, inf, inf]));
+    }
+
+    #[test]
+    fn bellman_ford_negative_cycle() {
+        // reachable negative cycle `1 -> 2 -> 3 -> 1`
+        let edges = vec![(0, 1, 1), (1, 2, 1), (2, 3, -3), (3, 1, 1)];
+        assert_eq!(bellman_ford(4, &edges, 0), Err(NegativeCycleError));
+
+        // unreachable negative cycle is ignored
+        let edges = vec![(0, 1, 1), (2, 3, -3), (3, 2, 1)];
+        assert!(bellman_ford(4, &edges, 0).is_ok());
+        assert!(detect_negative_cycle(4, &edges));
+    }
+
+    #[test]
+    fn detect_negative_cycle_self_loop() {
+        assert!(detect_negative_cycle(1, &[(0, 0, -1)]));
+        assert!(!detect_negative_cycle(1, &[(0, 0, 0)]));
+        assert!(!detect_negative_cycle::<i32>(3, &[]));
+    }
+
+    #[test]
+    fn floyd_warshall_matches_bellman_ford() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 1..10 {
+            let mut adj = vec![vec![]; n];
+            let mut edges = vec![];
+            for _ in 0..n * 2 {
+                let u = rand() as usize % n;
+                let v = rand() as usize % n;
+                let w = (rand() % 20) as i64 - 3;
+                adj[u].push((v, w));
+                edges.push((u, v, w));
+            }
+            let dist = floyd_warshall(n, &adj);
+            assert_eq!(has_negative_cycle(&dist), detect_negative_cycle(n, &edges));
+            if !has_negative_cycle(&dist) {
+                for (s, row) in dist.iter().enumerate() {
+                    assert_eq!(Ok(row.clone()), bellman_ford(n, &edges, s));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_negative_self_loop() {
+        let dist = floyd_warshall(2, &[vec![(0, -1)], vec![]]);
+        assert!(has_negative_cycle(&dist));
+    }
+
+    #[test]
+    fn johnson_matches_floyd_warshall() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 1..12 {
+            for _ in 0..10 {
+                let mut adj = vec![vec![]; n];
+                let mut edges = vec![];
+                for _ in 0..n * 2 {
+                    let u = rand() as usize % n;
+                    let v = rand() as usize % n;
+                    let w = (rand() % 20) as i64 - 2;
+                    adj[u].push((v, w));
+                    edges.push((u, v, w));
+                }
+                let dist = floyd_warshall(n, &adj);
+                if has_negative_cycle(&dist) {
+                    assert_eq!(johnson(n, &edges), Err(NegativeCycleError));
+                } else {
+                    assert_eq!(johnson(n, &edges), Ok(dist));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bfs_01_matches_johnson() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..15 {
+            let mut graph = vec![vec![]; n];
+            let mut edges = vec![];
+            for _ in 0..n * 2 {
+                let (u, v, w) = (rand() % n, rand() % n, rand() % 2 == 0);
+                graph[u].push((v, w));
+                edges.push((u, v, w as i64));
+            }
+            let dist = johnson(n, &edges).unwrap();
+            for (s, row) in dist.iter().enumerate() {
+                let expected = row
+                    .iter()
+                    .map(|&d| {
+                        if d == i64::upper_bound() {
+                            None
+                        } else {
+                            Some(d as usize)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(bfs_01_bool(n, &graph, s), expected);
+            }
+        }
+    }
+}
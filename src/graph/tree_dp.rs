@@ -0,0 +1,254 @@
+/// Visits the nodes reachable from `root` in BFS order and sets their parents in `parent` (`!0` for `root`),
+/// where `to` returns the neighbour in an element of `adj`, or `None` to skip it.
+///
+/// The visit stops once it exceeds `adj.len()` nodes, which happens only if the graph has a cycle.
+pub(crate) fn bfs_visit<A, F>(
+    adj: &[Vec<A>],
+    root: usize,
+    parent: &mut [usize],
+    to: F,
+) -> Vec<usize>
+where
+    F: Fn(&A) -> Option<usize>,
+{
+    parent[root] = !0;
+    let mut order = vec![root];
+    let mut i = 0;
+    while i < order.len() && order.len() <= adj.len() {
+        let v = order[i];
+        for u in adj[v].iter().filter_map(&to) {
+            if u != parent[v] {
+                parent[u] = v;
+                order.push(u);
+            }
+        }
+        i += 1;
+    }
+    order
+}
+
+/// Returns the BFS order from `root` and the parent of each node (`!0` for `root`),
+/// where `to` extracts the neighbour from an element of `adj`.
+pub(crate) fn bfs_order<A, F>(adj: &[Vec<A>], root: usize, to: F) -> (Vec<usize>, Vec<usize>)
+where
+    F: Fn(&A) -> usize,
+{
+    let n = adj.len();
+    let mut parent = vec![!0; n];
+    let order = bfs_visit(adj, root, &mut parent, |a| Some(to(a)));
+    assert_eq!(order.len(), n, "the graph must be a connected tree");
+    (order, parent)
+}
+
+/// Computes the DP on the subtrees of the tree rooted at `root`.
+///
+/// The value of `v` is `leaf_val(v)` merged with the value of each child `c` by `merge(acc, dp[c], v, c)`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_dp::subtree_dp;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// // the heights of the subtrees
+/// let height = subtree_dp(&adj, 0, |_| 0, |acc: usize, h, _, _| acc.max(h + 1));
+/// assert_eq!(height, vec![2, 1, 0, 0, 0]);
+/// ```
+pub fn subtree_dp<T, F, G>(adj: &[Vec<usize>], root: usize, leaf_val: F, merge: G) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(usize) -> T,
+    G: Fn(T, T, usize, usize) -> T,
+{
+    assert!(root < adj.len());
+    let n = adj.len();
+    let (order, parent) = bfs_order(adj, root, |&u| u);
+
+    let mut dp = (0..n).map(|v| Some(leaf_val(v))).collect::<Vec<_>>();
+    for &v in order.iter().rev() {
+        if v != root {
+            let p = parent[v];
+            let (acc, child) = (dp[p].take().unwrap(), dp[v].clone().unwrap());
+            dp[p] = Some(merge(acc, child, p, v));
+        }
+    }
+    dp.into_iter().map(Option::unwrap).collect()
+}
+
+/// Computes the DP value of each node as if it were the root with the rerooting technique.
+///
+/// `adj[v]` is a list of `(u, e)`, where `e` is the label of the edge such as an index or a weight.
+/// The value of `v` rooted at `v` is
+/// `put_vertex(merge(put_edge(dp[c1], e1), put_edge(dp[c2], e2), …), v)` over its children `c`,
+/// where the empty merge is `identity`.
+/// `merge` must be associative and commutative with `identity`.
+///
+/// Each value excluding one child is obtained by the prefix and suffix merges of the children.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_dp::reroot_dp;
+///
+/// // 0 - 1 - 2 - 3
+/// let adj = vec![vec![(1, 0)], vec![(0, 0), (2, 1)], vec![(1, 1), (3, 2)], vec![(2, 2)]];
+/// // (the number of nodes, the sum of the distances to them)
+/// let dp = reroot_dp(
+///     &adj,
+///     (0, 0),
+///     |a, b| (a.0 + b.0, a.1 + b.1),
+///     |&(cnt, sum), _| (cnt, sum + cnt),
+///     |&(cnt, sum), _| (cnt + 1, sum),
+/// );
+/// assert_eq!(dp, vec![(4, 6), (4, 4), (4, 4), (4, 6)]);
+/// ```
+pub fn reroot_dp<T, E, M, PE, PV>(
+    adj: &[Vec<(usize, E)>],
+    identity: T,
+    merge: M,
+    put_edge: PE,
+    put_vertex: PV,
+) -> Vec<T>
+where
+    T: Clone,
+    E: Copy,
+    M: Fn(&T, &T) -> T,
+    PE: Fn(&T, E) -> T,
+    PV: Fn(&T, usize) -> T,
+{
+    let n = adj.len();
+    if n == 0 {
+        return vec![];
+    }
+    let (order, parent) = bfs_order(adj, 0, |&(u, _)| u);
+
+    // the values of the subtrees rooted at 0
+    let mut down = vec![identity.clone(); n];
+    for &v in order.iter().rev() {
+        let mut acc = identity.clone();
+        for &(u, e) in &adj[v] {
+            if parent[u] == v {
+                acc = merge(&acc, &put_edge(&down[u], e));
+            }
+        }
+        down[v] = put_vertex(&acc, v);
+    }
+
+    // up[v] is the value of the parent of `v` rooted at it, without the subtree of `v`
+    let mut up = vec![identity.clone(); n];
+    let mut ret = vec![identity.clone(); n];
+    for &v in &order {
+        let vals = adj[v]
+            .iter()
+            .map(|&(u, e)| {
+                if parent[v] == u {
+                    put_edge(&up[v], e)
+                } else {
+                    put_edge(&down[u], e)
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut suffix = vec![identity.clone(); vals.len() + 1];
+        for i in (0..vals.len()).rev() {
+            suffix[i] = merge(&vals[i], &suffix[i + 1]);
+        }
+        ret[v] = put_vertex(&suffix[0], v);
+
+        let mut prefix = identity.clone();
+        for (i, &(u, _)) in adj[v].iter().enumerate() {
+            if parent[v] != u {
+                up[u] = put_vertex(&merge(&prefix, &suffix[i + 1]), v);
+            }
+            prefix = merge(&prefix, &vals[i]);
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    #[should_panic(expected = "the graph must be a connected tree")]
+    fn cycle() {
+        let adj = vec![vec![1, 2], vec![0, 2], vec![0, 1]];
+        bfs_order(&adj, 0, |&u| u);
+    }
+
+    #[test]
+    fn sum_of_distances() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..30 {
+            let mut adj = vec![vec![]; n];
+            for v in 1..n {
+                let (p, w) = (rand() % v, rand() % 10);
+                adj[p].push((v, w));
+                adj[v].push((p, w));
+            }
+
+            // the weighted distances from each node with DFS
+            let expected = (0..n)
+                .map(|s| {
+                    let mut dist = vec![None; n];
+                    dist[s] = Some(0);
+                    let mut stack = vec![s];
+                    while let Some(v) = stack.pop() {
+                        for &(u, w) in &adj[v] {
+                            if dist[u].is_none() {
+                                dist[u] = Some(dist[v].unwrap() + w);
+                                stack.push(u);
+                            }
+                        }
+                    }
+                    (n, dist.into_iter().map(Option::unwrap).sum::<usize>())
+                })
+                .collect::<Vec<_>>();
+            let dp = reroot_dp(
+                &adj,
+                (0, 0),
+                |a, b| (a.0 + b.0, a.1 + b.1),
+                |&(cnt, sum), w| (cnt, sum + cnt * w),
+                |&(cnt, sum), _| (cnt + 1, sum),
+            );
+            assert_eq!(dp, expected);
+
+            // the sizes of the subtrees rooted at each node sum up to the unweighted distances
+            let unweighted = adj
+                .iter()
+                .map(|a| a.iter().map(|&(u, _)| u).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let depth_sum = reroot_dp(
+                &adj,
+                (0, 0),
+                |a, b| (a.0 + b.0, a.1 + b.1),
+                |&(cnt, sum), _| (cnt, sum + cnt),
+                |&(cnt, sum), _| (cnt + 1, sum),
+            );
+            for (root, &(cnt, sum)) in depth_sum.iter().enumerate() {
+                let size = subtree_dp(&unweighted, root, |_| 1, |acc, s, _, _| acc + s);
+                assert_eq!(size[root], cnt);
+                assert_eq!(size.iter().sum::<usize>() - n, sum);
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::bfs_order;
+
+/// Returns the one or two centers of a tree, by removing the leaves repeatedly.
+fn centers(n: usize, adj: &[Vec<usize>]) -> Vec<usize> {
+    if n <= 2 {
+        return (0..n).collect();
+    }
+    let mut degree = adj.iter().map(|a| a.len()).collect::<Vec<_>>();
+    let mut leaves = (0..n).filter(|&v| degree[v] == 1).collect::<Vec<_>>();
+    let mut remaining = n;
+    while remaining > 2 {
+        remaining -= leaves.len();
+        let mut next = vec![];
+        for &v in &leaves {
+            for &u in &adj[v] {
+                degree[u] -= 1;
+                if degree[u] == 1 {
+                    next.push(u);
+                }
+            }
+        }
+        leaves = next;
+    }
+    leaves.sort_unstable();
+    leaves
+}
+
+/// Returns the bracket notation of the tree rooted at `root`, where the children are sorted canonically.
+///
+/// The classes of the subtrees are numbered by the AHU algorithm:
+/// in ascending order of the height and then of the sorted classes of the children.
+/// The order does not depend on the tree, so it gives the canonical order of the children.
+fn rooted_form(n: usize, adj: &[Vec<usize>], root: usize) -> Vec<u8> {
+    let (order, parent) = bfs_order(adj, root, |&u| u);
+
+    let mut height = vec![0; n];
+    for &v in order.iter().rev() {
+        if v != root {
+            height[parent[v]] = height[parent[v]].max(height[v] + 1);
+        }
+    }
+    let mut by_height = vec![vec![]; height[root] + 1];
+    for v in 0..n {
+        by_height[height[v]].push(v);
+    }
+
+    let mut class = vec![0; n];
+    let mut next_class = 0;
+    for nodes in by_height {
+        let mut keyed = nodes
+            .into_iter()
+            .map(|v| {
+                let mut key = adj[v]
+                    .iter()
+                    .filter(|&&u| u != parent[v])
+                    .map(|&u| class[u])
+                    .collect::<Vec<_>>();
+                key.sort_unstable();
+                (key, v)
+            })
+            .collect::<Vec<_>>();
+        keyed.sort_unstable();
+        for (i, (key, v)) in keyed.iter().enumerate() {
+            if i > 0 && keyed[i - 1].0 != *key {
+                next_class += 1;
+            }
+            class[*v] = next_class;
+        }
+        next_class += 1;
+    }
+
+    let mut form = Vec::with_capacity(2 * n);
+    // (node, whether its children are already pushed)
+    let mut stack = vec![(root, false)];
+    while let Some((v, expanded)) = stack.pop() {
+        if expanded {
+            form.push(b')');
+            continue;
+        }
+        form.push(b'(');
+        stack.push((v, true));
+        let mut children = adj[v]
+            .iter()
+            .copied()
+            .filter(|&u| u != parent[v])
+            .collect::<Vec<_>>();
+        // the smallest class is visited first
+        children.sort_unstable_by_key(|&u| core::cmp::Reverse(class[u]));
+        stack.extend(children.into_iter().map(|u| (u, false)));
+    }
+    form
+}
+
+/// Returns the canonical form of an unrooted tree as a bracket notation.
+///
+/// The tree is rooted at its center, or at the center giving the smaller form if it has two centers.
+/// Two trees are isomorphic if and only if their canonical forms are equal.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `adj` does not have `n` nodes, or the graph is not a tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_iso::tree_canonical_form;
+///
+/// // 0 - 1 - 2
+/// let adj = vec![vec![1], vec![0, 2], vec![1]];
+/// assert_eq!(tree_canonical_form(3, &adj), b"(()())".to_vec());
+/// ```
+pub fn tree_canonical_form(n: usize, adj: &[Vec<usize>]) -> Vec<u8> {
+    assert_eq!(adj.len(), n);
+    centers(n, adj)
+        .into_iter()
+        .map(|c| rooted_form(n, adj, c))
+        .min()
+        .unwrap_or_default()
+}
+
+/// Returns `true` if two unrooted trees are isomorphic, with the AHU algorithm.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `adj1` or `adj2` does not have `n1` or `n2` nodes respectively.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_iso::are_trees_isomorphic;
+///
+/// // a star centered at 0, a star centered at 2, and a path
+/// let star0 = vec![vec![1, 2, 3], vec![0], vec![0], vec![0]];
+/// let star2 = vec![vec![2], vec![2], vec![0, 1, 3], vec![2]];
+/// let path = vec![vec![1], vec![0, 2], vec![1, 3], vec![2]];
+/// assert!(are_trees_isomorphic(4, &star0, 4, &star2));
+/// assert!(!are_trees_isomorphic(4, &star0, 4, &path));
+/// ```
+pub fn are_trees_isomorphic(
+    n1: usize,
+    adj1: &[Vec<usize>],
+    n2: usize,
+    adj2: &[Vec<usize>],
+) -> bool {
+    assert_eq!(adj1.len(), n1);
+    assert_eq!(adj2.len(), n2);
+    n1 == n2 && tree_canonical_form(n1, adj1) == tree_canonical_form(n2, adj2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..8 {
+            for _ in 0..30 {
+                let edges1 = (1..n).map(|v| (rand() % v, v)).collect::<Vec<_>>();
+                let edges2 = (1..n).map(|v| (rand() % v, v)).collect::<Vec<_>>();
+                let to_adj = |edges: &[(usize, usize)], perm: &[usize]| {
+                    let mut adj = vec![vec![]; n];
+                    for &(u, v) in edges {
+                        adj[perm[u]].push(perm[v]);
+                        adj[perm[v]].push(perm[u]);
+                    }
+                    adj
+                };
+
+                // a relabeled copy is isomorphic
+                let mut perm = (0..n).collect::<Vec<_>>();
+                for i in (1..n).rev() {
+                    perm.swap(i, rand() % (i + 1));
+                }
+                let id = (0..n).collect::<Vec<_>>();
+                let adj1 = to_adj(&edges1, &id);
+                assert!(are_trees_isomorphic(n, &adj1, n, &to_adj(&edges1, &perm)));
+
+                // compare with all the permutations
+                let adj2 = to_adj(&edges2, &id);
+                let mut set2 = edges2
+                    .iter()
+                    .map(|&(u, v)| (u.min(v), u.max(v)))
+                    .collect::<Vec<_>>();
+                set2.sort_unstable();
+                let mut expected = false;
+                let mut p = id.clone();
+                loop {
+                    let mut mapped = edges1
+                        .iter()
+                        .map(|&(u, v)| (p[u].min(p[v]), p[u].max(p[v])))
+                        .collect::<Vec<_>>();
+                    mapped.sort_unstable();
+                    if mapped == set2 {
+                        expected = true;
+                        break;
+                    }
+                    // next permutation
+                    match (1..n).rev().find(|&i| p[i - 1] < p[i]) {
+                        None => break,
+                        Some(i) => {
+                            let j = (i..n).rev().find(|&j| p[j] > p[i - 1]).unwrap();
+                            p.swap(i - 1, j);
+                            p[i..].reverse();
+                        }
+                    }
+                }
+                assert_eq!(are_trees_isomorphic(n, &adj1, n, &adj2), expected);
+            }
+        }
+        assert!(!are_trees_isomorphic(1, &[vec![]], 2, &[vec![1], vec![0]]));
+    }
+}
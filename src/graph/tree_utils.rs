@@ -0,0 +1,459 @@
+use super::{bfs_order, LCA};
+use core::cmp::Reverse;
+
+/// Returns the size of the subtree of each node in the tree rooted at `root`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::subtree_sizes;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(subtree_sizes(&adj, 0), vec![5, 3, 1, 1, 1]);
+/// assert_eq!(subtree_sizes(&adj, 3), vec![2, 4, 1, 5, 1]);
+/// ```
+pub fn subtree_sizes(adj: &[Vec<usize>], root: usize) -> Vec<usize> {
+    assert!(root < adj.len());
+    let (order, parent) = bfs_order(adj, root, |&u| u);
+    let mut size = vec![1; adj.len()];
+    for &v in order.iter().rev() {
+        if v != root {
+            size[parent[v]] += size[v];
+        }
+    }
+    size
+}
+
+/// Returns all the centroids of the tree in ascending order.
+///
+/// A centroid is a node whose removal leaves no component with more than *n*/2 nodes.
+/// A non-empty tree has one or two centroids, and two centroids are adjacent.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::find_all_centroids;
+///
+/// // 0 - 1 - 2 - 3
+/// let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2]];
+/// assert_eq!(find_all_centroids(&adj), vec![1, 2]);
+///
+/// // 0 - 1 - 2 - 3 - 4
+/// let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+/// assert_eq!(find_all_centroids(&adj), vec![2]);
+/// ```
+pub fn find_all_centroids(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    if n == 0 {
+        return vec![];
+    }
+    let (order, parent) = bfs_order(adj, 0, |&u| u);
+    let mut size = vec![1; n];
+    // the largest component after removing each node
+    let mut largest = vec![0; n];
+    for &v in order.iter().rev() {
+        largest[v] = largest[v].max(n - size[v]);
+        if v != 0 {
+            size[parent[v]] += size[v];
+            largest[parent[v]] = largest[parent[v]].max(size[v]);
+        }
+    }
+    (0..n).filter(|&v| largest[v] * 2 <= n).collect()
+}
+
+/// Returns a centroid of the tree, which is the smallest one if there are two.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is empty or not connected.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::find_centroid;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(find_centroid(&adj), 1);
+/// ```
+pub fn find_centroid(adj: &[Vec<usize>]) -> usize {
+    assert!(!adj.is_empty(), "the tree must not be empty");
+    find_all_centroids(adj)[0]
+}
+
+/// Encodes a labeled tree into its Prüfer sequence of length *n* - 2.
+///
+/// The smallest leaf is removed repeatedly and its neighbor is recorded,
+/// which gives a bijection between the labeled trees and the sequences in `[0, n)^(n - 2)`.
+/// Hence there are *n*^(*n* - 2) labeled trees (Cayley's formula).
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if `adj` does not have `n` nodes or the graph is not connected.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::prufer_encode;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(prufer_encode(5, &adj), vec![0, 1, 1]);
+/// ```
+pub fn prufer_encode(n: usize, adj: &[Vec<usize>]) -> Vec<usize> {
+    assert_eq!(adj.len(), n);
+    if n <= 2 {
+        return vec![];
+    }
+    // `n - 1` is never removed, so it is the root
+    let (_, parent) = bfs_order(adj, n - 1, |&u| u);
+    let mut degree = adj.iter().map(|a| a.len()).collect::<Vec<_>>();
+    let mut ptr = (0..n).find(|&v| degree[v] == 1).unwrap();
+    let mut leaf = ptr;
+    let mut seq = Vec::with_capacity(n - 2);
+    for _ in 0..n - 2 {
+        let next = parent[leaf];
+        seq.push(next);
+        degree[next] -= 1;
+        if degree[next] == 1 && next < ptr {
+            leaf = next;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    seq
+}
+
+/// Decodes a Prüfer sequence into the adjacency list of the labeled tree with `seq.len() + 2` nodes.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if an element of `seq` is not less than `seq.len() + 2`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::prufer_decode;
+///
+/// let adj = prufer_decode(&[0, 1, 1]);
+/// assert_eq!(adj, vec![vec![2, 1], vec![0, 3, 4], vec![0], vec![1], vec![1]]);
+/// ```
+pub fn prufer_decode(seq: &[usize]) -> Vec<Vec<usize>> {
+    let n = seq.len() + 2;
+    let mut degree = vec![1; n];
+    for &v in seq {
+        assert!(v < n, "the elements must be less than {}", n);
+        degree[v] += 1;
+    }
+
+    let mut adj = vec![vec![]; n];
+    let mut add_edge = |u: usize, v: usize| {
+        adj[u].push(v);
+        adj[v].push(u);
+    };
+    let mut ptr = (0..n).find(|&v| degree[v] == 1).unwrap();
+    let mut leaf = ptr;
+    for &v in seq {
+        add_edge(leaf, v);
+        degree[v] -= 1;
+        if degree[v] == 1 && v < ptr {
+            leaf = v;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    add_edge(leaf, n - 1);
+    adj
+}
+
+/// Returns the distances from `root` to all the nodes.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::tree_distances_from;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(tree_distances_from(&adj, 3), vec![2, 1, 3, 0, 2]);
+/// ```
+pub fn tree_distances_from(adj: &[Vec<usize>], root: usize) -> Vec<usize> {
+    assert!(root < adj.len());
+    let (order, parent) = bfs_order(adj, root, |&u| u);
+    let mut dist = vec![0; adj.len()];
+    for &v in &order[1..] {
+        dist[v] = dist[parent[v]] + 1;
+    }
+    dist
+}
+
+/// Returns the distances between all the pairs of nodes.
+///
+/// This operation is O(*n*²), so it is only for small trees; see [`TreeDistanceQuery`] for large ones.
+///
+/// # Panics
+///
+/// Panics if the graph is not a connected tree.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::all_pairs_tree_distances;
+///
+/// // 0 - 1 - 2
+/// let adj = vec![vec![1], vec![0, 2], vec![1]];
+/// assert_eq!(all_pairs_tree_distances(&adj), vec![vec![0, 1, 2], vec![1, 0, 1], vec![2, 1, 0]]);
+/// ```
+pub fn all_pairs_tree_distances(adj: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..adj.len())
+        .map(|v| tree_distances_from(adj, v))
+        .collect()
+}
+
+/// Returns the diameter of the tree and its endpoints `(diameter, u, v)`.
+///
+/// The farthest node `u` from any node is an endpoint of a diameter,
+/// and the farthest node `v` from `u` is the other one.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the graph is empty or not connected.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::tree_diameter;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// assert_eq!(tree_diameter(&adj), (3, 3, 2));
+/// ```
+pub fn tree_diameter(adj: &[Vec<usize>]) -> (usize, usize, usize) {
+    assert!(!adj.is_empty(), "the tree must not be empty");
+    let farthest = |dist: &[usize]| {
+        (0..dist.len())
+            .max_by_key(|&v| (dist[v], Reverse(v)))
+            .unwrap()
+    };
+    let u = farthest(&tree_distances_from(adj, 0));
+    let dist = tree_distances_from(adj, u);
+    let v = farthest(&dist);
+    (dist[v], u, v)
+}
+
+/// Answers the distances between two nodes of a tree with [`LCA`].
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::graph::tree_utils::TreeDistanceQuery;
+///
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let adj = vec![vec![1, 2], vec![0, 3, 4], vec![0], vec![1], vec![1]];
+/// let query = TreeDistanceQuery::new(&adj);
+/// assert_eq!(query.dist(3, 4), 2);
+/// assert_eq!(query.dist(3, 2), 3);
+/// assert_eq!(query.dist(2, 2), 0);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm | Worst case        |
+/// | --------- | ----------------- |
+/// | [`new`]   | O(*n* log(*n*))   |
+/// | [`dist`]  | O(log(*n*))       |
+///
+/// [`new`]: TreeDistanceQuery::new
+/// [`dist`]: TreeDistanceQuery::dist
+#[derive(Clone, Debug)]
+pub struct TreeDistanceQuery {
+    lca: LCA,
+}
+
+impl TreeDistanceQuery {
+    /// Creates a query structure from the adjacency list of a tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is empty.
+    pub fn new(adj: &[Vec<usize>]) -> Self {
+        Self {
+            lca: LCA::new(adj, 0),
+        }
+    }
+
+    /// Returns the number of edges on the path between `u` and `v`.
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.lca.dist(u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..30 {
+            let mut adj = vec![vec![]; n];
+            for v in 1..n {
+                let p = rand() % v;
+                adj[p].push(v);
+                adj[v].push(p);
+            }
+
+            // the component sizes after removing `v` are the subtree sizes of its neighbors rooted at `v`
+            let sizes = (0..n).map(|v| subtree_sizes(&adj, v)).collect::<Vec<_>>();
+            for (v, size) in sizes.iter().enumerate() {
+                assert_eq!(size[v], n);
+            }
+            let expected = (0..n)
+                .filter(|&v| adj[v].iter().all(|&u| sizes[v][u] * 2 <= n))
+                .collect::<Vec<_>>();
+            let centroids = find_all_centroids(&adj);
+            assert_eq!(centroids, expected);
+            assert!(centroids.len() == 1 || centroids.len() == 2);
+            assert_eq!(find_centroid(&adj), centroids[0]);
+        }
+    }
+
+    #[test]
+    fn prufer() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+        let sorted = |adj: &[Vec<usize>]| {
+            adj.iter()
+                .map(|a| {
+                    let mut a = a.clone();
+                    a.sort();
+                    a
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for n in 2..30 {
+            let mut adj = vec![vec![]; n];
+            for v in 1..n {
+                let p = rand() % v;
+                adj[p].push(v);
+                adj[v].push(p);
+            }
+            let seq = prufer_encode(n, &adj);
+            assert_eq!(seq.len(), n - 2);
+            assert_eq!(sorted(&prufer_decode(&seq)), sorted(&adj));
+
+            let seq = (0..n - 2).map(|_| rand() % n).collect::<Vec<_>>();
+            assert_eq!(prufer_encode(n, &prufer_decode(&seq)), seq);
+        }
+
+        // Cayley's formula: all the 5^3 sequences give distinct trees
+        let mut trees = (0..125)
+            .map(|x: usize| sorted(&prufer_decode(&[x % 5, x / 5 % 5, x / 25])))
+            .collect::<Vec<_>>();
+        trees.sort();
+        trees.dedup();
+        assert_eq!(trees.len(), 125);
+    }
+
+    #[test]
+    fn distances() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..30 {
+            let mut adj = vec![vec![]; n];
+            for v in 1..n {
+                let p = rand() % v;
+                adj[p].push(v);
+                adj[v].push(p);
+            }
+
+            // Floyd-Warshall
+            let mut expected = vec![vec![n; n]; n];
+            for v in 0..n {
+                expected[v][v] = 0;
+                for &u in &adj[v] {
+                    expected[v][u] = 1;
+                }
+            }
+            for k in 0..n {
+                for i in 0..n {
+                    for j in 0..n {
+                        expected[i][j] = expected[i][j].min(expected[i][k] + expected[k][j]);
+                    }
+                }
+            }
+
+            assert_eq!(all_pairs_tree_distances(&adj), expected);
+            let query = TreeDistanceQuery::new(&adj);
+            for (u, row) in expected.iter().enumerate() {
+                for (v, &d) in row.iter().enumerate() {
+                    assert_eq!(query.dist(u, v), d);
+                }
+            }
+            let (d, u, v) = tree_diameter(&adj);
+            assert_eq!(d, expected.iter().flatten().copied().max().unwrap());
+            assert_eq!(expected[u][v], d);
+        }
+    }
+}
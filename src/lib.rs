@@ -1,5 +1,12 @@
+pub mod algorithms;
 pub mod extends;
+pub mod geometry;
+pub mod graph;
 pub mod macros;
 pub mod math;
+pub mod string;
 pub mod structs;
 pub mod tools;
+
+#[cfg(test)]
+mod testing;
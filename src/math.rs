@@ -1,7 +1,9 @@
+use self::num::{Group, Monoid, One, Zero};
+
 #[cfg_attr(nightly, codesnip::entry("GcdLcm"))]
 pub mod gcd_lcm;
 #[codesnip::entry("GcdLcm")]
-pub use gcd_lcm::GcdLcm;
+pub use gcd_lcm::{ExtGcd, GcdLcm};
 
 // #[codesnip::entry("Rational", include("GcdLcm"))]
 #[cfg_attr(nightly, codesnip::entry("Rational", include("GcdLcm")))]
@@ -10,6 +12,36 @@ pub mod rational;
 #[cfg_attr(nightly, codesnip::entry("Prime"))]
 pub mod prime;
 
+#[cfg_attr(nightly, codesnip::entry("Complex"))]
+pub mod complex;
+#[codesnip::entry("Complex")]
+pub use complex::Complex;
+
+#[cfg_attr(nightly, codesnip::entry("Matrix", include("One", "Zero")))]
+pub mod matrix;
+#[codesnip::entry("Matrix")]
+pub use matrix::Matrix;
+
+#[cfg_attr(nightly, codesnip::entry("Sequences", include("Matrix")))]
+pub mod sequences;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("LinearRecurrence", include("ModInt", "Matrix", "Fps"))
+)]
+pub mod linear_recurrence;
+
+pub mod bit_utils;
+pub mod combinatorics;
+#[cfg_attr(nightly, codesnip::entry("LinearAlgebra"))]
+pub mod linear_algebra;
+#[cfg_attr(nightly, codesnip::entry("ModArithmetic", include("GcdLcm")))]
+pub mod mod_arithmetic;
 pub mod modint;
 pub mod multiset;
 pub mod num;
+
+#[cfg_attr(nightly, codesnip::entry("SosDp", include("Monoid", "Group")))]
+pub mod sos_dp;
+
+pub mod polynomial;
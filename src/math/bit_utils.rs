@@ -0,0 +1,105 @@
+/// Converts `n` to the reflected binary Gray code.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::bit_utils::to_gray;
+///
+/// assert_eq!(to_gray(0b0110), 0b0101);
+/// ```
+#[codesnip::entry("GrayCode")]
+pub fn to_gray(n: u64) -> u64 {
+    n ^ (n >> 1)
+}
+
+/// Converts the reflected binary Gray code `g` back to the binary number.
+///
+/// This is the inverse of [`to_gray`].
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::bit_utils::{from_gray, to_gray};
+///
+/// assert_eq!(from_gray(0b0101), 0b0110);
+/// assert_eq!(from_gray(to_gray(123_456_789)), 123_456_789);
+/// ```
+#[codesnip::entry("GrayCode")]
+pub fn from_gray(mut g: u64) -> u64 {
+    let mut shift = 1;
+    while shift < 64 {
+        g ^= g >> shift;
+        shift <<= 1;
+    }
+    g
+}
+
+/// Returns the position of `g` in the Gray code sequence.
+///
+/// It is the same as [`from_gray`].
+#[codesnip::entry("GrayCode")]
+pub fn gray_rank(g: u64) -> u64 {
+    from_gray(g)
+}
+
+/// Returns the `2^bits` Gray codes of `bits` bits in the standard order.
+///
+/// Adjacent codes differ in exactly one bit.
+///
+/// # Panics
+///
+/// Panics if `bits` is greater than `63`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::bit_utils::gray_code_sequence;
+///
+/// let v = gray_code_sequence(3).collect::<Vec<_>>();
+/// assert_eq!(v, vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+/// ```
+#[codesnip::entry("GrayCode")]
+pub fn gray_code_sequence(bits: u32) -> impl Iterator<Item = u64> {
+    assert!(bits < 64);
+    (0..1_u64 << bits).map(to_gray)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn inverse() {
+        for n in 0..1 << 16 {
+            assert_eq!(from_gray(to_gray(n)), n);
+            assert_eq!(to_gray(from_gray(n)), n);
+        }
+
+        let mut rand = xorshift(0x9E37_79B9_7F4A_7C15_u64, |seed| seed);
+        for n in (0..100_000)
+            .map(|_| rand())
+            .chain(vec![!0, 1 << 63, !0 >> 1])
+        {
+            assert_eq!(from_gray(to_gray(n)), n);
+            assert_eq!(to_gray(from_gray(n)), n);
+        }
+    }
+
+    #[test]
+    fn sequence() {
+        for bits in 0..12 {
+            let v = gray_code_sequence(bits).collect::<Vec<_>>();
+            assert_eq!(v.len(), 1 << bits);
+            for w in v.windows(2) {
+                assert_eq!((w[0] ^ w[1]).count_ones(), 1);
+            }
+            let mut sorted = v.clone();
+            sorted.sort_unstable();
+            assert!(sorted.iter().copied().eq(0..1 << bits));
+            for (i, &g) in v.iter().enumerate() {
+                assert_eq!(gray_rank(g), i as u64);
+            }
+        }
+    }
+}
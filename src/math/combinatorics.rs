@@ -0,0 +1,470 @@
+use super::modint::{ModInt, Modulo};
+use super::polynomial::Polynomial;
+
+/// Computes `C(n, k) mod M::MOD` with Lucas' theorem.
+///
+/// `M::MOD` must be a prime.
+/// It works even if `n` is larger than `M::MOD`, unlike [`ModIntFactMap`](super::modint::ModIntFactMap).
+///
+/// This operation is O(*p* log(*n*) / log(*p*)) where *p* is `M::MOD`, so it is meant for small primes.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::define_modulo;
+/// use lib_rust::math::combinatorics::comb_lucas;
+/// use lib_rust::math::modint::Modulo;
+///
+/// define_modulo! { Modulo7: usize = 7 }
+///
+/// // C(10, 3) = 120
+/// assert_eq!(comb_lucas::<Modulo7>(10, 3), 120 % 7);
+/// assert_eq!(comb_lucas::<Modulo7>(3, 10), 0);
+/// ```
+#[codesnip::entry("CombLucas", include("ModInt", "Modulo"))]
+pub fn comb_lucas<M>(mut n: u64, mut k: u64) -> ModInt<M>
+where
+    M: Modulo<Set = usize> + Clone,
+{
+    let p = M::MOD as u64;
+    let mut acc = ModInt::one();
+    while k > 0 {
+        let (a, b) = (n % p, k % p);
+        if a < b {
+            return ModInt::zero();
+        }
+        // C(a, b) with a < p
+        let b = b.min(a - b);
+        let mut num = ModInt::<M>::one();
+        let mut den = ModInt::<M>::one();
+        for i in 0..b {
+            num *= (a - i) as usize;
+            den *= (i + 1) as usize;
+        }
+        acc *= num / den;
+        n /= p;
+        k /= p;
+    }
+    acc
+}
+
+/// Returns `x` such that `a * x ≡ 1 (mod m)` for `gcd(a, m) = 1`.
+#[codesnip::entry("CombGranville")]
+fn inv_mod(a: u64, m: u64) -> u64 {
+    let (mut r0, mut r1) = (a as i128, m as i128);
+    let (mut x0, mut x1) = (1_i128, 0_i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        let r = r0 - q * r1;
+        r0 = r1;
+        r1 = r;
+        let x = x0 - q * x1;
+        x0 = x1;
+        x1 = x;
+    }
+    debug_assert_eq!(r0, 1);
+    x0.rem_euclid(m as i128) as u64
+}
+
+/// Computes `C(n, k) mod pᵉ` for a prime `p` with Granville's extension of Lucas' theorem.
+///
+/// This operation is O(*pᵉ* + log(*n*)) and uses O(*pᵉ*) memory, so it is meant for small prime powers.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::comb_granville;
+///
+/// // C(10, 3) = 120
+/// assert_eq!(comb_granville(10, 3, 2, 3), 0);
+/// assert_eq!(comb_granville(10, 3, 7, 2), 120 % 49);
+/// assert_eq!(comb_granville(10, 4, 3, 3), 210 % 27);
+/// ```
+#[codesnip::entry("CombGranville")]
+pub fn comb_granville(n: u64, k: u64, p: u64, e: u32) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let pe = p.pow(e);
+    if pe == 1 {
+        return 0;
+    }
+
+    // The exponent of `p` in `C(n, k)`, that is, the number of carries when adding `k` and `n - k` in base `p`.
+    let mut v = 0;
+    let mut q = n / p;
+    let (mut qk, mut qr) = (k / p, (n - k) / p);
+    while q > 0 {
+        v += q - qk - qr;
+        q /= p;
+        qk /= p;
+        qr /= p;
+    }
+    if v >= u64::from(e) {
+        return 0;
+    }
+
+    let mul = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(pe)) as u64;
+    let pow = |mut base: u64, mut exp: u64| {
+        let mut acc = 1 % pe;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = mul(acc, base);
+            }
+            base = mul(base, base);
+            exp >>= 1;
+        }
+        acc
+    };
+
+    // fact[i] = product of `j ≤ i` which is not divisible by `p`
+    let mut fact = vec![1 % pe; pe as usize + 1];
+    for i in 1..=pe {
+        let j = if i % p == 0 { 1 } else { i };
+        fact[i as usize] = mul(fact[i as usize - 1], j);
+    }
+    // `x!` without the factors `p`
+    let unit_fact = |mut x: u64| {
+        let mut acc = 1 % pe;
+        while x > 0 {
+            acc = mul(acc, pow(fact[pe as usize], x / pe));
+            acc = mul(acc, fact[(x % pe) as usize]);
+            x /= p;
+        }
+        acc
+    };
+
+    let num = mul(pow(p, v), unit_fact(n));
+    let den = mul(unit_fact(k), unit_fact(n - k));
+    mul(num, inv_mod(den, pe))
+}
+
+/// Computes the signed Stirling number of the first kind `s(n, k)`.
+///
+/// `|s(n, k)|` is the number of permutations of `n` elements with exactly `k` cycles,
+/// and the sign is `(-1)ⁿ⁻ᵏ`.
+///
+/// This operation is O(*nk*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::stirling_first;
+///
+/// assert_eq!(stirling_first(4, 2), 11);
+/// assert_eq!(stirling_first(5, 2), -50);
+/// assert_eq!(stirling_first(0, 0), 1);
+/// ```
+#[codesnip::entry("StirlingFirst", include("StirlingFirstUnsigned"))]
+// `is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn stirling_first(n: usize, k: usize) -> i64 {
+    let abs = stirling_first_unsigned(n, k) as i64;
+    if (n + k) % 2 == 0 {
+        abs
+    } else {
+        -abs
+    }
+}
+
+/// Computes the unsigned Stirling number of the first kind `[n, k]`,
+/// that is, the number of permutations of `n` elements with exactly `k` cycles.
+///
+/// This operation is O(*nk*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::stirling_first_unsigned;
+///
+/// assert_eq!(stirling_first_unsigned(4, 2), 11);
+/// assert_eq!(stirling_first_unsigned(5, 2), 50);
+/// ```
+#[codesnip::entry("StirlingFirstUnsigned")]
+pub fn stirling_first_unsigned(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    // dp[j] = [i, j]
+    let mut dp = vec![0_u64; k + 1];
+    dp[0] = 1;
+    for i in 0..n {
+        for j in (1..=k).rev() {
+            dp[j] = dp[j - 1] + i as u64 * dp[j];
+        }
+        dp[0] = 0;
+    }
+    dp[k]
+}
+
+/// Computes the Stirling number of the second kind `S(n, k)`,
+/// that is, the number of ways to partition a set of `n` elements into `k` non-empty subsets.
+///
+/// This operation is O(*nk*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::stirling_second;
+///
+/// assert_eq!(stirling_second(4, 2), 7);
+/// assert_eq!(stirling_second(5, 3), 25);
+/// ```
+#[codesnip::entry("StirlingSecond")]
+pub fn stirling_second(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    // dp[j] = S(i, j)
+    let mut dp = vec![0_u64; k + 1];
+    dp[0] = 1;
+    for _ in 0..n {
+        for j in (1..=k).rev() {
+            dp[j] = dp[j - 1] + j as u64 * dp[j];
+        }
+        dp[0] = 0;
+    }
+    dp[k]
+}
+
+/// Computes `[s(n, 0), s(n, 1), …, s(n, n)]`, the signed Stirling numbers of the first kind,
+/// as the coefficients of `x(x - 1)…(x - n + 1)`.
+///
+/// The product is computed by divide and conquer with the naive multiplication.
+///
+/// This operation is O(*n*²).
+///
+/// The values overflow `i64` for `n > 20`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::stirling_first_row;
+///
+/// assert_eq!(stirling_first_row(4), vec![0, -6, 11, -6, 1]);
+/// assert_eq!(stirling_first_row(0), vec![1]);
+/// ```
+#[codesnip::entry("StirlingFirstRow", include("Polynomial"))]
+pub fn stirling_first_row(n: usize) -> Vec<i64> {
+    // x(x - 1)…(x - n + 1) restricted to the factors `l..r`
+    fn product(l: usize, r: usize) -> Polynomial<i64> {
+        match r - l {
+            0 => Polynomial::new(vec![1]),
+            1 => Polynomial::new(vec![-(l as i64), 1]),
+            _ => {
+                let m = (l + r) / 2;
+                product(l, m) * product(m, r)
+            }
+        }
+    }
+    product(0, n).into_coef()
+}
+
+/// Computes the number of derangements `D(n)`, that is, permutations of `n` elements without fixed points.
+///
+/// `D(0) = 1, D(1) = 0, D(2) = 1, D(3) = 2, D(4) = 9, D(5) = 44, …`
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if the result overflows `u64`, which happens for `n > 20`.
+/// Use [`derangements_mod`] for larger `n`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::derangements;
+///
+/// assert_eq!(derangements(4), 9);
+/// assert_eq!(derangements(5), 44);
+/// ```
+#[codesnip::entry("Derangements")]
+pub fn derangements(n: u64) -> u64 {
+    // (D(i - 1), D(i))
+    let (mut prev, mut cur) = (1_u64, 0_u64);
+    if n == 0 {
+        return prev;
+    }
+    for i in 2..=n {
+        let next = (prev + cur)
+            .checked_mul(i - 1)
+            .expect("derangements overflowed u64");
+        prev = cur;
+        cur = next;
+    }
+    cur
+}
+
+/// Computes the number of derangements `D(n) mod modulus`.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::combinatorics::{derangements, derangements_mod};
+///
+/// assert_eq!(derangements_mod(5, 7), 44 % 7);
+/// assert_eq!(derangements_mod(20, 1_000_000_007), derangements(20) % 1_000_000_007);
+/// ```
+#[codesnip::entry("Derangements")]
+pub fn derangements_mod(n: u64, modulus: u64) -> u64 {
+    let m = u128::from(modulus);
+    let (mut prev, mut cur) = (1 % m, 0_u128);
+    if n == 0 {
+        return prev as u64;
+    }
+    for i in 2..=n {
+        let next = (prev + cur) % m * (u128::from(i - 1) % m) % m;
+        prev = cur;
+        cur = next;
+    }
+    cur as u64
+}
+
+/// Computes the subfactorial `!n`, which is the same as [`derangements`].
+#[codesnip::entry("Derangements")]
+pub fn subfactorial(n: u64) -> u64 {
+    derangements(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::ModInt1e9_7;
+
+    fn pascal(n: usize) -> Vec<Vec<u128>> {
+        let mut c = vec![vec![1_u128]];
+        for i in 1..=n {
+            let prev = &c[i - 1];
+            let row = (0..=i)
+                .map(|j| {
+                    let left = if j > 0 { prev[j - 1] } else { 0 };
+                    left + prev.get(j).copied().unwrap_or(0)
+                })
+                .collect();
+            c.push(row);
+        }
+        c
+    }
+
+    #[test]
+    fn lucas() {
+        crate::define_modulo! { Modulo5: usize = 5 }
+        crate::define_modulo! { Modulo13: usize = 13 }
+        let c = pascal(80);
+        for (n, row) in c.iter().enumerate() {
+            for (k, &x) in row.iter().enumerate() {
+                assert_eq!(comb_lucas::<Modulo5>(n as u64, k as u64), (x % 5) as usize);
+                assert_eq!(
+                    comb_lucas::<Modulo13>(n as u64, k as u64),
+                    (x % 13) as usize
+                );
+            }
+        }
+        assert_eq!(comb_lucas::<Modulo5>(1_000_000_000_000, 1), 0);
+        assert_eq!(comb_lucas::<Modulo13>(0, 0), 1);
+    }
+
+    #[test]
+    fn lucas_large_prime() {
+        // Without any carries, it is the same as the usual formula.
+        assert_eq!(
+            comb_lucas::<crate::math::modint::types::Modulo1e9_7>(100, 50),
+            {
+                let mut acc = ModInt1e9_7::new(1);
+                for i in 0..50 {
+                    acc = acc * (100 - i) / (i + 1);
+                }
+                acc
+            }
+        );
+    }
+
+    #[test]
+    fn stirling() {
+        // known rows
+        let first = [1, 10, 35, 50, 24];
+        let second = [1, 15, 25, 10, 1];
+        for k in 1..=5 {
+            assert_eq!(stirling_first_unsigned(5, k), first[5 - k]);
+            assert_eq!(stirling_second(5, k), second[k - 1]);
+        }
+        assert_eq!(stirling_first_unsigned(5, 0), 0);
+        assert_eq!(stirling_second(0, 0), 1);
+        assert_eq!(stirling_second(3, 5), 0);
+        assert_eq!(stirling_first(3, 5), 0);
+
+        // Σₖ [n, k] = n!, Σₖ s(n, k) = 0 for n ≥ 2
+        for n in 2..15 {
+            let fact = (1..=n as u64).product::<u64>();
+            assert_eq!(
+                (0..=n).map(|k| stirling_first_unsigned(n, k)).sum::<u64>(),
+                fact
+            );
+            assert_eq!((0..=n).map(|k| stirling_first(n, k)).sum::<i64>(), 0);
+        }
+    }
+
+    #[test]
+    fn stirling_row() {
+        for n in 0..18 {
+            let row = stirling_first_row(n);
+            assert_eq!(row.len(), n + 1);
+            for (k, &s) in row.iter().enumerate() {
+                assert_eq!(s, stirling_first(n, k));
+            }
+        }
+    }
+
+    #[test]
+    fn derangement() {
+        let expected = [1, 0, 1, 2, 9, 44, 265, 1854, 14833, 133_496];
+        for (n, &d) in expected.iter().enumerate() {
+            assert_eq!(derangements(n as u64), d);
+            assert_eq!(subfactorial(n as u64), d);
+            assert_eq!(derangements_mod(n as u64, 1000), d % 1000);
+        }
+        assert_eq!(derangements(20), 895_014_631_192_902_121);
+        assert_eq!(derangements_mod(0, 1), 0);
+
+        // D(n) = n D(n - 1) + (-1)ⁿ
+        let m = 998_244_353;
+        let mut prev = derangements_mod(999, m);
+        for n in 1000..1010 {
+            let d = derangements_mod(n, m);
+            assert_eq!(d, (n * prev + if n % 2 == 0 { 1 } else { m - 1 }) % m);
+            prev = d;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn derangement_overflow() {
+        derangements(21);
+    }
+
+    #[test]
+    fn granville() {
+        let c = pascal(100);
+        for &(p, e) in &[(2, 1), (2, 3), (2, 6), (3, 2), (5, 2), (7, 1), (11, 2)] {
+            let pe = (p as u128).pow(e);
+            for (n, row) in c.iter().enumerate() {
+                for (k, &x) in row.iter().enumerate() {
+                    assert_eq!(
+                        u128::from(comb_granville(n as u64, k as u64, p, e)),
+                        x % pe,
+                        "C({}, {}) mod {}^{}",
+                        n,
+                        k,
+                        p,
+                        e
+                    );
+                }
+            }
+        }
+        assert_eq!(comb_granville(3, 5, 2, 2), 0);
+        assert_eq!(comb_granville(5, 2, 3, 0), 0);
+    }
+}
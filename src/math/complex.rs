@@ -0,0 +1,180 @@
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A complex number `re + im i`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::Complex;
+///
+/// let a = Complex::new(1, 2);
+/// let b = Complex::new(3, -1);
+/// assert_eq!(a + b, Complex::new(4, 1));
+/// assert_eq!(a - b, Complex::new(-2, 3));
+/// assert_eq!(a * b, Complex::new(5, 5));
+/// assert_eq!(a.conj(), Complex::new(1, -2));
+/// assert_eq!(a.norm_sq(), 5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Neg<Output = T>,
+{
+    /// Returns the complex conjugate `re - im i`.
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the squared absolute value `re² + im²`.
+    pub fn norm_sq(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Complex<f64> {
+    /// Returns the absolute value.
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the argument in `(-π, π]`.
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Creates a complex number from the polar form `r e^{iθ}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::Complex;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Complex::from_polar(2.0, PI / 2.0);
+    /// assert!(z.re.abs() < 1e-9);
+    /// assert!((z.im - 2.0).abs() < 1e-9);
+    /// assert!((z.norm() - 2.0).abs() < 1e-9);
+    /// assert!((z.arg() - PI / 2.0).abs() < 1e-9);
+    /// ```
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Returns the multiplicative inverse `1 / self`.
+    ///
+    /// The result is NaN if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::Complex;
+    ///
+    /// let z = Complex::new(3.0, 4.0);
+    /// let one = z * z.inv();
+    /// assert!((one.re - 1.0).abs() < 1e-9);
+    /// assert!(one.im.abs() < 1e-9);
+    /// ```
+    pub fn inv(self) -> Self {
+        let n = self.norm_sq();
+        Self::new(self.re / n, -self.im / n)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: Default,
+{
+    fn from(re: T) -> Self {
+        Self::new(re, T::default())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:+}i", self.re, self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ops() {
+        let a = Complex::new(2_i64, 3);
+        let b = Complex::from(5_i64);
+        assert_eq!(-a, Complex::new(-2, -3));
+        assert_eq!(a * b, Complex::new(10, 15));
+        assert_eq!(a * a.conj(), Complex::from(a.norm_sq()));
+        // i² = -1
+        let i = Complex::new(0, 1);
+        assert_eq!(i * i, Complex::new(-1, 0));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1, 2).to_string(), "1+2i");
+        assert_eq!(Complex::new(0.5, 0.0).to_string(), "0.5+0i");
+        assert_eq!(Complex::new(1, -2).to_string(), "1-2i");
+    }
+
+    #[test]
+    fn float() {
+        let z = Complex::new(-3.0, 4.0);
+        assert!((z.norm() - 5.0).abs() < 1e-9);
+        let w = Complex::from_polar(z.norm(), z.arg());
+        assert!((w - z).norm() < 1e-9);
+        let u = Complex::new(1.0, 2.0);
+        assert!((z * u * u.inv() - z).norm() < 1e-9);
+    }
+}
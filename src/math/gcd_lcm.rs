@@ -67,6 +67,111 @@ macro_rules! impl_gcd_lcm_int {
 
 impl_gcd_lcm_int! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
 
+/// The extended Euclidean algorithm on primitive integers.
+///
+/// It is separated from [`GcdLcm`] because the coefficients cannot be returned through references.
+pub trait ExtGcd: Sized {
+    /// Returns `(g, x, y)` such that `self * x + other * y == g`, where `g` is the non-negative gcd.
+    ///
+    /// For unsigned integers, `x` and `y` are represented in two's complement,
+    /// i.e. the identity holds with the wrapping arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::gcd_lcm::ExtGcd;
+    ///
+    /// let (g, x, y) = 240_i32.bezout(46);
+    /// assert_eq!(g, 2);
+    /// assert_eq!(240 * x + 46 * y, 2);
+    ///
+    /// let (g, x, y) = 240_u32.bezout(46);
+    /// assert_eq!(g, 2);
+    /// assert_eq!(240_u32.wrapping_mul(x).wrapping_add(46_u32.wrapping_mul(y)), 2);
+    /// ```
+    fn bezout(self, other: Self) -> (Self, Self, Self);
+
+    /// Returns the inverse of `self` modulo `modulus` in `[0, modulus)`,
+    /// or `None` if `self` and `modulus` are not coprime.
+    ///
+    /// It does not overflow even if `modulus` is close to the maximum value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::gcd_lcm::ExtGcd;
+    ///
+    /// assert_eq!(3_u32.modular_inverse(7), Some(5));
+    /// assert_eq!((-3_i64).modular_inverse(7), Some(2));
+    /// assert_eq!(4_u64.modular_inverse(6), None);
+    /// ```
+    fn modular_inverse(self, modulus: Self) -> Option<Self>;
+}
+
+macro_rules! impl_ext_gcd_int {
+    (@impl $t:ty, |$g:ident, $x:ident, $y:ident| $normalize:expr) => {
+        impl ExtGcd for $t {
+            fn bezout(self, other: $t) -> ($t, $t, $t) {
+                let (mut a, mut b) = (self, other);
+                let (mut x0, mut x1): ($t, $t) = (1, 0);
+                let (mut y0, mut y1): ($t, $t) = (0, 1);
+                while b != 0 {
+                    let q = a / b;
+                    let r = a - q * b;
+                    a = b;
+                    b = r;
+                    let x2 = x0.wrapping_sub(q.wrapping_mul(x1));
+                    x0 = x1;
+                    x1 = x2;
+                    let y2 = y0.wrapping_sub(q.wrapping_mul(y1));
+                    y0 = y1;
+                    y1 = y2;
+                }
+                let ($g, $x, $y) = (a, x0, y0);
+                $normalize
+            }
+
+            fn modular_inverse(self, modulus: $t) -> Option<$t> {
+                assert!(modulus > 0);
+                // the coefficients alternate in sign, so only their absolute values are kept
+                let (mut a, mut b) = (self.rem_euclid(modulus), modulus);
+                let (mut x0, mut x1): ($t, $t) = (1, 0);
+                let mut negative = false;
+                while b != 0 {
+                    let q = a / b;
+                    let r = a - q * b;
+                    a = b;
+                    b = r;
+                    let x2 = x0 + q * x1;
+                    x0 = x1;
+                    x1 = x2;
+                    negative = !negative;
+                }
+                if a != 1 {
+                    None
+                } else if negative {
+                    Some((modulus - x0) % modulus)
+                } else {
+                    Some(x0 % modulus)
+                }
+            }
+        }
+    };
+    (signed $($t:ty)*) => {$(
+        impl_ext_gcd_int! { @impl $t, |g, x, y| if g < 0 { (-g, -x, -y) } else { (g, x, y) } }
+    )*};
+    (unsigned $($t:ty)*) => {$(
+        impl_ext_gcd_int! { @impl $t, |g, x, y| (g, x, y) }
+    )*};
+}
+
+impl_ext_gcd_int! { signed i8 i16 i32 i64 i128 isize }
+impl_ext_gcd_int! { unsigned u8 u16 u32 u64 u128 usize }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,4 +205,39 @@ mod tests {
         assert_eq!(-c, a.lcm(-b));
         assert_eq!(c, (-a).lcm(-b));
     }
+
+    #[test]
+    fn bezout_test() {
+        for a in -50_i64..=50 {
+            for b in -50_i64..=50 {
+                let (g, x, y) = a.bezout(b);
+                assert_eq!(g, a.gcd(b).abs(), "{} {}", a, b);
+                assert_eq!(a * x + b * y, g, "{} {}", a, b);
+
+                let (a, b) = (a as u8, b as u8);
+                let (g, x, y) = a.bezout(b);
+                assert_eq!(g, a.gcd(b));
+                assert_eq!(a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)), g);
+            }
+        }
+    }
+
+    #[test]
+    fn modular_inverse_test() {
+        for m in 1_i32..=60 {
+            for a in -60..=60 {
+                let expected = (0..m).find(|&x| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(a.modular_inverse(m), expected, "{} {}", a, m);
+            }
+        }
+        for &m in &[255_u8, 254, 251, 128] {
+            for a in 0..=255_u8 {
+                let expected = (0..m).find(|&x| (a as u32 * x as u32) % m as u32 == 1);
+                assert_eq!(a.modular_inverse(m), expected, "{} {}", a, m);
+            }
+        }
+        let m = 18_446_744_073_709_551_557_u64; // 2^64 - 59 is prime
+        let x = 1_234_567_u64.modular_inverse(m).unwrap();
+        assert_eq!((x as u128 * 1_234_567) % m as u128, 1);
+    }
 }
@@ -0,0 +1,140 @@
+/// Performs Gauss-Jordan elimination over GF(2), where each row is a bit vector, and returns the rank.
+///
+/// After the elimination, `mat[..rank]` is in the reduced row echelon form:
+/// the leading bits are strictly decreasing and each of them is set only in its row.
+/// The rest of the rows are zero.
+///
+/// This operation is O(64*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_algebra::gauss_gf2;
+///
+/// let mut mat = vec![0b110, 0b011, 0b101];
+/// assert_eq!(gauss_gf2(&mut mat), 2);
+/// assert_eq!(mat, vec![0b101, 0b011, 0]);
+/// ```
+pub fn gauss_gf2(mat: &mut [u64]) -> usize {
+    let mut rank = 0;
+    for bit in (0..64).rev() {
+        let pivot = match (rank..mat.len()).find(|&i| mat[i] >> bit & 1 == 1) {
+            Some(i) => i,
+            None => continue,
+        };
+        mat.swap(rank, pivot);
+        let row = mat[rank];
+        for (i, x) in mat.iter_mut().enumerate() {
+            if i != rank && *x >> bit & 1 == 1 {
+                *x ^= row;
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Returns a basis of the span of `vectors` over GF(2), i.e. with XOR as the addition.
+///
+/// The basis is in the reduced row echelon form as [`gauss_gf2`],
+/// so the leading bits are distinct and in descending order.
+///
+/// This operation is O(64*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_algebra::xor_basis;
+///
+/// assert_eq!(xor_basis(&[3, 5, 6, 0]), vec![5, 3]);
+/// ```
+pub fn xor_basis(vectors: &[u64]) -> Vec<u64> {
+    let mut basis = vectors.to_vec();
+    let rank = gauss_gf2(&mut basis);
+    basis.truncate(rank);
+    basis
+}
+
+/// Returns `true` if `value` is the XOR of a subset of `basis`, which is a result of [`xor_basis`].
+///
+/// This operation is O(*r*) where *r* is the size of the basis.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_algebra::{span_contains, xor_basis};
+///
+/// let basis = xor_basis(&[0b1100, 0b0110]);
+/// assert!(span_contains(&basis, 0b1010));
+/// assert!(span_contains(&basis, 0));
+/// assert!(!span_contains(&basis, 0b0010));
+/// ```
+pub fn span_contains(basis: &[u64], value: u64) -> bool {
+    basis.iter().fold(value, |v, &b| v.min(v ^ b)) == 0
+}
+
+/// Returns the maximum of `external` XOR a subset of `basis`, which is a result of [`xor_basis`].
+///
+/// The leading bits are decided greedily from the highest one.
+///
+/// This operation is O(*r*) where *r* is the size of the basis.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_algebra::{max_xor_subset, xor_basis};
+///
+/// let basis = xor_basis(&[2, 4, 5]);
+/// assert_eq!(max_xor_subset(&basis, 0), 7);
+/// assert_eq!(max_xor_subset(&basis, 8), 15);
+/// ```
+pub fn max_xor_subset(basis: &[u64], external: u64) -> u64 {
+    basis.iter().fold(external, |v, &b| v.max(v ^ b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..8 {
+            for _ in 0..30 {
+                // small vectors to make the span overlap
+                let vectors = (0..n).map(|_| rand() % 64).collect::<Vec<_>>();
+                let span = (0..1_u32 << n)
+                    .map(|s| {
+                        (0..n)
+                            .filter(|&i| s >> i & 1 == 1)
+                            .fold(0, |acc, i| acc ^ vectors[i])
+                    })
+                    .collect::<BTreeSet<_>>();
+
+                let basis = xor_basis(&vectors);
+                assert_eq!(1 << basis.len(), span.len());
+                for w in basis.windows(2) {
+                    assert!(w[0].leading_zeros() < w[1].leading_zeros());
+                }
+                for x in 0..64 {
+                    assert_eq!(span_contains(&basis, x), span.contains(&x));
+                    let expected = span.iter().map(|&y| x ^ y).max().unwrap();
+                    assert_eq!(max_xor_subset(&basis, x), expected);
+                }
+
+                let mut mat = vectors.clone();
+                assert_eq!(gauss_gf2(&mut mat), basis.len());
+                assert!(mat[basis.len()..].iter().all(|&x| x == 0));
+            }
+        }
+
+        // full 64-bit vectors
+        let vectors = (0..100).map(|_| rand()).collect::<Vec<_>>();
+        let basis = xor_basis(&vectors);
+        assert_eq!(basis.len(), 64);
+        assert_eq!(max_xor_subset(&basis, 0), !0);
+    }
+}
@@ -0,0 +1,308 @@
+use super::matrix::nth_linear_recurrence;
+use super::modint::{ModInt, Modulo};
+use super::polynomial::{mul_trunc, ntt_root, Polynomial};
+
+/// Finds the shortest linear recurrence which generates `s` with the Berlekamp-Massey algorithm.
+///
+/// Returns `c` such that `s[i] = c[0] * s[i - 1] + c[1] * s[i - 2] + … + c[L - 1] * s[i - L]` for all `i ≥ L`.
+/// `M::MOD` must be a prime.
+/// To find a recurrence of length `L` correctly, `s` should have at least `2L` terms.
+///
+/// This operation is O(*n*²).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_recurrence::berlekamp_massey;
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+///
+/// let fib = [0, 1, 1, 2, 3, 5, 8, 13].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// assert_eq!(berlekamp_massey(&fib), vec![1, 1]);
+/// ```
+pub fn berlekamp_massey<M>(s: &[ModInt<M>]) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = usize> + Copy,
+{
+    // connection polynomial `1 - c[0] x - c[1] x² - …`
+    let mut cur = vec![ModInt::<M>::one()];
+    let mut prev = vec![ModInt::<M>::one()];
+    let mut len = 0;
+    // the discrepancy when `prev` was current, and the shift from it
+    let mut prev_d = ModInt::<M>::one();
+    let mut shift = 1;
+
+    for (n, &x) in s.iter().enumerate() {
+        let d = cur
+            .iter()
+            .skip(1)
+            .zip(s[..n].iter().rev())
+            .fold(x, |acc, (&c, &y)| acc + c * y);
+        if d == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let coef = d / prev_d;
+        let next = {
+            let mut next = cur.clone();
+            if next.len() < prev.len() + shift {
+                next.resize(prev.len() + shift, ModInt::zero());
+            }
+            for (i, &p) in prev.iter().enumerate() {
+                next[i + shift] -= coef * p;
+            }
+            next
+        };
+        if 2 * len <= n {
+            len = n + 1 - len;
+            prev = core::mem::replace(&mut cur, next);
+            prev_d = d;
+            shift = 1;
+        } else {
+            cur = next;
+            shift += 1;
+        }
+    }
+
+    cur.resize(len + 1, ModInt::zero());
+    cur.into_iter().skip(1).map(|c| -c).collect()
+}
+
+/// Computes the `n`-th term of the linear recurrence `rec` found by [`berlekamp_massey`],
+/// where `init` is the first terms.
+///
+/// This operation is O(*k*³ log(*n*)) where *k* is the length of `rec`.
+///
+/// # Panics
+///
+/// Panics if `init` is shorter than `rec`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_recurrence::{berlekamp_massey, nth_term_of_recurrence};
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+///
+/// // tribonacci numbers
+/// let s = [0, 0, 1, 1, 2, 4, 7, 13].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let rec = berlekamp_massey(&s);
+/// assert_eq!(rec, vec![1, 1, 1]);
+/// assert_eq!(nth_term_of_recurrence(&rec, &s, 9), 44);
+/// ```
+pub fn nth_term_of_recurrence<M>(rec: &[ModInt<M>], init: &[ModInt<M>], n: u64) -> ModInt<M>
+where
+    M: Modulo<Set = usize> + Copy,
+{
+    let k = rec.len();
+    assert!(init.len() >= k);
+    nth_linear_recurrence(rec, &init[..k], n)
+}
+
+/// Computes the `n`-th term of the linear recurrence `rec` with Kitamasa's method,
+/// where `init` is the first terms and `rec` is in the same form as [`berlekamp_massey`].
+///
+/// It computes `xⁿ` modulo the characteristic polynomial `xᵏ - rec[0] xᵏ⁻¹ - … - rec[k - 1]`
+/// by repeated squaring, and the remainder gives `a[n]` as a linear combination of `init`.
+/// The modulus should be a NTT-friendly prime such as `998244353`,
+/// otherwise the multiplications fall back to O(*k*²).
+///
+/// This operation is O(*k* log(*k*) log(*n*)) where *k* is the length of `rec`.
+///
+/// # Panics
+///
+/// Panics if `init` is shorter than `rec`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_recurrence::nth_term_kitamasa;
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+///
+/// // a[i] = a[i - 1] + 2a[i - 2]: 1, 1, 3, 5, 11, 21, …
+/// let rec = [Mint::new(1), Mint::new(2)];
+/// let init = [Mint::new(1), Mint::new(1)];
+/// assert_eq!(nth_term_kitamasa(&rec, &init, 5), 21);
+/// ```
+pub fn nth_term_kitamasa<M>(rec: &[ModInt<M>], init: &[ModInt<M>], n: u64) -> ModInt<M>
+where
+    M: Modulo<Set = usize> + Copy,
+{
+    let k = rec.len();
+    assert!(init.len() >= k);
+    if k == 0 {
+        return ModInt::zero();
+    }
+    if n < k as u64 {
+        return init[n as usize];
+    }
+
+    // `xᵏ ≡ rec[0] xᵏ⁻¹ + … + rec[k - 1]`, and `low` is the right-hand side
+    let low = rec.iter().rev().copied().collect::<Vec<_>>();
+    // the inverse of the reversed characteristic polynomial, to compute the quotients
+    let rev = core::iter::once(ModInt::one())
+        .chain(rec.iter().map(|&c| -c))
+        .collect::<Vec<_>>();
+    let rev_inv = Polynomial::new(rev).fps_inverse(k).into_coef();
+    // the primitive root is computed once for all the squarings
+    let root = ntt_root::<M>();
+
+    // the remainder of `a`, whose degree is less than `2k`, divided by the characteristic polynomial
+    let reduce = |mut a: Vec<ModInt<M>>| {
+        if a.len() <= k {
+            return a;
+        }
+        let m = a.len() - k;
+        let a_rev = a.iter().rev().take(m).copied().collect::<Vec<_>>();
+        let mut quotient = mul_trunc(&a_rev, &rev_inv, m, root);
+        quotient.reverse();
+        a.truncate(k);
+        for (x, y) in a.iter_mut().zip(mul_trunc(&quotient, &low, k, root)) {
+            *x += y;
+        }
+        a
+    };
+
+    let mut r = vec![ModInt::one()];
+    for i in (0..64 - n.leading_zeros()).rev() {
+        r = reduce(mul_trunc(&r, &r, 2 * r.len() - 1, root));
+        if n >> i & 1 == 1 {
+            r.insert(0, ModInt::zero());
+            r = reduce(r);
+        }
+    }
+    r.iter()
+        .zip(init)
+        .fold(ModInt::zero(), |acc, (&c, &x)| acc + c * x)
+}
+
+/// Computes the `n`-th term of the sequence starting with `s`,
+/// by finding its linear recurrence with [`berlekamp_massey`] and then applying [`nth_term_kitamasa`].
+///
+/// `s` should have at least twice as many terms as the length of the recurrence.
+///
+/// This operation is O(*m*² + *k* log(*k*) log(*n*)) where *m* is the length of `s`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::linear_recurrence::guess_nth_term;
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+///
+/// // the sums of squares
+/// let s = [0, 1, 5, 14, 30, 55, 91, 140, 204].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// assert_eq!(guess_nth_term(&s, 100), 338350);
+/// ```
+pub fn guess_nth_term<M>(s: &[ModInt<M>], n: u64) -> ModInt<M>
+where
+    M: Modulo<Set = usize> + Copy,
+{
+    nth_term_kitamasa(&berlekamp_massey(s), s, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::{ModInt1e9_7, ModInt998244353 as Mint};
+    use crate::testing::xorshift;
+
+    fn mints(v: &[usize]) -> Vec<Mint> {
+        v.iter().map(|&x| Mint::new(x)).collect()
+    }
+
+    #[test]
+    fn fibonacci() {
+        let mut fib = vec![Mint::new(0), Mint::new(1)];
+        for i in 2..20 {
+            let next = fib[i - 1] + fib[i - 2];
+            fib.push(next);
+        }
+        let rec = berlekamp_massey(&fib);
+        assert_eq!(rec, mints(&[1, 1]));
+        for (n, &x) in fib.iter().enumerate() {
+            assert_eq!(nth_term_of_recurrence(&rec, &fib, n as u64), x);
+        }
+        // F(100) mod 998244353
+        let f100 = (0..100)
+            .fold((Mint::new(0), Mint::new(1)), |(a, b), _| (b, a + b))
+            .0;
+        assert_eq!(nth_term_of_recurrence(&rec, &fib, 100), f100);
+    }
+
+    #[test]
+    fn tribonacci() {
+        let mut s = mints(&[3, 1, 4]);
+        for i in 3..20 {
+            let next = s[i - 1] + s[i - 2] + s[i - 3];
+            s.push(next);
+        }
+        let rec = berlekamp_massey(&s);
+        assert_eq!(rec, mints(&[1, 1, 1]));
+        assert_eq!(nth_term_of_recurrence(&rec, &s, 19), s[19]);
+    }
+
+    #[test]
+    fn random_recurrence() {
+        // a[i] = 5a[i-1] + 0a[i-2] + 7a[i-3] + 123456789a[i-4]
+        let c = [5, 0, 7, 123_456_789]
+            .iter()
+            .map(|&x| ModInt1e9_7::new(x))
+            .collect::<Vec<_>>();
+        let mut s = [2, 7, 1, 8]
+            .iter()
+            .map(|&x| ModInt1e9_7::new(x))
+            .collect::<Vec<_>>();
+        for i in 4..30 {
+            let next = (0..4).map(|j| c[j] * s[i - 1 - j]).sum();
+            s.push(next);
+        }
+        assert_eq!(berlekamp_massey(&s), c);
+    }
+
+    #[test]
+    fn trivial() {
+        assert!(berlekamp_massey::<crate::math::modint::types::Modulo998244353>(&[]).is_empty());
+        assert!(berlekamp_massey(&mints(&[0, 0, 0])).is_empty());
+        // geometric sequence
+        assert_eq!(berlekamp_massey(&mints(&[3, 6, 12, 24])), mints(&[2]));
+        // a single non-zero term needs a long recurrence
+        assert_eq!(berlekamp_massey(&mints(&[0, 0, 1])).len(), 3);
+    }
+
+    #[test]
+    fn kitamasa() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for k in 0..12 {
+            let rec = (0..k).map(|_| Mint::new(rand())).collect::<Vec<_>>();
+            let mut s = (0..k).map(|_| Mint::new(rand())).collect::<Vec<_>>();
+            for i in k..80 {
+                let next = (0..k).map(|j| rec[j] * s[i - 1 - j]).sum();
+                s.push(next);
+            }
+            for (n, &x) in s.iter().enumerate() {
+                assert_eq!(nth_term_kitamasa(&rec, &s, n as u64), x, "{} {}", k, n);
+            }
+            let n = 1_000_000_007;
+            assert_eq!(
+                nth_term_kitamasa(&rec, &s, n),
+                nth_term_of_recurrence(&rec, &s, n)
+            );
+            assert_eq!(guess_nth_term(&s, n), nth_term_of_recurrence(&rec, &s, n));
+        }
+
+        // a modulus which is not NTT-friendly
+        let rec = [5, 0, 7, 123_456_789]
+            .iter()
+            .map(|&x| ModInt1e9_7::new(x))
+            .collect::<Vec<_>>();
+        let init = [2, 7, 1, 8]
+            .iter()
+            .map(|&x| ModInt1e9_7::new(x))
+            .collect::<Vec<_>>();
+        let n = 1 << 40;
+        assert_eq!(
+            nth_term_kitamasa(&rec, &init, n),
+            nth_term_of_recurrence(&rec, &init, n)
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use super::{One, Zero};
+use core::ops::{Add, Index, IndexMut, Mul};
+
+/// A square matrix.
+///
+/// The size is given at runtime, since const generics are not available on Rust 1.42.
+/// Use `Matrix<ModInt<M>>` or [`mat_pow`] for the computations modulo a number.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::Matrix;
+///
+/// let a = Matrix::new(vec![vec![1, 1], vec![1, 0]]);
+/// assert_eq!(a.clone() * a.clone(), Matrix::new(vec![vec![2, 1], vec![1, 1]]));
+/// // Fibonacci numbers
+/// assert_eq!(a.pow(10)[(0, 1)], 55);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<T> {
+    data: Vec<Vec<T>>,
+}
+
+impl<T> Matrix<T> {
+    /// Creates a matrix from rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is not square.
+    pub fn new(data: Vec<Vec<T>>) -> Self {
+        let n = data.len();
+        assert!(data.iter().all(|row| row.len() == n));
+        Self { data }
+    }
+
+    /// Returns the number of rows (and columns).
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the rows.
+    pub fn rows(&self) -> &[Vec<T>] {
+        &self.data
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Creates the `n × n` zero matrix.
+    pub fn zero(n: usize) -> Self {
+        Self::new(vec![vec![T::zero(); n]; n])
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// Creates the `n × n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zero(n);
+        for (i, row) in m.data.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        m
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    /// Computes `self` to the power of `exp` by repeated squaring.
+    ///
+    /// This operation is O(*n*³ log(*exp*)).
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut acc = Self::identity(self.size());
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        acc
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.data[i][j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[i][j]
+    }
+}
+
+impl<T> Mul for Matrix<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Multiplies in O(*n*³).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sizes are different.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let n = self.size();
+        assert_eq!(n, rhs.size());
+        let mut ret = Self::zero(n);
+        for (row, lhs) in ret.data.iter_mut().zip(self.data.iter()) {
+            for (a, rhs) in lhs.iter().zip(rhs.data.iter()) {
+                for (c, b) in row.iter_mut().zip(rhs.iter()) {
+                    *c = c.clone() + a.clone() * b.clone();
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// Computes `a` to the power of `exp` modulo `modulus` by repeated squaring.
+///
+/// The products are computed in `u128`, so any `modulus` fits.
+///
+/// This operation is O(*n*³ log(*exp*)).
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::matrix::mat_pow;
+/// use lib_rust::math::Matrix;
+///
+/// // Fibonacci numbers
+/// let a = Matrix::new(vec![vec![1, 1], vec![1, 0]]);
+/// assert_eq!(mat_pow(&a, 100, 1_000_000_007)[(0, 1)], 687_995_182);
+/// assert_eq!(mat_pow(&a, 0, 1), Matrix::new(vec![vec![0, 0], vec![0, 0]]));
+/// ```
+pub fn mat_pow(a: &Matrix<u64>, mut exp: u64, modulus: u64) -> Matrix<u64> {
+    assert!(modulus > 0);
+    let n = a.size();
+    let mul = |x: &Matrix<u64>, y: &Matrix<u64>| {
+        let mut ret = Matrix::zero(n);
+        for (row, lhs) in ret.data.iter_mut().zip(x.data.iter()) {
+            for (&a, rhs) in lhs.iter().zip(y.data.iter()) {
+                for (c, &b) in row.iter_mut().zip(rhs.iter()) {
+                    *c = ((*c as u128 + a as u128 * b as u128) % modulus as u128) as u64;
+                }
+            }
+        }
+        ret
+    };
+
+    let mut acc = Matrix::identity(n);
+    for row in &mut acc.data {
+        for x in row.iter_mut() {
+            *x %= modulus;
+        }
+    }
+    let mut base = mul(a, &acc);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul(&acc, &base);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul(&base, &base);
+        }
+    }
+    acc
+}
+
+/// Computes the `n`-th term of the linear recurrence
+/// `a[k] = coeffs[0] * a[k - 1] + coeffs[1] * a[k - 2] + … + coeffs[N - 1] * a[k - N]`,
+/// where `init` is `[a[0], a[1], …, a[N - 1]]`.
+///
+/// Use [`ModInt`](crate::math::modint::ModInt) to compute it modulo a number.
+///
+/// This operation is O(*N*³ log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `init` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::matrix::nth_linear_recurrence;
+/// use lib_rust::math::modint::types::ModInt1e9_7 as Mint;
+///
+/// // Fibonacci numbers
+/// assert_eq!(nth_linear_recurrence(&[1_u64, 1], &[0, 1], 50), 12_586_269_025);
+///
+/// let one = Mint::new(1);
+/// assert_eq!(nth_linear_recurrence(&[one, one], &[Mint::new(0), one], 100), 687_995_182);
+/// ```
+pub fn nth_linear_recurrence<T>(coeffs: &[T], init: &[T], n: u64) -> T
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    let k = coeffs.len();
+    assert_eq!(k, init.len());
+    if k == 0 {
+        return T::zero();
+    }
+    if n < k as u64 {
+        return init[n as usize].clone();
+    }
+
+    // companion matrix mapping (a[i + k - 1], …, a[i]) to (a[i + k], …, a[i + 1])
+    let mut m = Matrix::zero(k);
+    for (j, c) in coeffs.iter().enumerate() {
+        m[(0, j)] = c.clone();
+    }
+    for i in 1..k {
+        m[(i, i - 1)] = T::one();
+    }
+    let m = m.pow(n - (k as u64 - 1));
+
+    // a[n] is the first entry of m * (a[k - 1], …, a[0])
+    m.data[0]
+        .iter()
+        .zip(init.iter().rev())
+        .fold(T::zero(), |acc, (a, b)| acc + a.clone() * b.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow() {
+        let a = Matrix::new(vec![vec![2_i64, 1, 0], vec![0, 1, 3], vec![-1, 0, 1]]);
+        let mut naive = Matrix::identity(3);
+        for e in 0..10 {
+            assert_eq!(a.pow(e), naive);
+            naive = naive * a.clone();
+        }
+        assert_eq!(Matrix::<i64>::new(vec![]).pow(5).size(), 0);
+    }
+
+    #[test]
+    fn pow_mod() {
+        let a = Matrix::new(vec![vec![2_u64, 1, 0], vec![0, 1, 3], vec![5, 0, 1]]);
+        let modulus = 1_000_000_007;
+        let mut naive = Matrix::identity(3);
+        for e in 0..20 {
+            assert_eq!(mat_pow(&a, e, modulus), naive);
+            naive = mat_pow(&(naive * a.clone()), 1, modulus);
+        }
+
+        // the entries larger than 2^32 do not overflow
+        let big = (1_u64 << 63) + 5;
+        let a = Matrix::new(vec![vec![big - 1, big - 2], vec![3, big - 4]]);
+        let mut naive = Matrix::identity(2);
+        for e in 0..10 {
+            assert_eq!(mat_pow(&a, e, big), naive);
+            let mut next = Matrix::zero(2);
+            for i in 0..2 {
+                for j in 0..2 {
+                    next[(i, j)] = (0..2).fold(0_u128, |acc, k| {
+                        (acc + naive[(i, k)] as u128 * a[(k, j)] as u128) % big as u128
+                    }) as u64;
+                }
+            }
+            naive = next;
+        }
+    }
+
+    #[test]
+    fn index() {
+        let mut a = Matrix::<i32>::identity(2);
+        a[(0, 1)] = 5;
+        assert_eq!(a.rows(), &[vec![1, 5], vec![0, 1]]);
+        assert_eq!(a[(1, 1)], 1);
+    }
+
+    #[test]
+    fn linear_recurrence() {
+        // a[k] = 2a[k-1] - a[k-2] + 3a[k-3]
+        let coeffs = [2_i64, -1, 3];
+        let mut a = vec![1_i64, -2, 4];
+        for k in 3..30 {
+            let next = 2 * a[k - 1] - a[k - 2] + 3 * a[k - 3];
+            a.push(next);
+        }
+        for (n, &x) in a.iter().enumerate() {
+            assert_eq!(nth_linear_recurrence(&coeffs, &a[..3], n as u64), x);
+        }
+        assert_eq!(nth_linear_recurrence::<i64>(&[], &[], 10), 0);
+    }
+}
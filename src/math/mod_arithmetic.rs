@@ -0,0 +1,110 @@
+use super::ExtGcd;
+
+fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+/// Returns the inverses of `0, 1, …, n` modulo the prime `p`, where the inverse of `0` is `0`.
+///
+/// It uses the recurrence `inv[i] = -(p / i) * inv[p % i]`, which follows from `p = (p / i) * i + p % i`.
+///
+/// This operation is O(*n*).
+///
+/// # Panics
+///
+/// Panics if `n >= p`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::mod_arithmetic::modinv_table;
+///
+/// assert_eq!(modinv_table(6, 7), vec![0, 1, 4, 5, 2, 3, 6]);
+/// ```
+pub fn modinv_table(n: usize, p: u64) -> Vec<u64> {
+    assert!((n as u64) < p);
+    let mut inv = vec![0; n + 1];
+    if n >= 1 {
+        inv[1] = 1;
+    }
+    for i in 2..=n {
+        let q = p / i as u64;
+        inv[i] = p - mul_mod(q, inv[(p % i as u64) as usize], p);
+    }
+    inv
+}
+
+/// Returns the inverses of `values` modulo the prime `p`.
+///
+/// It inverts only the product of all the values with [`ExtGcd::modular_inverse`],
+/// and the inverse of each value is recovered from the prefix and suffix products.
+///
+/// This operation is O(*n* + log(*p*)).
+///
+/// # Panics
+///
+/// Panics if any value is a multiple of `p`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::mod_arithmetic::modinv_sieve;
+///
+/// assert_eq!(modinv_sieve(&[3, 10, 6], 7), vec![5, 5, 6]);
+/// ```
+pub fn modinv_sieve(values: &[u64], p: u64) -> Vec<u64> {
+    // prefix[i] is the product of `values[..i]`
+    let mut prefix = Vec::with_capacity(values.len() + 1);
+    prefix.push(1 % p);
+    for &x in values {
+        assert!(x % p != 0, "{} is not invertible modulo {}", x, p);
+        prefix.push(mul_mod(*prefix.last().unwrap(), x, p));
+    }
+
+    // the inverse of the product of `values[..=i]`
+    let mut acc = prefix[values.len()].modular_inverse(p).unwrap();
+    let mut inv = vec![0; values.len()];
+    for (i, &x) in values.iter().enumerate().rev() {
+        inv[i] = mul_mod(acc, prefix[i], p);
+        acc = mul_mod(acc, x, p);
+    }
+    inv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn table() {
+        for &p in &[2, 3, 5, 7, 13, 998_244_353, 1_000_000_007] {
+            let n = (p - 1).min(10_000) as usize;
+            let inv = modinv_table(n, p);
+            assert_eq!(inv.len(), n + 1);
+            for (i, &x) in inv.iter().enumerate().skip(1) {
+                assert!(x < p);
+                assert_eq!(mul_mod(i as u64, x, p), 1, "{} {}", i, p);
+            }
+        }
+        assert_eq!(modinv_table(0, 2), vec![0]);
+    }
+
+    #[test]
+    fn sieve() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        // a prime close to 2⁶³
+        for &p in &[2, 7, 998_244_353, 9_223_372_036_854_775_783] {
+            let values = (0..100)
+                .map(|_| rand())
+                .filter(|&x| x % p != 0)
+                .collect::<Vec<_>>();
+            let inv = modinv_sieve(&values, p);
+            for (&x, &y) in values.iter().zip(&inv) {
+                assert_eq!(mul_mod(x % p, y, p), 1 % p);
+            }
+        }
+        assert!(modinv_sieve(&[], 7).is_empty());
+    }
+}
@@ -14,3 +14,41 @@ pub use alge_struct::{Group, Monoid, Semigroup};
 pub mod all_bit_one;
 #[codesnip::entry("AllBitOne")]
 pub use all_bit_one::AllBitOne;
+
+#[cfg_attr(nightly, codesnip::entry("Signed", include("Zero")))]
+pub mod signed;
+#[codesnip::entry("Signed")]
+pub use signed::Signed;
+
+#[cfg_attr(nightly, codesnip::entry("Integer", include("One", "Zero", "Signed")))]
+pub mod integer;
+#[codesnip::entry("Integer")]
+pub use integer::{Integer, SignedInteger, UnsignedInteger};
+
+#[cfg_attr(nightly, codesnip::entry("CheckedOps"))]
+pub mod checked;
+#[codesnip::entry("CheckedOps")]
+pub use checked::CheckedOps;
+
+#[cfg_attr(nightly, codesnip::entry("SaturatingOps"))]
+pub mod saturating;
+#[codesnip::entry("SaturatingOps")]
+pub use saturating::SaturatingOps;
+
+#[cfg_attr(nightly, codesnip::entry("WrappingOps"))]
+pub mod wrapping;
+#[codesnip::entry("WrappingOps")]
+pub use wrapping::WrappingOps;
+
+#[cfg_attr(nightly, codesnip::entry("Pow"))]
+pub mod pow;
+#[codesnip::entry("Pow")]
+pub use pow::Pow;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("OrderedF64", include("BoundedAbove", "BoundedBelow", "One", "Zero"))
+)]
+pub mod ordered_float;
+#[codesnip::entry("OrderedF64")]
+pub use ordered_float::OrderedF64;
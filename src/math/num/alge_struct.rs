@@ -8,3 +8,9 @@ pub mod group;
 pub use group::Group;
 
 pub mod types;
+
+pub mod pow;
+pub use pow::{fast_pow, monoid_pow, MonoidPow};
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,123 @@
+use super::Monoid;
+
+/// Computes `value ∘ value ∘ … ∘ value` (`exp` times) with repeated squaring.
+///
+/// It returns `M::id()` if `exp` is `0`.
+///
+/// This operation is O(log(*exp*)) operations.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::fast_pow;
+/// use lib_rust::math::num::alge_struct::types::{AddMonoid, MulMonoid};
+///
+/// assert_eq!(fast_pow::<AddMonoid<u64>>(7, 6), 42);
+/// assert_eq!(fast_pow::<MulMonoid<u64>>(3, 5), 243);
+/// assert_eq!(fast_pow::<MulMonoid<u64>>(3, 0), 1);
+/// ```
+#[codesnip::entry("FastPow", include("Monoid"))]
+pub fn fast_pow<M: Monoid>(value: M::Set, mut exp: u64) -> M::Set {
+    match exp {
+        0 => M::id(),
+        1 => value,
+        _ => {
+            let mut base = value;
+            let mut acc = M::id();
+            while exp > 1 {
+                if exp & 1 == 1 {
+                    acc = M::operate(&acc, &base);
+                }
+                exp >>= 1;
+                base = M::operate(&base, &base);
+            }
+            M::operate(&acc, &base)
+        }
+    }
+}
+
+/// Computes `value ∘ value ∘ … ∘ value` (`n` times) with repeated squaring.
+///
+/// It is the same as [`fast_pow`] but takes `value` by reference.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::monoid_pow;
+/// use lib_rust::math::num::alge_struct::types::MulMonoid;
+///
+/// assert_eq!(monoid_pow::<MulMonoid<u64>>(&2, 10), 1024);
+/// ```
+#[codesnip::entry("MonoidPow", include("FastPow"))]
+pub fn monoid_pow<M: Monoid>(value: &M::Set, n: u64) -> M::Set {
+    fast_pow::<M>(value.clone(), n)
+}
+
+/// A wrapper of an element of the monoid `M` to call [`monoid_pow`] as a method.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::math::num::alge_struct::MonoidPow;
+///
+/// let x = MonoidPow::<AddMonoid<i32>>::new(-3);
+/// assert_eq!(x.pow(5).into_inner(), -15);
+/// assert_eq!(x.pow(0).into_inner(), 0);
+/// ```
+#[codesnip::entry("MonoidPow", include("FastPow"))]
+pub struct MonoidPow<M: Monoid>(M::Set);
+
+#[codesnip::entry("MonoidPow", include("FastPow"))]
+impl<M: Monoid> MonoidPow<M> {
+    pub fn new(value: M::Set) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self) -> &M::Set {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> M::Set {
+        self.0
+    }
+
+    /// Computes `self ∘ self ∘ … ∘ self` (`n` times).
+    pub fn pow(&self, n: u64) -> Self {
+        Self(monoid_pow::<M>(&self.0, n))
+    }
+}
+
+#[codesnip::entry("MonoidPow", include("FastPow"))]
+impl<M: Monoid> Clone for MonoidPow<M> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::{AddMonoid, BitXorMonoid, MaxMonoid};
+    use crate::math::num::{BoundedBelow, Semigroup};
+
+    #[test]
+    fn matches_naive() {
+        for exp in 0..40 {
+            let naive = (0..exp).fold(0_u64, |acc, _| AddMonoid::operate(&acc, &13));
+            assert_eq!(fast_pow::<AddMonoid<u64>>(13, exp), naive);
+        }
+        assert_eq!(fast_pow::<BitXorMonoid<u32>>(5, 3), 5);
+        assert_eq!(fast_pow::<BitXorMonoid<u32>>(5, 4), 0);
+        assert_eq!(fast_pow::<MaxMonoid<i32>>(-5, 0), i32::lower_bound());
+        assert_eq!(fast_pow::<MaxMonoid<i32>>(-5, 1_000_000_007), -5);
+    }
+
+    #[test]
+    fn wrapper() {
+        let x = MonoidPow::<BitXorMonoid<u8>>::new(0b1010);
+        assert_eq!(*x.pow(7).get(), 0b1010);
+        assert_eq!(x.pow(6).into_inner(), 0);
+        assert_eq!(monoid_pow::<AddMonoid<u8>>(x.get(), 3), 30);
+    }
+}
@@ -0,0 +1,172 @@
+use super::Monoid;
+use core::fmt;
+
+/// A counterexample of a monoid law found by [`check_monoid_laws`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonoidLawViolation<T> {
+    /// `id ◦ x ≠ x`
+    LeftIdentity { x: T },
+    /// `x ◦ id ≠ x`
+    RightIdentity { x: T },
+    /// `(a ◦ b) ◦ c ≠ a ◦ (b ◦ c)`
+    Associativity { a: T, b: T, c: T },
+}
+
+impl<T: fmt::Debug> fmt::Display for MonoidLawViolation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LeftIdentity { x } => write!(f, "left identity is violated for {:?}", x),
+            Self::RightIdentity { x } => write!(f, "right identity is violated for {:?}", x),
+            Self::Associativity { a, b, c } => write!(
+                f,
+                "associativity is violated for ({:?}, {:?}, {:?})",
+                a, b, c
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for MonoidLawViolation<T> {}
+
+/// Checks the identity laws for all `samples` and the associativity for all triples of `samples`.
+///
+/// This operation is O(*n*³).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::testing::{check_monoid_laws, MonoidLawViolation};
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::math::num::{Monoid, Semigroup};
+///
+/// assert_eq!(check_monoid_laws::<AddMonoid<i64>>(&[-3, 0, 5, 7]), Ok(()));
+///
+/// // subtraction is not associative
+/// struct Sub;
+/// impl Semigroup for Sub {
+///     type Set = i64;
+///     fn operate(lhs: &i64, rhs: &i64) -> i64 { lhs - rhs }
+/// }
+/// impl Monoid for Sub {
+///     fn id() -> i64 { 0 }
+/// }
+/// assert_eq!(
+///     check_monoid_laws::<Sub>(&[1]),
+///     Err(MonoidLawViolation::LeftIdentity { x: 1 })
+/// );
+/// ```
+#[codesnip::entry("CheckMonoidLaws", include("Monoid"))]
+pub fn check_monoid_laws<M>(samples: &[M::Set]) -> Result<(), MonoidLawViolation<M::Set>>
+where
+    M: Monoid,
+    M::Set: PartialEq + fmt::Debug,
+{
+    let id = M::id();
+    for x in samples {
+        if M::operate(&id, x) != *x {
+            return Err(MonoidLawViolation::LeftIdentity { x: x.clone() });
+        }
+        if M::operate(x, &id) != *x {
+            return Err(MonoidLawViolation::RightIdentity { x: x.clone() });
+        }
+    }
+    for a in samples {
+        for b in samples {
+            let ab = M::operate(a, b);
+            for c in samples {
+                if M::operate(&ab, c) != M::operate(a, &M::operate(b, c)) {
+                    return Err(MonoidLawViolation::Associativity {
+                        a: a.clone(),
+                        b: b.clone(),
+                        c: c.clone(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::{
+        AddMonoid, BitAndMonoid, BitOrMonoid, BitXorMonoid, MaxMonoid, MaxMonoidF, MinMonoid,
+        MulMonoid,
+    };
+    use crate::math::num::{BoundedAbove, Semigroup};
+
+    #[test]
+    fn predefined_monoids() {
+        let samples = [-7_i64, -1, 0, 1, 2, 3, 1000];
+        assert_eq!(check_monoid_laws::<AddMonoid<i64>>(&samples), Ok(()));
+        assert_eq!(check_monoid_laws::<MulMonoid<i64>>(&samples), Ok(()));
+        assert_eq!(check_monoid_laws::<MaxMonoid<i64>>(&samples), Ok(()));
+        assert_eq!(check_monoid_laws::<MinMonoid<i64>>(&samples), Ok(()));
+
+        let bits = [0_u32, 1, 6, 0xF0, !0];
+        assert_eq!(check_monoid_laws::<BitXorMonoid<u32>>(&bits), Ok(()));
+        assert_eq!(check_monoid_laws::<BitOrMonoid<u32>>(&bits), Ok(()));
+        assert_eq!(check_monoid_laws::<BitAndMonoid<u32>>(&bits), Ok(()));
+
+        let floats = [-1.5, 0.0, 2.25, f64::upper_bound()];
+        assert_eq!(check_monoid_laws::<MaxMonoidF<f64>>(&floats), Ok(()));
+    }
+
+    #[test]
+    fn violations() {
+        struct Avg;
+        impl Semigroup for Avg {
+            type Set = i32;
+            fn operate(lhs: &i32, rhs: &i32) -> i32 {
+                (lhs + rhs) / 2
+            }
+        }
+        impl Monoid for Avg {
+            fn id() -> i32 {
+                0
+            }
+        }
+        assert_eq!(
+            check_monoid_laws::<Avg>(&[0, 4]),
+            Err(MonoidLawViolation::LeftIdentity { x: 4 })
+        );
+
+        struct Left;
+        impl Semigroup for Left {
+            type Set = i32;
+            fn operate(lhs: &i32, _: &i32) -> i32 {
+                *lhs
+            }
+        }
+        impl Monoid for Left {
+            fn id() -> i32 {
+                0
+            }
+        }
+        assert_eq!(
+            check_monoid_laws::<Left>(&[0, 1]),
+            Err(MonoidLawViolation::LeftIdentity { x: 1 })
+        );
+
+        // subtraction with zero as a two-sided identity
+        struct Sub;
+        impl Semigroup for Sub {
+            type Set = i32;
+            fn operate(lhs: &i32, rhs: &i32) -> i32 {
+                match (lhs, rhs) {
+                    (0, x) | (x, 0) => *x,
+                    (x, y) => x - y,
+                }
+            }
+        }
+        impl Monoid for Sub {
+            fn id() -> i32 {
+                0
+            }
+        }
+        let err = check_monoid_laws::<Sub>(&[1, 2]).unwrap_err();
+        assert_eq!(err, MonoidLawViolation::Associativity { a: 1, b: 2, c: 1 });
+        assert_eq!(err.to_string(), "associativity is violated for (1, 2, 1)");
+    }
+}
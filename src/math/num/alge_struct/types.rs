@@ -3,3 +3,6 @@ pub use monoid::*;
 
 pub mod group;
 pub use group::*;
+
+pub mod max_subarray;
+pub use max_subarray::*;
@@ -11,7 +11,7 @@ macro_rules! define_group {
         $crate::define_monoid! { @impl $group <$t$(: $($bounds),*)?>, |$oplhs,$oprhs| $operate, $id }
     };
     ($group:ident <$t:tt$(: $($bounds:path),*)?>, |$oplhs:ident,$oprhs:ident| $operate:expr, $id:expr, |$ix:ident| $inv:expr, $(|$ioplhs:ident,$ioprhs:ident| $inv_operate:expr,)? mod $mod:ident $({$($items:item)+})? ) => {
-        struct $group<$t>(core::marker::PhantomData<$t>);
+        pub struct $group<$t>(core::marker::PhantomData<$t>);
         mod $mod {
             use super::*;
             $($($items)+)?
@@ -0,0 +1,142 @@
+use crate::math::num::{BoundedBelow, Monoid, Semigroup, Zero};
+use core::marker::PhantomData;
+use core::ops::Add;
+
+/// The summary of a segment for [`MaxSubarrayAlge`].
+///
+/// `T::lower_bound()` is treated as -∞, which is the value of the maximum fields of the empty segment.
+#[codesnip::entry("MaxSubarray", include("Monoid", "Semigroup", "BoundedBelow", "Zero"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaxSubarrayData<T> {
+    /// The sum of the segment.
+    pub total: T,
+    /// The maximum sum of a non-empty prefix.
+    pub max_prefix: T,
+    /// The maximum sum of a non-empty suffix.
+    pub max_suffix: T,
+    /// The maximum sum of a non-empty contiguous subarray.
+    pub max_subarray: T,
+}
+
+#[codesnip::entry("MaxSubarray")]
+impl<T: Clone> MaxSubarrayData<T> {
+    /// Creates the summary of the segment with the single element `x`.
+    pub fn new(x: T) -> Self {
+        Self {
+            total: x.clone(),
+            max_prefix: x.clone(),
+            max_suffix: x.clone(),
+            max_subarray: x,
+        }
+    }
+}
+
+/// The monoid to compute the maximum subarray sum of a segment.
+///
+/// The set is [`MaxSubarrayData`], and the elements are made with [`MaxSubarrayData::new`].
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::{MaxSubarrayAlge, MaxSubarrayData};
+/// use lib_rust::structs::segment_tree::SegmentTree;
+///
+/// let arr = [3, -4, 2, -1, 2, -5, 1];
+/// let tree = SegmentTree::<MaxSubarrayAlge<i64>>::from(
+///     arr.iter().map(|&x| MaxSubarrayData::new(x)).collect::<Vec<_>>(),
+/// );
+/// // [3] or [2, -1, 2]
+/// assert_eq!(tree.query(..).max_subarray, 3);
+/// assert_eq!(tree.query(3..6).max_subarray, 2);
+/// ```
+#[codesnip::entry("MaxSubarray")]
+pub struct MaxSubarrayAlge<T>(PhantomData<T>);
+
+/// Adds `x` and `y`, where `T::lower_bound()` is treated as -∞.
+#[codesnip::entry("MaxSubarray")]
+fn add_or_lower<T: Ord + Add<Output = T> + BoundedBelow + Clone>(x: &T, y: &T) -> T {
+    let lower = T::lower_bound();
+    if *x == lower || *y == lower {
+        lower
+    } else {
+        x.clone() + y.clone()
+    }
+}
+
+#[codesnip::entry("MaxSubarray")]
+impl<T: Ord + Add<Output = T> + Zero + BoundedBelow + Clone> Semigroup for MaxSubarrayAlge<T> {
+    type Set = MaxSubarrayData<T>;
+
+    fn operate(lhs: &Self::Set, rhs: &Self::Set) -> Self::Set {
+        MaxSubarrayData {
+            total: lhs.total.clone() + rhs.total.clone(),
+            max_prefix: Ord::max(
+                lhs.max_prefix.clone(),
+                add_or_lower(&lhs.total, &rhs.max_prefix),
+            ),
+            max_suffix: Ord::max(
+                rhs.max_suffix.clone(),
+                add_or_lower(&lhs.max_suffix, &rhs.total),
+            ),
+            max_subarray: Ord::max(
+                Ord::max(lhs.max_subarray.clone(), rhs.max_subarray.clone()),
+                add_or_lower(&lhs.max_suffix, &rhs.max_prefix),
+            ),
+        }
+    }
+}
+
+#[codesnip::entry("MaxSubarray")]
+impl<T: Ord + Add<Output = T> + Zero + BoundedBelow + Clone> Monoid for MaxSubarrayAlge<T> {
+    fn id() -> Self::Set {
+        MaxSubarrayData {
+            total: T::zero(),
+            max_prefix: T::lower_bound(),
+            max_suffix: T::lower_bound(),
+            max_subarray: T::lower_bound(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::testing::check_monoid_laws;
+    use crate::structs::segment_tree::SegmentTree;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn monoid_laws() {
+        let mut samples = vec![MaxSubarrayAlge::<i64>::id()];
+        samples.extend((-3..=3).map(MaxSubarrayData::new));
+        samples.push(MaxSubarrayData::new(-1_000_000));
+        assert_eq!(check_monoid_laws::<MaxSubarrayAlge<i64>>(&samples), Ok(()));
+    }
+
+    #[test]
+    fn segment_tree() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| (seed % 21) as i64 - 10);
+
+        for n in 1..30 {
+            let arr = (0..n).map(|_| rand()).collect::<Vec<_>>();
+            let tree = SegmentTree::<MaxSubarrayAlge<i64>>::from(
+                arr.iter()
+                    .map(|&x| MaxSubarrayData::new(x))
+                    .collect::<Vec<_>>(),
+            );
+            for l in 0..n {
+                for r in l + 1..=n {
+                    let mut best = i64::lower_bound();
+                    for i in l..r {
+                        for j in i + 1..=r {
+                            best = best.max(arr[i..j].iter().sum());
+                        }
+                    }
+                    let data = tree.query(l..r);
+                    assert_eq!(data.max_subarray, best);
+                    assert_eq!(data.total, arr[l..r].iter().sum::<i64>());
+                }
+            }
+        }
+    }
+}
@@ -1,23 +1,57 @@
 use crate::math::num::{AllBitOne, BoundedAbove, BoundedBelow, Monoid, One, Semigroup, Zero};
 
+/// Defines a monoid `$monoid<$t>` whose set is `$t`.
+///
+/// The bounds of `$t` are separated by commas.
+/// Additional bounds such as the ones on associated types are given in the `where` clause separated by semicolons,
+/// and the items in the `impl { ... }` block following the module are added to the monoid as inherent items.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::define_monoid;
+/// use lib_rust::math::num::{Monoid, Semigroup};
+///
+/// define_monoid! {
+///     /// The maximum of the dereferenced values.
+///     MaxDerefMonoid<T: Clone, Default, Deref> where T::Target: Ord,
+///     |lhs,rhs| if **lhs < **rhs { rhs.clone() } else { lhs.clone() },
+///     T::default(),
+///     mod max_deref_monoid_impl { use core::ops::Deref; }
+///     impl {
+///         pub fn fold(v: &[T]) -> T {
+///             v.iter().fold(Self::id(), |acc, x| Self::operate(&acc, x))
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let v = vec![Box::new(3), Box::new(1), Box::new(4)];
+///     assert_eq!(MaxDerefMonoid::fold(&v), Box::new(4));
+/// }
+/// ```
 #[cfg_attr(nightly, codesnip::entry(include("Monoid", "Semigroup")))]
 #[macro_export]
 macro_rules! define_monoid {
-    (@impl $monoid:ident <$t:tt $(: $($bounds:path),*)?>, |$lhs:ident,$rhs:ident| $operate:expr, $id:expr) => {
-        impl<$t$(: $($bounds+)*)?> Monoid for $monoid<$t> {
+    (@impl $monoid:ident <$t:tt $(: $($bounds:path),*)?> $(where $($wt:ty: $($wb:path),+);+)?, |$lhs:ident,$rhs:ident| $operate:expr, $id:expr) => {
+        impl<$t$(: $($bounds+)*)?> Monoid for $monoid<$t> $(where $($wt: $($wb+)*),+)? {
             fn id() -> Self::Set { $id }
         }
-        impl<$t$(: $($bounds+)*)?> Semigroup for $monoid<$t> {
+        impl<$t$(: $($bounds+)*)?> Semigroup for $monoid<$t> $(where $($wt: $($wb+)*),+)? {
             type Set = $t;
             fn operate($lhs: &Self::Set, $rhs: &Self::Set) -> Self::Set { $operate }
         }
     };
-    ($monoid:ident <$t:tt$(: $($bounds:path),*)?>, |$lhs:ident,$rhs:ident| $operate:expr, $id:expr, mod $mod:ident $({$($items:item)+})? ) => {
+    ($(#[$attr:meta])* $monoid:ident <$t:tt$(: $($bounds:path),*)?> $(where $($wt:ty: $($wb:path),+);+)?, |$lhs:ident,$rhs:ident| $operate:expr, $id:expr, mod $mod:ident $({$($items:item)+})? $(impl {$($methods:tt)*})? ) => {
+        $(#[$attr])*
         pub struct $monoid<$t>(core::marker::PhantomData<$t>);
         mod $mod {
             use super::*;
             $($($items)+)?
-            $crate::define_monoid! { @impl $monoid <$t$(: $($bounds),*)?>, |$lhs,$rhs| $operate, $id }
+            $crate::define_monoid! { @impl $monoid <$t$(: $($bounds),*)?> $(where $($wt: $($wb),+);+)?, |$lhs,$rhs| $operate, $id }
+            impl<$t$(: $($bounds+)*)?> $monoid<$t> $(where $($wt: $($wb+)*),+)? {
+                $($($methods)*)?
+            }
         }
     };
 }
@@ -54,6 +88,48 @@ define_monoid! {
     mod min_monoid_impl
 }
 
+#[codesnip::entry("MaxMonoidF", include("define_monoid", "BoundedBelow"))]
+define_monoid! {
+    /// The maximum monoid for partially ordered types such as `f64`.
+    ///
+    /// NaN is ignored in the same way as [`f64::max`], so the result is NaN only if all the elements are NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::num::alge_struct::types::MaxMonoidF;
+    /// use lib_rust::math::num::{Monoid, Semigroup};
+    ///
+    /// type M = MaxMonoidF<f64>;
+    /// let v = [1.5, core::f64::NAN, -2.0, 3.25];
+    /// assert_eq!(v.iter().fold(M::id(), |acc, x| M::operate(&acc, x)), 3.25);
+    /// assert!(M::operate(&core::f64::NAN, &core::f64::NAN).is_nan());
+    /// ```
+    MaxMonoidF<T: Clone, PartialOrd, BoundedBelow>,
+    |lhs,rhs| {
+        #[allow(clippy::eq_op)]
+        let lhs_is_nan = lhs != lhs;
+        if lhs_is_nan || rhs > lhs { rhs.clone() } else { lhs.clone() }
+    },
+    BoundedBelow::lower_bound(),
+    mod max_monoid_f_impl
+}
+
+#[codesnip::entry("MinMonoidF", include("define_monoid", "BoundedAbove"))]
+define_monoid! {
+    /// The minimum monoid for partially ordered types such as `f64`.
+    ///
+    /// NaN is ignored in the same way as [`f64::min`], so the result is NaN only if all the elements are NaN.
+    MinMonoidF<T: Clone, PartialOrd, BoundedAbove>,
+    |lhs,rhs| {
+        #[allow(clippy::eq_op)]
+        let lhs_is_nan = lhs != lhs;
+        if lhs_is_nan || rhs < lhs { rhs.clone() } else { lhs.clone() }
+    },
+    BoundedAbove::upper_bound(),
+    mod min_monoid_f_impl
+}
+
 #[codesnip::entry("BitXorMonoid", include("define_monoid", "Zero"))]
 define_monoid! {
     BitXorMonoid<T: Clone, Zero, BitXor<Output = T>>,
@@ -15,6 +15,35 @@ mod bounded_above {
 
     impl_bound! { impl BoundedAbove, upper_bound, MAX; for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
     impl_bound! { impl BoundedAbove, upper_bound, INFINITY; for f32 f64 }
+    impl BoundedAbove for char {
+        fn upper_bound() -> Self {
+            '\u{10FFFF}'
+        }
+    }
+
+    macro_rules! impl_bounded_above_tuple {
+        ($($t:ident)*) => {
+            impl<$($t: BoundedAbove),*> BoundedAbove for ($($t,)*) {
+                fn upper_bound() -> Self { ($($t::upper_bound(),)*) }
+            }
+        };
+    }
+
+    impl_bounded_above_tuple! { A B }
+    impl_bounded_above_tuple! { A B C }
+
+    macro_rules! impl_bounded_above_nonzero {
+        ($($t:ident $p:ty)*) => {$(
+            impl BoundedAbove for core::num::$t {
+                fn upper_bound() -> Self { core::num::$t::new(<$p>::upper_bound()).unwrap() }
+            }
+        )*};
+    }
+
+    impl_bounded_above_nonzero! {
+        NonZeroI8 i8 NonZeroI16 i16 NonZeroI32 i32 NonZeroI64 i64 NonZeroI128 i128 NonZeroIsize isize
+        NonZeroU8 u8 NonZeroU16 u16 NonZeroU32 u32 NonZeroU64 u64 NonZeroU128 u128 NonZeroUsize usize
+    }
 }
 
 pub use bounded_below_impl::*;
@@ -26,6 +55,37 @@ mod bounded_below_impl {
 
     impl_bound! { impl BoundedBelow, lower_bound, MIN; for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
     impl_bound! { impl BoundedBelow, lower_bound, NEG_INFINITY; for f32 f64 }
+    impl BoundedBelow for char {
+        fn lower_bound() -> Self {
+            '\0'
+        }
+    }
+
+    macro_rules! impl_bounded_below_tuple {
+        ($($t:ident)*) => {
+            impl<$($t: BoundedBelow),*> BoundedBelow for ($($t,)*) {
+                fn lower_bound() -> Self { ($($t::lower_bound(),)*) }
+            }
+        };
+    }
+
+    impl_bounded_below_tuple! { A B }
+    impl_bounded_below_tuple! { A B C }
+
+    macro_rules! impl_bounded_below_nonzero {
+        ($($t:ident $lower:expr;)*) => {$(
+            impl BoundedBelow for core::num::$t {
+                fn lower_bound() -> Self { core::num::$t::new($lower).unwrap() }
+            }
+        )*};
+    }
+
+    // the minimum of the unsigned ones is 1
+    impl_bounded_below_nonzero! {
+        NonZeroI8 i8::lower_bound(); NonZeroI16 i16::lower_bound(); NonZeroI32 i32::lower_bound();
+        NonZeroI64 i64::lower_bound(); NonZeroI128 i128::lower_bound(); NonZeroIsize isize::lower_bound();
+        NonZeroU8 1; NonZeroU16 1; NonZeroU32 1; NonZeroU64 1; NonZeroU128 1; NonZeroUsize 1;
+    }
 }
 
 pub use bounded_impl::*;
@@ -42,5 +102,65 @@ mod bounded_impl {
         )*};
     }
 
-    impl_bounded! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64 }
+    impl_bounded! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64 char }
+    impl_bounded! {
+        core::num::NonZeroI8 core::num::NonZeroI16 core::num::NonZeroI32
+        core::num::NonZeroI64 core::num::NonZeroI128 core::num::NonZeroIsize
+        core::num::NonZeroU8 core::num::NonZeroU16 core::num::NonZeroU32
+        core::num::NonZeroU64 core::num::NonZeroU128 core::num::NonZeroUsize
+    }
+    impl<A: Bounded, B: Bounded> Bounded for (A, B) {}
+    impl<A: Bounded, B: Bounded, C: Bounded> Bounded for (A, B, C) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::{MaxMonoid, MinMonoid};
+    use crate::math::num::{Monoid, Semigroup};
+
+    #[test]
+    fn char_bounds() {
+        assert_eq!(char::upper_bound(), core::char::from_u32(0x10FFFF).unwrap());
+        assert_eq!(char::lower_bound(), '\0');
+        assert!(core::char::from_u32(0x110000).is_none());
+    }
+
+    #[test]
+    fn tuple_bounds() {
+        assert_eq!(<(u8, i8)>::upper_bound(), (255, 127));
+        assert_eq!(<(u8, i8, char)>::lower_bound(), (0, -128, '\0'));
+
+        // lexicographic maximum
+        type M = MaxMonoid<(u32, u32)>;
+        let v = [(1, 5), (3, 2), (3, 4), (2, 9)];
+        assert_eq!(v.iter().fold(M::id(), |acc, x| M::operate(&acc, x)), (3, 4));
+        type N = MinMonoid<(u32, u32)>;
+        assert_eq!(v.iter().fold(N::id(), |acc, x| N::operate(&acc, x)), (1, 5));
+    }
+
+    #[test]
+    fn nonzero_bounds() {
+        use core::num::{NonZeroI8, NonZeroU64};
+
+        assert_eq!(NonZeroU64::upper_bound().get(), 18_446_744_073_709_551_615);
+        assert_eq!(NonZeroU64::lower_bound().get(), 1);
+        assert_eq!(NonZeroI8::upper_bound().get(), 127);
+        assert_eq!(NonZeroI8::lower_bound().get(), -128);
+
+        let v = [3, 1, 4, 1, 5]
+            .iter()
+            .map(|&x| NonZeroU64::new(x).unwrap())
+            .collect::<Vec<_>>();
+        type M = MaxMonoid<NonZeroU64>;
+        assert_eq!(
+            v.iter().fold(M::id(), |acc, x| M::operate(&acc, x)).get(),
+            5
+        );
+        type N = MinMonoid<NonZeroU64>;
+        assert_eq!(
+            v.iter().fold(N::id(), |acc, x| N::operate(&acc, x)).get(),
+            1
+        );
+    }
 }
@@ -0,0 +1,82 @@
+/// Arithmetic operations which return `None` instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::CheckedOps;
+///
+/// fn checked_sum<T: CheckedOps + Copy>(init: T, v: &[T]) -> Option<T> {
+///     v.iter().try_fold(init, |acc, &x| CheckedOps::checked_add(acc, x))
+/// }
+///
+/// assert_eq!(checked_sum(0_u8, &[100, 100, 55]), Some(255));
+/// assert_eq!(checked_sum(0_u8, &[100, 100, 56]), None);
+/// ```
+pub trait CheckedOps: Sized {
+    /// Computes `self + rhs`, returning `None` if overflow occurred.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Computes `self - rhs`, returning `None` if overflow occurred.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Computes `self * rhs`, returning `None` if overflow occurred.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Computes `self / rhs`, returning `None` if `rhs == 0` or overflow occurred.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Computes `-self`, returning `None` if overflow occurred.
+    fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_ops {
+    (for $($t:ty)*) => {$(
+        impl CheckedOps for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+        }
+    )*};
+}
+
+impl_checked_ops! { for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<T: CheckedOps + Copy>(a: T, b: T) -> [Option<T>; 5] {
+        [
+            CheckedOps::checked_add(a, b),
+            CheckedOps::checked_sub(a, b),
+            CheckedOps::checked_mul(a, b),
+            CheckedOps::checked_div(a, b),
+            CheckedOps::checked_neg(a),
+        ]
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(
+            all(7_i32, 2),
+            [Some(9), Some(5), Some(14), Some(3), Some(-7)]
+        );
+        assert_eq!(all(-128_i8, -1), [None, Some(-127), None, None, None]);
+        assert_eq!(all(1_i64, 0), [Some(1), Some(1), Some(0), None, Some(-1)]);
+    }
+
+    #[test]
+    fn unsigned() {
+        assert_eq!(all(7_u32, 2), [Some(9), Some(5), Some(14), Some(3), None]);
+        assert_eq!(all(0_usize, 1), [Some(1), None, Some(0), Some(0), Some(0)]);
+        assert_eq!(all(255_u8, 2), [None, Some(253), None, Some(127), None]);
+    }
+}
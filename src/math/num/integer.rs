@@ -0,0 +1,88 @@
+use super::{One, Signed, Zero};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Primitive integers.
+///
+/// It is a shorthand for the bounds commonly required by integer algorithms.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::Integer;
+///
+/// fn digit_sum<T: Integer>(mut x: T, base: T) -> T {
+///     let mut sum = T::zero();
+///     while x > T::zero() {
+///         sum = sum + x % base;
+///         x = x / base;
+///     }
+///     sum
+/// }
+///
+/// assert_eq!(digit_sum(1234_u32, 10), 10);
+/// assert_eq!(digit_sum(0b1011_i64, 2), 3);
+/// ```
+pub trait Integer:
+    Zero
+    + One
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Copy
+{
+}
+
+/// Primitive unsigned integers.
+pub trait UnsignedInteger: Integer {}
+
+/// Primitive signed integers.
+pub trait SignedInteger: Integer + Signed {}
+
+macro_rules! impl_integer {
+    ($imp:ident for $($t:ty)*) => {$(
+        impl Integer for $t {}
+        impl $imp for $t {}
+    )*};
+}
+
+impl_integer! { UnsignedInteger for u8 u16 u32 u64 u128 usize }
+impl_integer! { SignedInteger for i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gcd<T: Integer>(a: T, b: T) -> T {
+        if b == T::zero() {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    fn abs_gcd<T: SignedInteger>(a: T, b: T) -> T {
+        gcd(Signed::abs(a), Signed::abs(b))
+    }
+
+    fn is_power_of_two<T: UnsignedInteger>(mut x: T) -> bool {
+        let two = T::one() + T::one();
+        while x > T::one() && x % two == T::zero() {
+            x = x / two;
+        }
+        x == T::one()
+    }
+
+    #[test]
+    fn generic() {
+        assert_eq!(gcd(12_u8, 18), 6);
+        assert_eq!(gcd(0_u128, 7), 7);
+        assert_eq!(abs_gcd(-12_i32, 18), 6);
+        assert_eq!(abs_gcd(12_isize, -18), 6);
+        assert!(is_power_of_two(64_usize));
+        assert!(!is_power_of_two(96_u16));
+        assert!(!is_power_of_two(0_u64));
+    }
+}
@@ -0,0 +1,167 @@
+use super::{BoundedAbove, BoundedBelow, One, Zero};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A totally ordered `f64`, which can be used with `Ord` bounds such as [`MaxMonoid`].
+///
+/// The comparison panics if either value is NaN.
+///
+/// [`MaxMonoid`]: crate::math::num::alge_struct::types::MaxMonoid
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::{AddMonoid, MaxMonoid};
+/// use lib_rust::math::num::ordered_float::OrderedF64;
+/// use lib_rust::math::num::{Monoid, Semigroup};
+/// use lib_rust::structs::binary_indexed_tree::BinaryIndexedTree;
+///
+/// let v = [1.5, -2.0, 3.25].iter().map(|&x| OrderedF64(x)).collect::<Vec<_>>();
+/// type M = MaxMonoid<OrderedF64>;
+/// assert_eq!(v.iter().fold(M::id(), |acc, x| M::operate(&acc, x)), OrderedF64(3.25));
+///
+/// let bit = BinaryIndexedTree::<AddMonoid<_>>::from(v);
+/// assert_eq!(f64::from(bit.fold(..2)), -0.5);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct OrderedF64(pub f64);
+
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    /// # Panics
+    ///
+    /// Panics if either value is NaN.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("NaN cannot be compared")
+    }
+}
+
+impl From<f64> for OrderedF64 {
+    fn from(x: f64) -> Self {
+        Self(x)
+    }
+}
+
+impl From<OrderedF64> for f64 {
+    fn from(x: OrderedF64) -> Self {
+        x.0
+    }
+}
+
+impl Add for OrderedF64 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for OrderedF64 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for OrderedF64 {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Neg for OrderedF64 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Zero for OrderedF64 {
+    fn zero() -> Self {
+        Self(0.0)
+    }
+}
+
+impl One for OrderedF64 {
+    fn one() -> Self {
+        Self(1.0)
+    }
+}
+
+impl BoundedAbove for OrderedF64 {
+    fn upper_bound() -> Self {
+        Self(f64::upper_bound())
+    }
+}
+
+impl BoundedBelow for OrderedF64 {
+    fn lower_bound() -> Self {
+        Self(f64::lower_bound())
+    }
+}
+
+impl fmt::Display for OrderedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for OrderedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::MinMonoid;
+    use crate::math::num::{Monoid, Semigroup};
+
+    #[test]
+    fn order() {
+        let mut v = [3.0, -0.0, 0.0, -1.5, 2.5, -7.0]
+            .iter()
+            .map(|&x| OrderedF64(x))
+            .collect::<Vec<_>>();
+        v.sort();
+        let sorted = v.iter().map(|&x| f64::from(x)).collect::<Vec<_>>();
+        assert_eq!(sorted, vec![-7.0, -1.5, -0.0, 0.0, 2.5, 3.0]);
+        assert_eq!(OrderedF64(-0.0), OrderedF64(0.0));
+
+        type M = MinMonoid<OrderedF64>;
+        assert_eq!(M::id(), OrderedF64(f64::upper_bound()));
+        assert_eq!(
+            v.iter().fold(M::id(), |acc, x| M::operate(&acc, x)),
+            OrderedF64(-7.0)
+        );
+        assert_eq!(
+            format!("{} {:?}", OrderedF64(1.5), OrderedF64(-2.0)),
+            "1.5 -2.0"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan() {
+        let _ = OrderedF64((-1.0_f64).sqrt()) < OrderedF64(0.0);
+    }
+}
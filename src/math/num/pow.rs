@@ -0,0 +1,71 @@
+/// Exponentiation.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::Pow;
+///
+/// fn cube<T: Pow>(x: T) -> T {
+///     x.pow(3)
+/// }
+///
+/// assert_eq!(cube(-2_i32), -8);
+/// assert_eq!(cube(1.5_f64), 3.375);
+/// ```
+pub trait Pow<Exp = u32>: Sized {
+    fn pow(self, exp: Exp) -> Self;
+}
+
+macro_rules! impl_pow_int {
+    (for $($t:ty)*) => {$(
+        impl Pow<u32> for $t {
+            fn pow(self, exp: u32) -> Self {
+                <$t>::pow(self, exp)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_pow_float {
+    (for $($t:ty)*) => {$(
+        impl Pow<u32> for $t {
+            fn pow(self, exp: u32) -> Self {
+                let mut base = self;
+                let mut exp = exp;
+                let mut acc = 1.0;
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc *= base;
+                    }
+                    base *= base;
+                    exp >>= 1;
+                }
+                acc
+            }
+        }
+    )*};
+}
+
+impl_pow_int! { for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+impl_pow_float! { for f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int() {
+        assert_eq!(Pow::pow(3_u64, 0), 1);
+        assert_eq!(Pow::pow(3_u64, 13), 1_594_323);
+        assert_eq!(Pow::pow(-3_i8, 3), -27);
+        assert_eq!(Pow::pow(2_u128, 100), 1 << 100);
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Pow::pow(2.0_f64, 10), 1024.0);
+        assert_eq!(Pow::pow(0.5_f32, 3), 0.125);
+        assert_eq!(Pow::pow(0.0_f64, 0), 1.0);
+        assert!((Pow::pow(1.1_f64, 7) - 1.1_f64.powi(7)).abs() < 1e-12);
+    }
+}
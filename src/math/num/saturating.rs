@@ -0,0 +1,62 @@
+/// Arithmetic operations which saturate at the numeric bounds instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::SaturatingOps;
+///
+/// fn saturating_sum<T: SaturatingOps + Copy>(init: T, v: &[T]) -> T {
+///     v.iter().fold(init, |acc, &x| SaturatingOps::saturating_add(acc, x))
+/// }
+///
+/// assert_eq!(saturating_sum(0_u8, &[100, 100, 100]), 255);
+/// assert_eq!(saturating_sum(0_i8, &[-100, -100]), -128);
+/// ```
+pub trait SaturatingOps: Sized {
+    /// Computes `self + rhs`, saturating at the numeric bounds.
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// Computes `self - rhs`, saturating at the numeric bounds.
+    fn saturating_sub(self, rhs: Self) -> Self;
+    /// Computes `self * rhs`, saturating at the numeric bounds.
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_saturating_ops {
+    (for $($t:ty)*) => {$(
+        impl SaturatingOps for $t {
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_saturating_ops! { for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<T: SaturatingOps + Copy>(a: T, b: T) -> [T; 3] {
+        [
+            SaturatingOps::saturating_add(a, b),
+            SaturatingOps::saturating_sub(a, b),
+            SaturatingOps::saturating_mul(a, b),
+        ]
+    }
+
+    #[test]
+    fn saturate() {
+        assert_eq!(all(7_i32, 2), [9, 5, 14]);
+        assert_eq!(all(-128_i8, -1), [-128, -127, 127]);
+        assert_eq!(all(100_i8, 100), [127, 0, 127]);
+        assert_eq!(all(3_u16, 5), [8, 0, 15]);
+        assert_eq!(all(255_u8, 2), [255, 253, 255]);
+    }
+}
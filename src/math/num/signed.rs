@@ -0,0 +1,109 @@
+use super::Zero;
+
+/// Numbers which can be negative.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::Signed;
+///
+/// fn manhattan<T: Signed + core::ops::Add<Output = T> + core::ops::Sub<Output = T>>(
+///     (x1, y1): (T, T),
+///     (x2, y2): (T, T),
+/// ) -> T {
+///     Signed::abs(x1 - x2) + Signed::abs(y1 - y2)
+/// }
+///
+/// assert_eq!(manhattan((1, -2), (-3, 4)), 10);
+/// assert_eq!(manhattan((0.5, 0.0), (0.0, 1.5)), 2.0);
+/// ```
+pub trait Signed: Zero + Clone {
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+    /// Returns `-1`, `0` or `1` according to the sign.
+    fn signum(self) -> Self;
+    /// Returns `true` if the number is strictly negative.
+    fn is_negative(&self) -> bool;
+    /// Returns `true` if the number is strictly positive.
+    fn is_positive(&self) -> bool;
+}
+
+macro_rules! impl_signed_int {
+    (for $($t:ty)*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+            fn is_negative(&self) -> bool {
+                <$t>::is_negative(*self)
+            }
+            fn is_positive(&self) -> bool {
+                <$t>::is_positive(*self)
+            }
+        }
+    )*};
+}
+
+// `f64::signum` returns `1.0` for `0.0`, so the zero and NaN are kept as they are.
+macro_rules! impl_signed_float {
+    (for $($t:ty)*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn signum(self) -> Self {
+                if self > 0.0 {
+                    1.0
+                } else if self < 0.0 {
+                    -1.0
+                } else {
+                    self
+                }
+            }
+            fn is_negative(&self) -> bool {
+                *self < 0.0
+            }
+            fn is_positive(&self) -> bool {
+                *self > 0.0
+            }
+        }
+    )*};
+}
+
+impl_signed_int! { for i8 i16 i32 i64 i128 isize }
+impl_signed_float! { for f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign_of<T: Signed>(x: T) -> (T, bool, bool) {
+        let negative = x.is_negative();
+        let positive = x.is_positive();
+        (Signed::signum(x), negative, positive)
+    }
+
+    #[test]
+    fn int() {
+        assert_eq!(sign_of(-5_i32), (-1, true, false));
+        assert_eq!(sign_of(0_i64), (0, false, false));
+        assert_eq!(sign_of(7_i8), (1, false, true));
+        assert_eq!(Signed::abs(-3_isize), 3);
+        assert_eq!(Signed::abs(i128::from(-4)), 4);
+    }
+
+    #[test]
+    // associated constants such as `f64::NAN` require Rust 1.43
+    #[allow(clippy::legacy_numeric_constants)]
+    fn float() {
+        assert_eq!(sign_of(-2.5_f64), (-1.0, true, false));
+        assert_eq!(sign_of(0.0_f32), (0.0, false, false));
+        assert_eq!(sign_of(-0.0_f64), (0.0, false, false));
+        assert_eq!(sign_of(1e-9_f64), (1.0, false, true));
+        assert!(Signed::signum(core::f64::NAN).is_nan());
+        assert_eq!(Signed::abs(-1.5_f64), 1.5);
+    }
+}
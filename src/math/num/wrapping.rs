@@ -0,0 +1,71 @@
+/// Arithmetic operations which wrap around at the numeric bounds.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::WrappingOps;
+///
+/// // polynomial rolling hash modulo 2⁶⁴
+/// fn rolling_hash<T: WrappingOps + Copy + From<u8>>(s: &[u8], base: T, init: T) -> T {
+///     s.iter().fold(init, |acc, &c| {
+///         WrappingOps::wrapping_add(WrappingOps::wrapping_mul(acc, base), T::from(c))
+///     })
+/// }
+///
+/// let h1 = rolling_hash(b"abracadabra", 1_000_003_u64, 0);
+/// let h2 = rolling_hash(b"abracadabrb", 1_000_003_u64, 0);
+/// assert_ne!(h1, h2);
+/// ```
+pub trait WrappingOps: Sized {
+    /// Computes `self + rhs`, wrapping around at the numeric bounds.
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// Computes `self - rhs`, wrapping around at the numeric bounds.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// Computes `self * rhs`, wrapping around at the numeric bounds.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Computes `-self`, wrapping around at the numeric bounds.
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! impl_wrapping_ops {
+    (for $($t:ty)*) => {$(
+        impl WrappingOps for $t {
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+            fn wrapping_neg(self) -> Self {
+                <$t>::wrapping_neg(self)
+            }
+        }
+    )*};
+}
+
+impl_wrapping_ops! { for i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<T: WrappingOps + Copy>(a: T, b: T) -> [T; 4] {
+        [
+            WrappingOps::wrapping_add(a, b),
+            WrappingOps::wrapping_sub(a, b),
+            WrappingOps::wrapping_mul(a, b),
+            WrappingOps::wrapping_neg(a),
+        ]
+    }
+
+    #[test]
+    fn wrap() {
+        assert_eq!(all(7_i32, 2), [9, 5, 14, -7]);
+        assert_eq!(all(-128_i8, -1), [127, -127, -128, -128]);
+        assert_eq!(all(3_u16, 5), [8, 65534, 15, 65533]);
+        assert_eq!(all(255_u8, 2), [1, 253, 254, 1]);
+    }
+}
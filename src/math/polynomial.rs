@@ -0,0 +1,33 @@
+use crate::math::modint::{ModInt, ModIntFactMap, Modulo};
+use crate::math::num::{One, Zero};
+
+#[cfg_attr(nightly, codesnip::entry("Polynomial", include("One", "Zero")))]
+mod poly;
+#[codesnip::entry("Polynomial")]
+pub use poly::Polynomial;
+
+#[cfg_attr(nightly, codesnip::entry("Ntt"))]
+pub mod ntt;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("Fps", include("Polynomial", "ModInt", "Ntt"))
+)]
+mod fps;
+#[codesnip::entry("Fps")]
+pub(crate) use fps::{mul_trunc, ntt_root};
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("Interpolation", include("Polynomial", "ModInt"))
+)]
+pub mod interpolation;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("GeneratingFunctions", include("Fps", "ModIntFactMap"))
+)]
+pub mod generating_functions;
+
+#[cfg_attr(nightly, codesnip::entry("PolynomialGcd", include("ModInt")))]
+pub mod gcd;
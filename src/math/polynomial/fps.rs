@@ -0,0 +1,291 @@
+use super::ntt::{ntt_convolve, primitive_root};
+use super::{ModInt, Modulo, Polynomial};
+
+type Set = usize;
+
+/// Returns a primitive root of `M::MOD` for [`mul_trunc`], or `None` if the modulus is too large for the NTT.
+///
+/// It takes O(√*MOD*), so it should be computed once for a series of multiplications.
+pub(crate) fn ntt_root<M: Modulo<Set = Set>>() -> Option<u64> {
+    let p = M::MOD as u64;
+    if p < 1 << 32 {
+        Some(primitive_root(p))
+    } else {
+        None
+    }
+}
+
+/// Multiplies `a` and `b`, and keeps only the first `n` coefficients.
+///
+/// It uses the NTT with `root` from [`ntt_root`] if `M::MOD` supports the length,
+/// otherwise it multiplies naively.
+pub(crate) fn mul_trunc<M>(
+    a: &[ModInt<M>],
+    b: &[ModInt<M>],
+    n: usize,
+    root: Option<u64>,
+) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    if a.is_empty() || b.is_empty() || n == 0 {
+        return vec![ModInt::zero(); n];
+    }
+    let a = &a[..a.len().min(n)];
+    let b = &b[..b.len().min(n)];
+    let len = a.len() + b.len() - 1;
+
+    let mut c = match root {
+        Some(root) if (M::MOD - 1) % len.next_power_of_two() == 0 => {
+            let a = a.iter().map(|&x| Set::from(x) as u64).collect::<Vec<_>>();
+            let b = b.iter().map(|&x| Set::from(x) as u64).collect::<Vec<_>>();
+            ntt_convolve(&a, &b, M::MOD as u64, root)
+                .into_iter()
+                .map(|x| ModInt::new(x as Set))
+                .collect::<Vec<_>>()
+        }
+        _ => {
+            let mut c = vec![ModInt::zero(); len];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    c[i + j] += x * y;
+                }
+            }
+            c
+        }
+    };
+    c.resize(n, ModInt::zero());
+    c
+}
+
+impl<M> Polynomial<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    /// Returns the first `n` coefficients, padded with zeros.
+    fn prefix(&self, n: usize) -> Vec<ModInt<M>> {
+        let mut coef = self.coef()[..self.coef().len().min(n)].to_vec();
+        coef.resize(n, ModInt::zero());
+        coef
+    }
+
+    /// Computes the first `terms` coefficients of the formal power series `g` such that `self * g = 1`.
+    ///
+    /// It uses Newton's method `g ← g(2 - self·g)`.
+    /// The modulus should be a NTT-friendly prime such as `998244353`,
+    /// otherwise the multiplications fall back to O(*n*²).
+    ///
+    /// This operation is O(*n* log(*n*)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constant term is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+    /// use lib_rust::math::polynomial::Polynomial;
+    ///
+    /// // 1 / (1 - x) = 1 + x + x² + …
+    /// let f = Polynomial::new(vec![Mint::new(1), -Mint::new(1)]);
+    /// assert_eq!(f.fps_inverse(4).coef(), &[1, 1, 1, 1]);
+    /// ```
+    pub fn fps_inverse(&self, terms: usize) -> Self {
+        self.inverse_with_root(terms, ntt_root::<M>())
+    }
+
+    fn inverse_with_root(&self, terms: usize, root: Option<u64>) -> Self {
+        let c0 = self.coef().first().copied().unwrap_or_else(ModInt::zero);
+        assert!(c0 != 0, "the constant term must be nonzero");
+
+        let mut g = vec![c0.recip()];
+        let mut len = 1;
+        while len < terms {
+            len <<= 1;
+            let mut h = mul_trunc(&self.prefix(len), &g, len, root);
+            for x in h.iter_mut() {
+                *x = -*x;
+            }
+            h[0] += 2;
+            g = mul_trunc(&g, &h, len, root);
+        }
+        g.truncate(terms);
+        Self::new(g)
+    }
+
+    /// Computes the first `terms` coefficients of `log(self)`.
+    ///
+    /// This operation is O(*n* log(*n*)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constant term is not one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+    /// use lib_rust::math::polynomial::Polynomial;
+    ///
+    /// // log(1 + x) = x - x²/2 + x³/3 - …
+    /// let f = Polynomial::new(vec![Mint::new(1), Mint::new(1)]);
+    /// let g = f.fps_log(4);
+    /// assert_eq!(g[2] * 2, -Mint::new(1));
+    /// assert_eq!(g[3] * 3, 1);
+    /// ```
+    pub fn fps_log(&self, terms: usize) -> Self {
+        self.log_with_root(terms, ntt_root::<M>())
+    }
+
+    fn log_with_root(&self, terms: usize, root: Option<u64>) -> Self {
+        assert!(
+            self.coef().first() == Some(&ModInt::one()),
+            "the constant term must be one"
+        );
+        if terms == 0 {
+            return Self::new(vec![]);
+        }
+
+        // log f = ∫ f' / f
+        let derivative = self
+            .coef()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .take(terms)
+            .map(|(i, &c)| c * i)
+            .collect::<Vec<_>>();
+        let inverse = self.inverse_with_root(terms, root);
+        let quotient = mul_trunc(&derivative, inverse.coef(), terms - 1, root);
+
+        let mut coef = Vec::with_capacity(terms);
+        coef.push(ModInt::zero());
+        coef.extend(
+            quotient
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| c / ModInt::new(i + 1)),
+        );
+        Self::new(coef)
+    }
+
+    /// Computes the first `terms` coefficients of `exp(self)`.
+    ///
+    /// It uses Newton's method `g ← g(1 - log(g) + self)`.
+    ///
+    /// This operation is O(*n* log(*n*)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the constant term is not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+    /// use lib_rust::math::polynomial::Polynomial;
+    ///
+    /// // exp(x) = 1 + x + x²/2 + x³/6 + …
+    /// let f = Polynomial::new(vec![Mint::new(0), Mint::new(1)]);
+    /// let g = f.fps_exp(4);
+    /// assert_eq!(g[0], 1);
+    /// assert_eq!(g[1], 1);
+    /// assert_eq!(g[2] * 2, 1);
+    /// assert_eq!(g[3] * 6, 1);
+    /// ```
+    pub fn fps_exp(&self, terms: usize) -> Self {
+        assert!(
+            self.coef().iter().take(1).all(|&c| c == 0),
+            "the constant term must be zero"
+        );
+        if terms == 0 {
+            return Self::new(vec![]);
+        }
+
+        let root = ntt_root::<M>();
+        let mut g = vec![ModInt::one()];
+        let mut len = 1;
+        while len < terms {
+            len <<= 1;
+            let log = Self::new(g.clone()).log_with_root(len, root);
+            let mut h = self.prefix(len);
+            for (x, l) in h.iter_mut().zip(log.coef()) {
+                *x -= *l;
+            }
+            h[0] += 1;
+            g = mul_trunc(&g, &h, len, root);
+        }
+        g.truncate(terms);
+        Self::new(g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::{ModInt1e9_7, ModInt998244353 as Mint};
+    use crate::math::polynomial::ntt::PRIMITIVE_ROOT_998244353;
+
+    fn poly(coef: &[usize]) -> Polynomial<Mint> {
+        Polynomial::new(coef.iter().map(|&c| Mint::new(c)).collect())
+    }
+
+    #[test]
+    fn inverse() {
+        let f = poly(&[5, 4, 3, 2, 1]);
+        for terms in 0..40 {
+            let g = f.fps_inverse(terms);
+            assert_eq!(g.coef().len(), terms);
+            let h = mul_trunc(f.coef(), g.coef(), terms, Some(PRIMITIVE_ROOT_998244353));
+            for (i, c) in h.into_iter().enumerate() {
+                assert_eq!(c, if i == 0 { 1 } else { 0 });
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_fallback() {
+        // 1e9 + 7 is not NTT-friendly
+        let f = Polynomial::new(vec![ModInt1e9_7::new(1), ModInt1e9_7::new(1)]);
+        let g = f.fps_inverse(5);
+        let one = ModInt1e9_7::new(1);
+        assert_eq!(g.coef(), &[one, -one, one, -one, one]);
+    }
+
+    #[test]
+    fn log_exp() {
+        let f = poly(&[0, 3, 1, 4, 1, 5, 9, 2, 6]);
+        let terms = 20;
+        let g = f.fps_exp(terms);
+        assert_eq!(g.fps_log(terms).coef(), f.prefix(terms).as_slice());
+
+        let h = poly(&[1, 2, 7, 1, 8, 2, 8]);
+        assert_eq!(
+            h.fps_log(terms).fps_exp(terms).coef(),
+            h.prefix(terms).as_slice()
+        );
+    }
+
+    #[test]
+    fn exp_derivative() {
+        // g = exp(f) satisfies g' = f' g
+        let f = poly(&[0, 1, 1]);
+        let n = 10;
+        let g = f.fps_exp(n + 1);
+        for k in 0..n {
+            let lhs = g[k + 1] * (k + 1);
+            let mut rhs = g[k];
+            if k >= 1 {
+                rhs += g[k - 1] * 2;
+            }
+            assert_eq!(lhs, rhs);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverse_zero_constant() {
+        poly(&[0, 1]).fps_inverse(3);
+    }
+}
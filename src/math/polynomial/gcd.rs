@@ -0,0 +1,203 @@
+use super::{ModInt, Modulo};
+
+type Set = usize;
+
+/// Removes the trailing zeros so that the last coefficient is the leading one.
+fn trim<M: Modulo<Set = Set> + Copy>(mut a: Vec<ModInt<M>>) -> Vec<ModInt<M>> {
+    while a.last() == Some(&ModInt::zero()) {
+        a.pop();
+    }
+    a
+}
+
+/// Makes the leading coefficient one.
+fn monic<M: Modulo<Set = Set> + Copy>(mut a: Vec<ModInt<M>>) -> Vec<ModInt<M>> {
+    if let Some(&lead) = a.last() {
+        let inv = lead.recip();
+        for c in &mut a {
+            *c *= inv;
+        }
+    }
+    a
+}
+
+/// Divides the polynomial `a` by `b` and returns the quotient and the remainder.
+///
+/// The coefficients are in ascending order of the degree, and the results have no trailing zeros.
+/// The modulus must be a prime.
+///
+/// This operation is O(*nm*).
+///
+/// # Panics
+///
+/// Panics if `b` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::gcd::poly_div_rem_mod;
+///
+/// // x² + 3x + 5 = (x + 2)(x + 1) + 3
+/// let a = [5, 3, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let b = [1, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let (q, r) = poly_div_rem_mod(&a, &b);
+/// assert_eq!(q, vec![2, 1]);
+/// assert_eq!(r, vec![3]);
+/// ```
+pub fn poly_div_rem_mod<M>(a: &[ModInt<M>], b: &[ModInt<M>]) -> (Vec<ModInt<M>>, Vec<ModInt<M>>)
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    let b = trim(b.to_vec());
+    let mut r = trim(a.to_vec());
+    assert!(!b.is_empty(), "the divisor must not be zero");
+    if r.len() < b.len() {
+        return (vec![], r);
+    }
+    let inv = b[b.len() - 1].recip();
+    let mut q = vec![ModInt::zero(); r.len() - b.len() + 1];
+    for i in (0..q.len()).rev() {
+        let c = r[i + b.len() - 1] * inv;
+        q[i] = c;
+        for (x, &y) in r[i..].iter_mut().zip(&b) {
+            *x -= c * y;
+        }
+    }
+    r.truncate(b.len() - 1);
+    (q, trim(r))
+}
+
+/// Computes the monic greatest common divisor of the polynomials `a` and `b` with the Euclidean algorithm.
+///
+/// The coefficients are in ascending order of the degree.
+/// Returns the empty vec if both are zero.
+/// The modulus must be a prime.
+///
+/// This operation is O(*nm*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::gcd::poly_gcd_mod;
+///
+/// // (x + 1)(x + 2) and (x + 1)(x + 3)
+/// let a = [2, 3, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let b = [3, 4, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// assert_eq!(poly_gcd_mod(&a, &b), vec![1, 1]);
+/// ```
+pub fn poly_gcd_mod<M>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    let (mut a, mut b) = (trim(a.to_vec()), trim(b.to_vec()));
+    while !b.is_empty() {
+        let (_, r) = poly_div_rem_mod(&a, &b);
+        a = b;
+        b = r;
+    }
+    monic(a)
+}
+
+/// Computes the monic least common multiple of the polynomials `a` and `b`.
+///
+/// The coefficients are in ascending order of the degree.
+/// Returns the empty vec if either is zero.
+/// The modulus must be a prime.
+///
+/// This operation is O(*nm*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::gcd::poly_lcm_mod;
+///
+/// // (x + 1)(x + 2) and (x + 1)(x + 3)
+/// let a = [2, 3, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let b = [3, 4, 1].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// // (x + 1)(x + 2)(x + 3)
+/// assert_eq!(poly_lcm_mod(&a, &b), vec![6, 11, 6, 1]);
+/// ```
+pub fn poly_lcm_mod<M>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    let (a, b) = (trim(a.to_vec()), trim(b.to_vec()));
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let (q, _) = poly_div_rem_mod(&a, &poly_gcd_mod(&a, &b));
+    let mut prod = vec![ModInt::zero(); q.len() + b.len() - 1];
+    for (i, &x) in q.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            prod[i + j] += x * y;
+        }
+    }
+    monic(prod)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::ModInt998244353 as Mint;
+    use crate::testing::xorshift;
+
+    fn mul(a: &[Mint], b: &[Mint]) -> Vec<Mint> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut prod = vec![Mint::zero(); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                prod[i + j] += x * y;
+            }
+        }
+        trim(prod)
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+        let mut poly = |n: usize| (0..n).map(|_| Mint::new(rand() % 5)).collect::<Vec<_>>();
+
+        for _ in 0..300 {
+            let (g, a, b) = (poly(4), poly(5), poly(5));
+            let (ga, gb) = (mul(&g, &a), mul(&g, &b));
+
+            // division
+            if !b.iter().all(|&x| x == Mint::zero()) {
+                let (q, r) = poly_div_rem_mod(&ga, &b);
+                assert!(r.len() < trim(b.clone()).len());
+                let mut back = mul(&q, &b);
+                back.resize(back.len().max(r.len()), Mint::zero());
+                for (x, &y) in back.iter_mut().zip(&r) {
+                    *x += y;
+                }
+                assert_eq!(trim(back), ga);
+            }
+
+            // the gcd divides both and is divided by `g`
+            let d = poly_gcd_mod(&ga, &gb);
+            if d.is_empty() {
+                assert!(ga.is_empty() && gb.is_empty());
+                continue;
+            }
+            assert_eq!(d.last(), Some(&Mint::one()));
+            assert!(poly_div_rem_mod(&ga, &d).1.is_empty());
+            assert!(poly_div_rem_mod(&gb, &d).1.is_empty());
+            if !g.iter().all(|&x| x == Mint::zero()) {
+                assert!(poly_div_rem_mod(&d, &g).1.is_empty());
+            }
+
+            // gcd * lcm = a * b up to a constant
+            let l = poly_lcm_mod(&ga, &gb);
+            if !ga.is_empty() && !gb.is_empty() {
+                assert_eq!(mul(&d, &l), monic(mul(&ga, &gb)));
+            } else {
+                assert!(l.is_empty());
+            }
+        }
+    }
+}
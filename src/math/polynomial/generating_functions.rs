@@ -0,0 +1,122 @@
+use super::fps::{mul_trunc, ntt_root};
+use super::{ModInt, ModIntFactMap, Modulo};
+
+type Set = usize;
+
+/// Converts the exponential generating function `Σ aₖxᵏ/k!` given as `[a₀/0!, a₁/1!, …]`
+/// to the ordinary generating function `[a₀, a₁, …]`, that is, multiplies the `k`-th coefficient by `k!`.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::{ModInt998244353 as Mint, Modulo998244353};
+/// use lib_rust::math::modint::ModIntFactMap;
+/// use lib_rust::math::polynomial::generating_functions::egf_to_ogf;
+///
+/// let mut fact = ModIntFactMap::<Modulo998244353>::new(10);
+/// // eˣ = Σ xᵏ/k!
+/// let egf = (0..5).map(|k| fact.recip_fact(k)).collect::<Vec<_>>();
+/// assert_eq!(egf_to_ogf(&egf, &mut fact), vec![Mint::new(1); 5]);
+/// ```
+pub fn egf_to_ogf<M>(egf: &[ModInt<M>], fact: &mut ModIntFactMap<M>) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    egf.iter()
+        .enumerate()
+        .map(|(k, &a)| a * fact.factorial(k))
+        .collect()
+}
+
+/// Converts the ordinary generating function `[a₀, a₁, …]`
+/// to the exponential generating function `[a₀/0!, a₁/1!, …]`, that is, divides the `k`-th coefficient by `k!`.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::{ModInt998244353 as Mint, Modulo998244353};
+/// use lib_rust::math::modint::ModIntFactMap;
+/// use lib_rust::math::polynomial::generating_functions::{egf_to_ogf, ogf_to_egf};
+///
+/// let mut fact = ModIntFactMap::<Modulo998244353>::new(10);
+/// let ogf = vec![Mint::new(3), Mint::new(1), Mint::new(4), Mint::new(1)];
+/// let egf = ogf_to_egf(&ogf, &mut fact);
+/// assert_eq!(egf[2] * Mint::new(2), ogf[2]);
+/// assert_eq!(egf_to_ogf(&egf, &mut fact), ogf);
+/// ```
+pub fn ogf_to_egf<M>(ogf: &[ModInt<M>], fact: &mut ModIntFactMap<M>) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    ogf.iter()
+        .enumerate()
+        .map(|(k, &a)| a * fact.recip_fact(k))
+        .collect()
+}
+
+/// Computes the binomial convolution `cₙ = Σₖ C(n, k) aₖ bₙ₋ₖ`,
+/// which corresponds to the product of the exponential generating functions.
+///
+/// The result has `a.len() + b.len() - 1` terms, or no terms if either is empty.
+/// The modulus must be a prime greater than the length of the result.
+///
+/// This operation is O(*n* log(*n*)) for NTT-friendly moduli, and O(*n*²) otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::generating_functions::binomial_convolution;
+///
+/// // Σₖ C(n, k) = 2ⁿ, which holds for `n` less than the lengths
+/// let ones = vec![Mint::new(1); 5];
+/// let c = binomial_convolution(&ones, &ones);
+/// assert_eq!(c.len(), 9);
+/// assert_eq!(c[..5], [1, 2, 4, 8, 16].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>()[..]);
+/// ```
+pub fn binomial_convolution<M>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let n = a.len() + b.len() - 1;
+    let mut fact = ModIntFactMap::new(n);
+    let a = ogf_to_egf(a, &mut fact);
+    let b = ogf_to_egf(b, &mut fact);
+    egf_to_ogf(&mul_trunc(&a, &b, n, ntt_root::<M>()), &mut fact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::{ModInt1e9_7 as Mint, Modulo1e9_7};
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| Mint::new(seed as usize));
+
+        let mut fact = ModIntFactMap::<Modulo1e9_7>::new(1);
+        for n in 0..10 {
+            for m in 0..10 {
+                let a = (0..n).map(|_| rand()).collect::<Vec<_>>();
+                let b = (0..m).map(|_| rand()).collect::<Vec<_>>();
+                let len = if n == 0 || m == 0 { 0 } else { n + m - 1 };
+                let mut expected = vec![Mint::zero(); len];
+                for (i, &x) in a.iter().enumerate() {
+                    for (j, &y) in b.iter().enumerate() {
+                        expected[i + j] += fact.combination(i + j, i) * x * y;
+                    }
+                }
+                assert_eq!(binomial_convolution(&a, &b), expected);
+                assert_eq!(egf_to_ogf(&ogf_to_egf(&a, &mut fact), &mut fact), a);
+            }
+        }
+    }
+}
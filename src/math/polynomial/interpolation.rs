@@ -0,0 +1,190 @@
+use super::{ModInt, Modulo, Polynomial};
+
+type Set = usize;
+
+/// Computes the polynomial of degree less than `n` passing through the `n` points `(xs[i], ys[i])`.
+///
+/// The modulus must be a prime.
+///
+/// This operation is O(*n*²).
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths.
+/// The result is meaningless if `xs` has duplicated values.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::interpolation::lagrange_interpolation;
+///
+/// // 1 + 2x + 3x²
+/// let xs = [0, 1, 2].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let ys = [1, 6, 17].iter().map(|&y| Mint::new(y)).collect::<Vec<_>>();
+/// let f = lagrange_interpolation(&xs, &ys);
+/// assert_eq!(f.coef(), &[1, 2, 3]);
+/// ```
+pub fn lagrange_interpolation<M>(xs: &[ModInt<M>], ys: &[ModInt<M>]) -> Polynomial<ModInt<M>>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+    // (x - xs[0])(x - xs[1])…(x - xs[n - 1])
+    let all = Polynomial::from_roots(xs);
+    let mut coef = vec![ModInt::zero(); n];
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        let denom = xs
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .fold(ModInt::one(), |acc, (_, &xj)| acc * (xi - xj));
+        let w = yi / denom;
+        // divide `all` by (x - xi) with the synthetic division from the highest degree
+        let mut q = ModInt::zero();
+        for (c, &a) in coef.iter_mut().zip(&all.coef()[1..]).rev() {
+            q = a + q * xi;
+            *c += w * q;
+        }
+    }
+    Polynomial::new(coef)
+}
+
+/// Evaluates the polynomial of degree less than `n` passing through the `n` points `(xs[i], ys[i])` at `t`,
+/// without computing its coefficients.
+///
+/// The modulus must be a prime.
+///
+/// This operation is O(*n*²).
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt998244353 as Mint;
+/// use lib_rust::math::polynomial::interpolation::lagrange_eval;
+///
+/// // 1 + 2x + 3x²
+/// let xs = [3, 1, 4].iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+/// let ys = [34, 6, 57].iter().map(|&y| Mint::new(y)).collect::<Vec<_>>();
+/// assert_eq!(lagrange_eval(&xs, &ys, Mint::new(10)), 321);
+/// ```
+pub fn lagrange_eval<M>(xs: &[ModInt<M>], ys: &[ModInt<M>], t: ModInt<M>) -> ModInt<M>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    assert_eq!(xs.len(), ys.len());
+    if let Some(i) = xs.iter().position(|&x| x == t) {
+        return ys[i];
+    }
+    let mut sum = ModInt::zero();
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        let (mut num, mut denom) = (yi, ModInt::one());
+        for (j, &xj) in xs.iter().enumerate() {
+            if j != i {
+                num *= t - xj;
+                denom *= xi - xj;
+            }
+        }
+        sum += num / denom;
+    }
+    sum
+}
+
+/// Evaluates the polynomial of degree less than `n` passing through the points `(i, ys[i])` at `t`.
+///
+/// The modulus must be a prime greater than `n`.
+///
+/// This operation is O(*n* + log(*MOD*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::modint::types::ModInt1e9_7 as Mint;
+/// use lib_rust::math::polynomial::interpolation::lagrange_eval_consecutive;
+///
+/// // the sums of squares 0² + 1² + … + i²
+/// let ys = [0, 1, 5, 14].iter().map(|&y| Mint::new(y)).collect::<Vec<_>>();
+/// assert_eq!(lagrange_eval_consecutive(&ys, 100), 338_350);
+/// ```
+// `is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn lagrange_eval_consecutive<M>(ys: &[ModInt<M>], t: Set) -> ModInt<M>
+where
+    M: Modulo<Set = Set> + Copy,
+{
+    let n = ys.len();
+    if n == 0 {
+        return ModInt::zero();
+    }
+    let t = ModInt::new(t);
+
+    // prefix[i] = (t - 0)…(t - (i - 1)) and suffix[i] = (t - i)…(t - (n - 1))
+    let mut prefix = vec![ModInt::one(); n + 1];
+    for i in 0..n {
+        prefix[i + 1] = prefix[i] * (t - ModInt::new(i));
+    }
+    let mut suffix = vec![ModInt::one(); n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = suffix[i + 1] * (t - ModInt::new(i));
+    }
+
+    let mut inv_fact = vec![ModInt::one(); n];
+    inv_fact[n - 1] = (1..n)
+        .fold(ModInt::one(), |acc, i| acc * ModInt::new(i))
+        .recip();
+    for i in (1..n).rev() {
+        inv_fact[i - 1] = inv_fact[i] * ModInt::new(i);
+    }
+
+    // the denominator of the i-th term is i! (n - 1 - i)! (-1)^(n - 1 - i)
+    let mut sum = ModInt::zero();
+    for (i, &y) in ys.iter().enumerate() {
+        let term = y * prefix[i] * suffix[i + 1] * inv_fact[i] * inv_fact[n - 1 - i];
+        if (n - 1 - i) % 2 == 0 {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::modint::types::ModInt998244353 as Mint;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| Mint::new(seed as usize));
+
+        for n in 0..10 {
+            let f = Polynomial::new((0..n).map(|_| rand()).collect::<Vec<_>>());
+            // distinct points
+            let mut xs = Vec::new();
+            while xs.len() < n {
+                let x = rand();
+                if !xs.contains(&x) {
+                    xs.push(x);
+                }
+            }
+            let ys = xs.iter().map(|&x| f.eval(x)).collect::<Vec<_>>();
+            assert_eq!(lagrange_interpolation(&xs, &ys), f);
+
+            let consecutive = (0..n).map(|i| f.eval(Mint::new(i))).collect::<Vec<_>>();
+            for t in (0..20).chain(Some(998_244_353 + 1)) {
+                assert_eq!(lagrange_eval(&xs, &ys, Mint::new(t)), f.eval(Mint::new(t)));
+                assert_eq!(
+                    lagrange_eval_consecutive(&consecutive, t),
+                    f.eval(Mint::new(t))
+                );
+            }
+        }
+    }
+}
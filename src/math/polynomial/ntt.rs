@@ -0,0 +1,229 @@
+/// The modulus `998244353 = 119 × 2²³ + 1`.
+pub const MOD_998244353: u64 = 998_244_353;
+/// A primitive root of [`MOD_998244353`].
+pub const PRIMITIVE_ROOT_998244353: u64 = 3;
+
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut acc = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    acc
+}
+
+/// Returns the smallest primitive root of the prime `p`.
+///
+/// This operation is O(√*p*).
+///
+/// # Panics
+///
+/// Panics if `p` is not less than 2³², since the products are computed in `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::polynomial::ntt::primitive_root;
+///
+/// assert_eq!(primitive_root(998_244_353), 3);
+/// assert_eq!(primitive_root(7), 3);
+/// ```
+// `is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn primitive_root(p: u64) -> u64 {
+    assert!(p < 1 << 32, "the modulus must be less than 2^32");
+    if p == 2 {
+        return 1;
+    }
+    let mut factors = vec![];
+    let mut m = p - 1;
+    let mut d = 2;
+    while d * d <= m {
+        if m % d == 0 {
+            factors.push(d);
+            while m % d == 0 {
+                m /= d;
+            }
+        }
+        d += 1;
+    }
+    if m > 1 {
+        factors.push(m);
+    }
+    (2..)
+        .find(|&g| factors.iter().all(|&q| pow_mod(g, (p - 1) / q, p) != 1))
+        .unwrap()
+}
+
+/// Computes the number theoretic transform of `a` in-place.
+///
+/// `modulus` must be a prime such that `a.len()` divides `modulus - 1`,
+/// and `primitive_root` must be a primitive root of `modulus`.
+/// The length of `a` must be a power of two, and the elements must be less than `modulus`.
+/// If `invert` is `true`, it computes the inverse transform including the division by `a.len()`.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two,
+/// or `modulus` is not less than 2³², since the products are computed in `u64`.
+pub fn ntt(a: &mut [u64], modulus: u64, primitive_root: u64, invert: bool) {
+    assert!(modulus < 1 << 32, "the modulus must be less than 2^32");
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    assert_eq!((modulus - 1) % n as u64, 0);
+
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = pow_mod(primitive_root, (modulus - 1) / len as u64, modulus);
+        let w = if invert {
+            pow_mod(w, modulus - 2, modulus)
+        } else {
+            w
+        };
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut wk = 1;
+            for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                let u = *x;
+                let v = *y * wk % modulus;
+                *x = if u + v >= modulus {
+                    u + v - modulus
+                } else {
+                    u + v
+                };
+                *y = if u >= v { u - v } else { u + modulus - v };
+                wk = wk * w % modulus;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, modulus - 2, modulus);
+        for x in a.iter_mut() {
+            *x = *x * n_inv % modulus;
+        }
+    }
+}
+
+/// Computes the convolution of `a` and `b` modulo `modulus`.
+///
+/// See [`ntt`] for the requirements of `modulus` and `primitive_root`.
+///
+/// This operation is O((*n* + *m*) log(*n* + *m*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::polynomial::ntt::{ntt_convolve, MOD_998244353, PRIMITIVE_ROOT_998244353};
+///
+/// let c = ntt_convolve(&[1, 1], &[1, 1], MOD_998244353, PRIMITIVE_ROOT_998244353);
+/// assert_eq!(c, vec![1, 2, 1]);
+/// ```
+pub fn ntt_convolve(a: &[u64], b: &[u64], modulus: u64, primitive_root: u64) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+
+    let mut fa = a.iter().map(|&x| x % modulus).collect::<Vec<_>>();
+    let mut fb = b.iter().map(|&x| x % modulus).collect::<Vec<_>>();
+    fa.resize(n, 0);
+    fb.resize(n, 0);
+    ntt(&mut fa, modulus, primitive_root, false);
+    ntt(&mut fb, modulus, primitive_root, false);
+    for (x, y) in fa.iter_mut().zip(fb.iter()) {
+        *x = *x * y % modulus;
+    }
+    ntt(&mut fa, modulus, primitive_root, true);
+    fa.truncate(len);
+    fa
+}
+
+/// Computes [`ntt`] with modulo `998244353`.
+pub fn ntt_998244353(a: &mut [u64], invert: bool) {
+    ntt(a, MOD_998244353, PRIMITIVE_ROOT_998244353, invert)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    fn naive(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+        let mut c = vec![0; a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                c[i + j] = (c[i + j] + x * y) % modulus;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn convolve() {
+        let m = MOD_998244353;
+        let r = PRIMITIVE_ROOT_998244353;
+        assert_eq!(ntt_convolve(&[1, 1], &[1, 1], m, r), vec![1, 2, 1]);
+        assert_eq!(
+            ntt_convolve(&[1, 2, 3, 4], &[5, 6, 7, 8, 9], m, r),
+            vec![5, 16, 34, 60, 70, 70, 59, 36]
+        );
+        assert!(ntt_convolve(&[], &[1], m, r).is_empty());
+
+        let mut rand = xorshift(0x0123_4567_u64, |seed| seed);
+        for &(n, k) in &[(1, 1), (7, 9), (64, 64), (100, 37)] {
+            let a = (0..n).map(|_| rand() % m).collect::<Vec<_>>();
+            let b = (0..k).map(|_| rand() % m).collect::<Vec<_>>();
+            assert_eq!(ntt_convolve(&a, &b, m, r), naive(&a, &b, m));
+        }
+    }
+
+    #[test]
+    fn other_modulus() {
+        // 469762049 = 7 × 2²⁶ + 1
+        let (m, r) = (469_762_049, 3);
+        let a = vec![123_456, 7, 469_762_048];
+        let b = vec![3, 141_592, 65, 35];
+        assert_eq!(ntt_convolve(&a, &b, m, r), naive(&a, &b, m));
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(primitive_root(2), 1);
+        assert_eq!(primitive_root(469_762_049), 3);
+        assert_eq!(primitive_root(1_000_000_007), 5);
+        assert_eq!(primitive_root(167_772_161), 3);
+    }
+
+    #[test]
+    fn inverse() {
+        let v = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let mut a = v.clone();
+        ntt_998244353(&mut a, false);
+        assert_ne!(a, v);
+        ntt_998244353(&mut a, true);
+        assert_eq!(a, v);
+    }
+}
@@ -0,0 +1,242 @@
+use super::{One, Zero};
+use core::ops::{Add, Index, Mul, Neg, Sub};
+
+/// A polynomial with coefficients in ascending degree order.
+///
+/// `coef[i]` is the coefficient of `xⁱ`.
+/// The trailing zero coefficients are not removed automatically.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::polynomial::Polynomial;
+///
+/// // 1 + 2x
+/// let f = Polynomial::new(vec![1, 2]);
+/// // 3 - x + x²
+/// let g = Polynomial::new(vec![3, -1, 1]);
+///
+/// assert_eq!((f.clone() + g.clone()).coef(), &[4, 1, 1]);
+/// assert_eq!((f.clone() * g.clone()).coef(), &[3, 5, -1, 2]);
+/// assert_eq!(g.eval(2), 5);
+/// assert_eq!(g.degree(), Some(2));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Polynomial<T> {
+    coef: Vec<T>,
+}
+
+impl<T> Polynomial<T> {
+    /// Creates a polynomial from coefficients in ascending degree order.
+    pub fn new(coef: Vec<T>) -> Self {
+        Self { coef }
+    }
+
+    /// Returns the coefficients in ascending degree order.
+    pub fn coef(&self) -> &[T] {
+        &self.coef
+    }
+
+    /// Returns the coefficients in ascending degree order.
+    pub fn into_coef(self) -> Vec<T> {
+        self.coef
+    }
+
+    /// Keeps only the first `n` coefficients, that is, it computes `self mod xⁿ`.
+    pub fn truncate(mut self, n: usize) -> Self {
+        self.coef.truncate(n);
+        self
+    }
+}
+
+impl<T: Zero + PartialEq> Polynomial<T> {
+    /// Returns the degree, or `None` for the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::polynomial::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::new(vec![1, 2, 0]).degree(), Some(1));
+    /// assert_eq!(Polynomial::new(vec![0, 0]).degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        let zero = T::zero();
+        self.coef.iter().rposition(|c| *c != zero)
+    }
+
+    /// Returns the coefficient of the highest degree term, or `None` for the zero polynomial.
+    pub fn leading_coeff(&self) -> Option<&T> {
+        self.degree().map(|d| &self.coef[d])
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    /// Evaluates the polynomial at `x` with Horner's method.
+    ///
+    /// This operation is O(*n*).
+    pub fn eval(&self, x: T) -> T {
+        self.coef
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Creates the monic polynomial `(x - r₀)(x - r₁)…` from its roots.
+    ///
+    /// This operation is O(*n*²).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::polynomial::Polynomial;
+    ///
+    /// let f = Polynomial::from_roots(&[1, -2]);
+    /// assert_eq!(f.coef(), &[-2, 1, 1]);
+    /// ```
+    pub fn from_roots(roots: &[T]) -> Self {
+        let mut coef = vec![T::one()];
+        for r in roots {
+            // multiply by `x - r`
+            let mut next = vec![T::zero(); coef.len() + 1];
+            for (i, c) in coef.into_iter().enumerate() {
+                next[i + 1] = next[i + 1].clone() + c.clone();
+                next[i] = next[i].clone() - c * r.clone();
+            }
+            coef = next;
+        }
+        Self::new(coef)
+    }
+}
+
+impl<T> From<Vec<T>> for Polynomial<T> {
+    fn from(coef: Vec<T>) -> Self {
+        Self::new(coef)
+    }
+}
+
+impl<T> Index<usize> for Polynomial<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.coef[index]
+    }
+}
+
+impl<T: Zero + Add<Output = T>> Add for Polynomial<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut long, short) = if self.coef.len() >= rhs.coef.len() {
+            (self.coef, rhs.coef)
+        } else {
+            (rhs.coef, self.coef)
+        };
+        for (i, c) in short.into_iter().enumerate() {
+            let x = core::mem::replace(&mut long[i], T::zero());
+            long[i] = x + c;
+        }
+        Self::new(long)
+    }
+}
+
+impl<T: Zero + Sub<Output = T>> Sub for Polynomial<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut coef = self.coef;
+        let n = coef.len().max(rhs.coef.len());
+        coef.extend((coef.len()..n).map(|_| T::zero()));
+        for (i, c) in rhs.coef.into_iter().enumerate() {
+            let x = core::mem::replace(&mut coef[i], T::zero());
+            coef[i] = x - c;
+        }
+        Self::new(coef)
+    }
+}
+
+impl<T: Zero + Sub<Output = T>> Neg for Polynomial<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coef.into_iter().map(|c| T::zero() - c).collect())
+    }
+}
+
+impl<T> Mul for Polynomial<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    /// Multiplies naively in O(*nm*).
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coef.is_empty() || rhs.coef.is_empty() {
+            return Self::new(vec![]);
+        }
+        let mut coef = vec![T::zero(); self.coef.len() + rhs.coef.len() - 1];
+        for (i, a) in self.coef.iter().enumerate() {
+            for (j, b) in rhs.coef.iter().enumerate() {
+                coef[i + j] = coef[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Self::new(coef)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ops() {
+        let f = Polynomial::new(vec![1, 2, 3]);
+        let g = Polynomial::new(vec![-1, 5]);
+        assert_eq!((f.clone() + g.clone()).coef(), &[0, 7, 3]);
+        assert_eq!((g.clone() + f.clone()).coef(), &[0, 7, 3]);
+        assert_eq!((f.clone() - g.clone()).coef(), &[2, -3, 3]);
+        assert_eq!((g.clone() - f.clone()).coef(), &[-2, 3, -3]);
+        assert_eq!((-g.clone()).coef(), &[1, -5]);
+        assert_eq!((f.clone() * g.clone()).coef(), &[-1, 3, 7, 15]);
+        assert!((f * Polynomial::new(vec![])).coef().is_empty());
+    }
+
+    #[test]
+    fn eval_matches_product() {
+        let f = Polynomial::new(vec![3_i64, 0, -2, 1]);
+        let g = Polynomial::new(vec![1_i64, 1]);
+        let h = f.clone() * g.clone();
+        for x in -5..=5 {
+            assert_eq!(h.eval(x), f.eval(x) * g.eval(x));
+        }
+    }
+
+    #[test]
+    fn roots() {
+        let roots = vec![2_i64, -3, 5, 0];
+        let f = Polynomial::from_roots(&roots);
+        assert_eq!(f.degree(), Some(4));
+        assert_eq!(f.leading_coeff(), Some(&1));
+        for &r in &roots {
+            assert_eq!(f.eval(r), 0);
+        }
+        assert_eq!(f.eval(1), (1 - 2) * (1 + 3) * (1 - 5));
+        assert_eq!(f.truncate(2).coef(), &[0, 30]);
+    }
+
+    #[test]
+    fn zero_polynomial() {
+        let f = Polynomial::<i32>::new(vec![]);
+        assert_eq!(f.degree(), None);
+        assert_eq!(f.leading_coeff(), None);
+        assert_eq!(f.eval(10), 0);
+    }
+}
@@ -97,11 +97,12 @@ impl Rational {
     }
 
     /// Reduce a fraction.
+    /// Zero is always non-negative, so that it has a unique representation.
     /// This function does *not* check zero denominator.
     fn simplify(self) -> Self {
         let gcd = self.numerator.gcd(self.denominator);
         Self {
-            minus: self.minus,
+            minus: self.minus && self.numerator != 0,
             numerator: self.numerator / gcd,
             denominator: self.denominator / gcd,
         }
@@ -532,6 +533,19 @@ mod tests {
         assert_eq!(Rational::from((-9, -3)), 3);
     }
 
+    #[test]
+    fn zero_sign() {
+        let zero = Rational::new(true, 0, 5).unwrap();
+        assert_eq!(zero, Rational::ZERO);
+        assert!(!zero.is_negative());
+        assert_eq!(-Rational::ZERO, Rational::ZERO);
+        assert_eq!(Rational::from((0, -3)), 0);
+        let x = Rational::from((-2, 3));
+        assert_eq!(x - x, Rational::ZERO);
+        assert!(Rational::from((-1, 2)) < -Rational::ZERO);
+        assert_eq!((-Rational::ZERO).to_string(), Rational::ZERO.to_string());
+    }
+
     #[test]
     fn add() {
         let a: Rational = (7, 6).into();
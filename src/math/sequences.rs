@@ -0,0 +1,179 @@
+use super::matrix::mat_pow;
+use super::Matrix;
+
+/// Returns the `n`-th Fibonacci number, where `F(0) = 0` and `F(1) = 1`.
+///
+/// This operation is O(*n*) for small `n`, and O(log(*n*)) with matrix exponentiation otherwise.
+///
+/// # Panics
+///
+/// Panics if `n > 186`, since `F(187)` overflows `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::sequences::fibonacci;
+///
+/// assert_eq!(fibonacci(0), 0);
+/// assert_eq!(fibonacci(10), 55);
+/// assert_eq!(fibonacci(100), 354_224_848_179_261_915_075);
+/// ```
+pub fn fibonacci(n: u64) -> u128 {
+    assert!(n <= 186, "F({}) overflows u128", n);
+    if n < 64 {
+        let (mut a, mut b) = (0_u128, 1_u128);
+        for _ in 0..n {
+            let c = a + b;
+            a = b;
+            b = c;
+        }
+        a
+    } else {
+        // [[F(n), F(n - 1)], [F(n - 1), F(n - 2)]], which does not overflow for `n = 186`
+        Matrix::new(vec![vec![1_u128, 1], vec![1, 0]]).pow(n - 1)[(0, 0)]
+    }
+}
+
+/// Computes the `n`-th term of the linear recurrence
+/// `a[k] = coeffs[0] * a[k - 1] + coeffs[1] * a[k - 2] + … + coeffs[N - 1] * a[k - N]` modulo `m`,
+/// where `init` is `[a[0], a[1], …, a[N - 1]]`.
+///
+/// It is the same as [`nth_linear_recurrence`](super::matrix::nth_linear_recurrence)
+/// with a modulus given at runtime.
+///
+/// This operation is O(*N*³ log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `init` have different lengths, or `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::sequences::linear_recurrence_mod;
+///
+/// // a[k] = 2a[k - 1] + 3a[k - 2]
+/// assert_eq!(linear_recurrence_mod(&[2, 3], &[1, 1], 4, 1000), 41);
+/// ```
+pub fn linear_recurrence_mod(coeffs: &[u64], init: &[u64], n: u64, m: u64) -> u64 {
+    let k = coeffs.len();
+    assert_eq!(k, init.len());
+    assert!(m > 0);
+    if k == 0 {
+        return 0;
+    }
+    if n < k as u64 {
+        return init[n as usize] % m;
+    }
+
+    // companion matrix mapping (a[i + k - 1], …, a[i]) to (a[i + k], …, a[i + 1])
+    let mut base = Matrix::zero(k);
+    for (j, &c) in coeffs.iter().enumerate() {
+        base[(0, j)] = c;
+    }
+    for i in 1..k {
+        base[(i, i - 1)] = 1;
+    }
+    let acc = mat_pow(&base, n - (k as u64 - 1), m);
+
+    // a[n] is the first entry of acc * (a[k - 1], …, a[0])
+    acc.rows()[0]
+        .iter()
+        .zip(init.iter().rev())
+        .fold(0, |s, (&a, &b)| {
+            ((s as u128 + a as u128 * (b % m) as u128) % m as u128) as u64
+        })
+}
+
+/// Returns the `n`-th Fibonacci number modulo `m`.
+///
+/// This operation is O(log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::sequences::fibonacci_mod;
+///
+/// assert_eq!(fibonacci_mod(10, 1000), 55);
+/// assert_eq!(fibonacci_mod(1_000_000_000_000, 1_000_000_007), 730_695_249);
+/// ```
+pub fn fibonacci_mod(n: u64, m: u64) -> u64 {
+    linear_recurrence_mod(&[1, 1], &[0, 1], n, m)
+}
+
+/// Returns the `n`-th tribonacci number modulo `m`,
+/// where `T(0) = T(1) = 0`, `T(2) = 1` and `T(n) = T(n - 1) + T(n - 2) + T(n - 3)`.
+///
+/// This operation is O(log(*n*)).
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::sequences::tribonacci_mod;
+///
+/// // 0, 0, 1, 1, 2, 4, 7, 13, 24, 44, …
+/// assert_eq!(tribonacci_mod(9, 100), 44);
+/// assert_eq!(tribonacci_mod(9, 10), 4);
+/// ```
+pub fn tribonacci_mod(n: u64, m: u64) -> u64 {
+    linear_recurrence_mod(&[1, 1, 1], &[0, 0, 1], n, m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fibonacci_small_and_large() {
+        let mut fib = vec![0_u128, 1];
+        for i in 2..=186 {
+            let next = fib[i - 1] + fib[i - 2];
+            fib.push(next);
+        }
+        for (n, &f) in fib.iter().enumerate() {
+            assert_eq!(fibonacci(n as u64), f);
+            let m = 1_000_000_007;
+            assert_eq!(fibonacci_mod(n as u64, m) as u128, f % m as u128);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fibonacci_overflow() {
+        fibonacci(187);
+    }
+
+    #[test]
+    fn recurrence() {
+        // a[k] = 3a[k - 1] + 0a[k - 2] + 5a[k - 3] mod 97
+        let m = 97;
+        let mut a = vec![4_u64, 100, 7];
+        for k in 3..50 {
+            let next = (3 * a[k - 1] + 5 * a[k - 3]) % m;
+            a.push(next);
+        }
+        for (n, &x) in a.iter().enumerate() {
+            assert_eq!(
+                linear_recurrence_mod(&[3, 0, 5], &[4, 100, 7], n as u64, m),
+                x % m
+            );
+        }
+        assert_eq!(linear_recurrence_mod(&[1, 1], &[0, 1], 5, 1), 0);
+        assert_eq!(linear_recurrence_mod(&[], &[], 5, 7), 0);
+    }
+
+    #[test]
+    fn large_modulus() {
+        let m = 18_446_744_073_709_551_557; // the largest prime below 2⁶⁴
+        assert_eq!(fibonacci_mod(90, m), fibonacci(90) as u64);
+        assert_eq!(tribonacci_mod(3, m), 1);
+    }
+}
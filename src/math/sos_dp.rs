@@ -0,0 +1,163 @@
+use super::{Group, Monoid};
+
+fn log2_len(n: usize) -> u32 {
+    assert!(n.is_power_of_two(), "the length must be a power of two");
+    n.trailing_zeros()
+}
+
+/// Computes the subset sum transform (zeta transform) in-place.
+///
+/// After the call, `f[mask]` is the fold of `f[sub]` for all `sub ⊆ mask`.
+/// The operation of `M` must be commutative.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if the length of `f` is not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::math::sos_dp::sos_dp;
+///
+/// let mut f = vec![1, 2, 3, 4];
+/// sos_dp::<AddMonoid<i64>>(&mut f);
+/// assert_eq!(f, vec![1, 1 + 2, 1 + 3, 1 + 2 + 3 + 4]);
+/// ```
+pub fn sos_dp<M: Monoid>(f: &mut [M::Set]) {
+    let k = log2_len(f.len());
+    for i in 0..k {
+        let bit = 1 << i;
+        for mask in 0..f.len() {
+            if mask & bit != 0 {
+                f[mask] = M::operate(&f[mask ^ bit], &f[mask]);
+            }
+        }
+    }
+}
+
+/// Computes the superset sum transform in-place.
+///
+/// After the call, `f[mask]` is the fold of `f[sup]` for all `sup ⊇ mask`.
+/// The operation of `M` must be commutative.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if the length of `f` is not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::math::sos_dp::superset_zeta;
+///
+/// let mut f = vec![1, 2, 3, 4];
+/// superset_zeta::<AddMonoid<i64>>(&mut f);
+/// assert_eq!(f, vec![1 + 2 + 3 + 4, 2 + 4, 3 + 4, 4]);
+/// ```
+pub fn superset_zeta<M: Monoid>(f: &mut [M::Set]) {
+    let k = log2_len(f.len());
+    for i in 0..k {
+        let bit = 1 << i;
+        for mask in 0..f.len() {
+            if mask & bit == 0 {
+                f[mask] = M::operate(&f[mask], &f[mask | bit]);
+            }
+        }
+    }
+}
+
+/// Computes the inverse of [`sos_dp`] (Möbius transform) in-place.
+///
+/// The operation of `M` must be commutative.
+///
+/// This operation is O(*n* log(*n*)).
+///
+/// # Panics
+///
+/// Panics if the length of `f` is not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddGroup;
+/// use lib_rust::math::sos_dp::{sos_dp, sos_inverse};
+///
+/// let mut f = vec![3, 1, 4, 1, 5, 9, 2, 6];
+/// sos_dp::<AddGroup<i64>>(&mut f);
+/// sos_inverse::<AddGroup<i64>>(&mut f);
+/// assert_eq!(f, vec![3, 1, 4, 1, 5, 9, 2, 6]);
+/// ```
+pub fn sos_inverse<M: Group>(f: &mut [M::Set]) {
+    let k = log2_len(f.len());
+    for i in 0..k {
+        let bit = 1 << i;
+        for mask in 0..f.len() {
+            if mask & bit != 0 {
+                f[mask] = M::inv_operate(&f[mask], &f[mask ^ bit]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::{AddGroup, AddMonoid, BitOrMonoid, MaxMonoid};
+
+    fn naive<M: Monoid>(f: &[M::Set], superset: bool) -> Vec<M::Set> {
+        (0..f.len())
+            .map(|mask| {
+                (0..f.len())
+                    .filter(|&x| {
+                        if superset {
+                            x & mask == mask
+                        } else {
+                            x & mask == x
+                        }
+                    })
+                    .fold(M::id(), |acc, x| M::operate(&acc, &f[x]))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn add() {
+        let f = (0..32_i64).map(|x| x * x - 7 * x).collect::<Vec<_>>();
+
+        let mut g = f.clone();
+        sos_dp::<AddMonoid<i64>>(&mut g);
+        assert_eq!(g, naive::<AddMonoid<i64>>(&f, false));
+        sos_inverse::<AddGroup<i64>>(&mut g);
+        assert_eq!(g, f);
+
+        let mut g = f.clone();
+        superset_zeta::<AddMonoid<i64>>(&mut g);
+        assert_eq!(g, naive::<AddMonoid<i64>>(&f, true));
+    }
+
+    #[test]
+    fn other_monoids() {
+        let f = vec![5_u32, 2, 9, 1, 0, 7, 3, 8];
+        let mut g = f.clone();
+        sos_dp::<MaxMonoid<u32>>(&mut g);
+        assert_eq!(g, naive::<MaxMonoid<u32>>(&f, false));
+        let mut g = f.clone();
+        superset_zeta::<BitOrMonoid<u32>>(&mut g);
+        assert_eq!(g, naive::<BitOrMonoid<u32>>(&f, true));
+
+        let mut single = vec![42_i64];
+        sos_dp::<AddMonoid<i64>>(&mut single);
+        assert_eq!(single, vec![42]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_power_of_two() {
+        sos_dp::<AddMonoid<i64>>(&mut [1, 2, 3]);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::math::num::Monoid;
+
+#[cfg_attr(nightly, codesnip::entry("SuffixAutomaton"))]
+pub mod sam;
+
+#[cfg_attr(nightly, codesnip::entry("AhoCorasick", include("Monoid")))]
+pub mod aho_corasick;
+
+#[cfg_attr(nightly, codesnip::entry("Eertree"))]
+pub mod eertree;
+
+#[cfg_attr(nightly, codesnip::entry("SuffixArray"))]
+pub mod suffix_array;
+
+#[cfg_attr(nightly, codesnip::entry("Periods"))]
+pub mod periods;
@@ -0,0 +1,256 @@
+use super::Monoid;
+use std::collections::{BTreeMap, VecDeque};
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    next: BTreeMap<u8, usize>,
+    fail: usize,
+    // the patterns equal to the string of this node
+    ends: Vec<usize>,
+}
+
+/// The Aho-Corasick automaton to find all the occurrences of multiple patterns in a text.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::aho_corasick::AhoCorasick;
+///
+/// let ac = AhoCorasick::new(&["he", "she", "his", "hers"]);
+/// assert_eq!(ac.find_all(b"ushers"), vec![(1, 1), (2, 0), (2, 3)]);
+/// assert_eq!(ac.count_at(b"ushers"), vec![0, 0, 0, 2, 0, 1]);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm    | Worst case                 |
+/// | ------------ | -------------------------- |
+/// | [`new`]      | O(*M* log(*σ*))            |
+/// | [`find_all`] | O(*n* log(*σ*) + *k*)      |
+/// | [`count_at`] | O(*n* log(*σ*))            |
+/// | [`fold`]     | O(*n* log(*σ*) + *M*)      |
+///
+/// *M* is the total length of the patterns, *n* is the length of the text,
+/// *σ* is the size of the alphabet and *k* is the number of occurrences.
+///
+/// [`new`]: AhoCorasick::new
+/// [`find_all`]: AhoCorasick::find_all
+/// [`count_at`]: AhoCorasick::count_at
+/// [`fold`]: AhoCorasick::fold
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+    // the nodes in BFS order, so the failure link of a node comes before it
+    order: Vec<usize>,
+    pattern_lens: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Builds the automaton of `patterns`.
+    ///
+    /// An empty pattern occurs at every position, including after the last byte.
+    pub fn new<P: AsRef<[u8]>>(patterns: &[P]) -> Self {
+        let mut nodes = vec![Node::default()];
+        for (i, p) in patterns.iter().enumerate() {
+            let mut v = 0;
+            for &c in p.as_ref() {
+                v = match nodes[v].next.get(&c) {
+                    Some(&u) => u,
+                    None => {
+                        nodes.push(Node::default());
+                        let u = nodes.len() - 1;
+                        nodes[v].next.insert(c, u);
+                        u
+                    }
+                };
+            }
+            nodes[v].ends.push(i);
+        }
+
+        let mut ret = Self {
+            nodes,
+            order: vec![],
+            pattern_lens: patterns.iter().map(|p| p.as_ref().len()).collect(),
+        };
+        let mut que = VecDeque::new();
+        que.push_back(0);
+        while let Some(v) = que.pop_front() {
+            ret.order.push(v);
+            let next = ret.nodes[v]
+                .next
+                .iter()
+                .map(|(&c, &u)| (c, u))
+                .collect::<Vec<_>>();
+            for (c, u) in next {
+                ret.nodes[u].fail = if v == 0 {
+                    0
+                } else {
+                    ret.step(ret.nodes[v].fail, c)
+                };
+                que.push_back(u);
+            }
+        }
+        ret
+    }
+
+    /// Returns the number of the states.
+    pub fn num_states(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the state after reading `c` at the state `v`.
+    fn step(&self, mut v: usize, c: u8) -> usize {
+        loop {
+            if let Some(&u) = self.nodes[v].next.get(&c) {
+                return u;
+            }
+            if v == 0 {
+                return 0;
+            }
+            v = self.nodes[v].fail;
+        }
+    }
+
+    /// Returns the states after reading each prefix of `text`.
+    fn states<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        text.iter().scan(0, move |v, &c| {
+            *v = self.step(*v, c);
+            Some(*v)
+        })
+    }
+
+    /// Returns all the occurrences as `(start, pattern_index)`,
+    /// in ascending order of the end position and then in descending order of the length.
+    pub fn find_all(&self, text: &[u8]) -> Vec<(usize, usize)> {
+        let mut ret = vec![];
+        for (i, mut v) in self.states(text).enumerate() {
+            loop {
+                for &p in &self.nodes[v].ends {
+                    ret.push((i + 1 - self.pattern_lens[p], p));
+                }
+                if v == 0 {
+                    break;
+                }
+                v = self.nodes[v].fail;
+            }
+        }
+        ret
+    }
+
+    /// Returns the number of the occurrences ending at each byte of `text`,
+    /// that is, `count_at(text)[i]` is the number of the patterns which are suffixes of `text[..=i]`.
+    pub fn count_at(&self, text: &[u8]) -> Vec<usize> {
+        let mut count = vec![0; self.nodes.len()];
+        for &v in &self.order {
+            let inherited = if v == 0 { 0 } else { count[self.nodes[v].fail] };
+            count[v] = self.nodes[v].ends.len() + inherited;
+        }
+        self.states(text).map(|v| count[v]).collect()
+    }
+
+    /// Folds `weights[p]` of all the occurrences of the patterns `p` in `text`.
+    ///
+    /// The order of the occurrences is the same as [`find_all`](Self::find_all).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers of `weights` and the patterns are different.
+    pub fn fold<M: Monoid>(&self, text: &[u8], weights: &[M::Set]) -> M::Set {
+        assert_eq!(weights.len(), self.pattern_lens.len());
+        // the fold of the occurrences ending at the current position
+        let mut acc = vec![M::id(); self.nodes.len()];
+        for &v in &self.order {
+            let own = self.nodes[v]
+                .ends
+                .iter()
+                .fold(M::id(), |acc, &p| M::operate(&acc, &weights[p]));
+            acc[v] = if v == 0 {
+                own
+            } else {
+                M::operate(&own, &acc[self.nodes[v].fail])
+            };
+        }
+        self.states(text)
+            .fold(M::id(), |sum, v| M::operate(&sum, &acc[v]))
+    }
+}
+
+/// Folds `weights[p]` of all the occurrences of `patterns[p]` in `text` with the Aho-Corasick automaton.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::{AddMonoid, MaxMonoid};
+/// use lib_rust::string::aho_corasick::aho_corasick_fold;
+///
+/// let patterns: [&[u8]; 3] = [b"a", b"ab", b"bab"];
+/// assert_eq!(aho_corasick_fold::<AddMonoid<i32>>(b"abab", &patterns, &[1, 10, 100]), 122);
+/// assert_eq!(aho_corasick_fold::<MaxMonoid<i32>>(b"aaa", &patterns, &[1, 10, 100]), 1);
+/// ```
+pub fn aho_corasick_fold<M: Monoid>(text: &[u8], patterns: &[&[u8]], weights: &[M::Set]) -> M::Set {
+    AhoCorasick::new(patterns).fold::<M>(text, weights)
+}
+
+/// Returns the number of the occurrences of `patterns` ending at each byte of `text`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::aho_corasick::aho_corasick_count_at;
+///
+/// let patterns: [&[u8]; 2] = [b"aa", b"a"];
+/// assert_eq!(aho_corasick_count_at(b"aaba", &patterns), vec![1, 2, 0, 1]);
+/// ```
+pub fn aho_corasick_count_at(text: &[u8], patterns: &[&[u8]]) -> Vec<usize> {
+    AhoCorasick::new(patterns).count_at(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::AddMonoid;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for _ in 0..200 {
+            let patterns = (0..rand() % 6)
+                .map(|_| (0..rand() % 4).map(|_| b'a' + (rand() % 2) as u8).collect())
+                .collect::<Vec<Vec<u8>>>();
+            let text = (0..rand() % 20)
+                .map(|_| b'a' + (rand() % 3) as u8)
+                .collect::<Vec<_>>();
+            let ac = AhoCorasick::new(&patterns);
+
+            let mut expected = vec![];
+            for end in 1..=text.len() {
+                let mut found = vec![];
+                for (p, pat) in patterns.iter().enumerate() {
+                    if text[..end].ends_with(pat) {
+                        found.push((end - pat.len(), p));
+                    }
+                }
+                // longer first, and in the order of the patterns for the same length
+                found.sort_by_key(|&(s, p)| (s, p));
+                expected.extend(found);
+            }
+            assert_eq!(ac.find_all(&text), expected);
+
+            let count = (0..text.len())
+                .map(|i| {
+                    expected
+                        .iter()
+                        .filter(|&&(s, p)| s + patterns[p].len() == i + 1)
+                        .count()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(ac.count_at(&text), count);
+
+            let weights = (0..patterns.len()).map(|p| 1 << p).collect::<Vec<u64>>();
+            let sum = expected.iter().map(|&(_, p)| weights[p]).sum::<u64>();
+            assert_eq!(ac.fold::<AddMonoid<u64>>(&text, &weights), sum);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug)]
+struct Node {
+    next: BTreeMap<u8, usize>,
+    // the longest proper palindromic suffix
+    link: usize,
+    // `-1` for the imaginary root
+    len: isize,
+    // the number of palindromic suffixes of this palindrome
+    depth: u64,
+}
+
+impl Node {
+    fn new(len: isize, link: usize, depth: u64) -> Self {
+        Self {
+            next: BTreeMap::new(),
+            link,
+            len,
+            depth,
+        }
+    }
+}
+
+/// The palindromic tree (eertree) of a string.
+///
+/// Each node except the two roots corresponds to a distinct palindromic substring.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::eertree::Eertree;
+///
+/// let t = Eertree::build(b"abaaba");
+/// // a, b, aba, aa, baab, abaaba
+/// assert_eq!(t.count_distinct_palindromic_substrings(), 6);
+/// assert_eq!(t.count_palindromic_substrings(), 11);
+/// assert_eq!(t.longest_palindromic_suffix_at(3), 2);
+/// assert_eq!(t.longest_palindromic_suffix_at(5), 6);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm                                   | Worst case          |
+/// | ------------------------------------------- | ------------------- |
+/// | [`build`]                                   | O(*n* log(*σ*))     |
+/// | [`count_distinct_palindromic_substrings`]   | O(1)                |
+/// | [`count_palindromic_substrings`]            | O(*n*)              |
+/// | [`longest_palindromic_suffix_at`]           | O(1)                |
+///
+/// *σ* is the size of the alphabet.
+///
+/// [`build`]: Eertree::build
+/// [`count_distinct_palindromic_substrings`]: Eertree::count_distinct_palindromic_substrings
+/// [`count_palindromic_substrings`]: Eertree::count_palindromic_substrings
+/// [`longest_palindromic_suffix_at`]: Eertree::longest_palindromic_suffix_at
+#[derive(Clone, Debug)]
+pub struct Eertree {
+    // `nodes[0]` is the imaginary root of length -1 and `nodes[1]` is the empty palindrome
+    nodes: Vec<Node>,
+    // the node of the longest palindromic suffix ending at each position
+    suffix: Vec<usize>,
+}
+
+impl Eertree {
+    pub fn build(s: &[u8]) -> Self {
+        let mut nodes = vec![Node::new(-1, 0, 0), Node::new(0, 0, 0)];
+        let mut suffix = Vec::with_capacity(s.len());
+
+        // the longest suffix palindrome `p` of `s[..i]` such that `c p c` is a suffix of `s[..=i]`
+        let extendable = |nodes: &[Node], mut v: usize, i: usize| loop {
+            let j = i as isize - 1 - nodes[v].len;
+            if j >= 0 && s[j as usize] == s[i] {
+                return v;
+            }
+            v = nodes[v].link;
+        };
+
+        let mut last = 1;
+        for (i, &c) in s.iter().enumerate() {
+            let v = extendable(&nodes, last, i);
+            last = match nodes[v].next.get(&c) {
+                Some(&u) => u,
+                None => {
+                    let len = nodes[v].len + 2;
+                    let link = if len == 1 {
+                        1
+                    } else {
+                        let u = extendable(&nodes, nodes[v].link, i);
+                        nodes[u].next[&c]
+                    };
+                    let depth = nodes[link].depth + 1;
+                    nodes.push(Node::new(len, link, depth));
+                    let w = nodes.len() - 1;
+                    nodes[v].next.insert(c, w);
+                    w
+                }
+            };
+            suffix.push(last);
+        }
+
+        Self { nodes, suffix }
+    }
+
+    /// Returns the number of distinct non-empty palindromic substrings.
+    pub fn count_distinct_palindromic_substrings(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// Returns the number of non-empty palindromic substrings, where the same strings at different positions are counted separately.
+    pub fn count_palindromic_substrings(&self) -> u64 {
+        self.suffix.iter().map(|&v| self.nodes[v].depth).sum()
+    }
+
+    /// Returns the length of the longest palindromic suffix of `s[..=pos]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of `s`.
+    pub fn longest_palindromic_suffix_at(&self, pos: usize) -> usize {
+        self.nodes[self.suffix[pos]].len as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..40 {
+            for &sigma in &[1, 2, 3, 26] {
+                let s = (0..n)
+                    .map(|_| b'a' + (rand() % sigma) as u8)
+                    .collect::<Vec<_>>();
+                let t = Eertree::build(&s);
+
+                let is_palindrome = |p: &[u8]| p.iter().eq(p.iter().rev());
+                let mut distinct = BTreeSet::new();
+                let mut count = 0;
+                for r in 1..=n {
+                    let mut longest = 0;
+                    for l in 0..r {
+                        if is_palindrome(&s[l..r]) {
+                            distinct.insert(&s[l..r]);
+                            count += 1;
+                            longest = longest.max(r - l);
+                        }
+                    }
+                    assert_eq!(t.longest_palindromic_suffix_at(r - 1), longest);
+                }
+                assert_eq!(t.count_distinct_palindromic_substrings(), distinct.len());
+                assert_eq!(t.count_palindromic_substrings(), count);
+            }
+        }
+    }
+}
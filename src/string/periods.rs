@@ -0,0 +1,124 @@
+/// Returns the KMP failure function, i.e. the length of the longest proper border of each prefix.
+fn failure(s: &[u8]) -> Vec<usize> {
+    let mut fail = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = fail[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = fail[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        fail[i] = k;
+    }
+    fail
+}
+
+/// Returns the shortest period of `s`, i.e. the smallest `p > 0` such that `s[i] == s[i + p]` for all valid `i`.
+///
+/// The shortest period is `n` minus the length of the longest proper border.
+/// It does not necessarily divide `n`; see [`is_primitive`] for that.
+/// Returns `0` for the empty string.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::periods::string_period;
+///
+/// assert_eq!(string_period(b"abcabcab"), 3);
+/// assert_eq!(string_period(b"abab"), 2);
+/// assert_eq!(string_period(b"abc"), 3);
+/// ```
+pub fn string_period(s: &[u8]) -> usize {
+    match failure(s).last() {
+        Some(&border) => s.len() - border,
+        None => 0,
+    }
+}
+
+/// Checks whether `s` is not a repetition of a shorter string.
+///
+/// `s` is a repetition iff its shortest period is a proper divisor of `n`.
+/// The empty string is not primitive.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::periods::is_primitive;
+///
+/// assert!(is_primitive(b"abcab"));
+/// assert!(!is_primitive(b"abab"));
+/// assert!(is_primitive(b"a"));
+/// ```
+// `is_multiple_of` requires Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn is_primitive(s: &[u8]) -> bool {
+    let n = s.len();
+    let p = string_period(s);
+    n > 0 && (p == n || n % p != 0)
+}
+
+/// Returns the lengths of all the proper non-empty borders of `s` in ascending order.
+///
+/// A border is a string that is both a prefix and a suffix of `s`,
+/// and the borders are obtained by following the failure function from the longest one.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::periods::string_border_lengths;
+///
+/// assert_eq!(string_border_lengths(b"abacaba"), vec![1, 3]);
+/// assert_eq!(string_border_lengths(b"aaaa"), vec![1, 2, 3]);
+/// assert!(string_border_lengths(b"abc").is_empty());
+/// ```
+pub fn string_border_lengths(s: &[u8]) -> Vec<usize> {
+    let fail = failure(s);
+    let mut borders = vec![];
+    let mut k = fail.last().copied().unwrap_or(0);
+    while k > 0 {
+        borders.push(k);
+        k = fail[k - 1];
+    }
+    borders.reverse();
+    borders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..40 {
+            for _ in 0..20 {
+                let s = (0..n)
+                    .map(|_| b'a' + (rand() % 2) as u8)
+                    .collect::<Vec<_>>();
+
+                let borders = (1..n).filter(|&k| s[..k] == s[n - k..]).collect::<Vec<_>>();
+                assert_eq!(string_border_lengths(&s), borders);
+
+                let period = (1..=n)
+                    .find(|&p| (0..n - p).all(|i| s[i] == s[i + p]))
+                    .unwrap_or(0);
+                assert_eq!(string_period(&s), period);
+
+                let primitive = n > 0
+                    && (1..n)
+                        .filter(|&p| n % p == 0)
+                        .all(|p| (0..n - p).any(|i| s[i] != s[i + p]));
+                assert_eq!(is_primitive(&s), primitive, "{:?}", s);
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug)]
+struct State {
+    len: usize,
+    link: Option<usize>,
+    next: BTreeMap<u8, usize>,
+    // the end position of the first occurrence
+    first_pos: usize,
+    is_clone: bool,
+}
+
+/// A suffix automaton, the minimal automaton which accepts all the suffixes of a string.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::sam::SuffixAutomaton;
+///
+/// let sam = SuffixAutomaton::build(b"abcbc");
+/// assert_eq!(sam.count_distinct_substrings(), 12);
+/// assert_eq!(sam.count_occurrences(b"bc"), 2);
+/// assert_eq!(sam.all_occurrences(b"bc"), vec![1, 3]);
+/// assert_eq!(sam.longest_common_substring(b"xxcbcx"), 3);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm                    | Worst case            |
+/// | ---------------------------- | --------------------- |
+/// | [`build`]                    | O(*n* log(*σ*))       |
+/// | [`count_distinct_substrings`]| O(*n*)                |
+/// | [`count_occurrences`]        | O(*m* log(*σ*))       |
+/// | [`all_occurrences`]          | O(*m* log(*σ*) + *k*) |
+/// | [`longest_common_substring`] | O(*m* log(*σ*))       |
+///
+/// *m* is the length of the argument, *σ* is the size of the alphabet and *k* is the number of occurrences.
+///
+/// [`build`]: SuffixAutomaton::build
+/// [`count_distinct_substrings`]: SuffixAutomaton::count_distinct_substrings
+/// [`count_occurrences`]: SuffixAutomaton::count_occurrences
+/// [`all_occurrences`]: SuffixAutomaton::all_occurrences
+/// [`longest_common_substring`]: SuffixAutomaton::longest_common_substring
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    // the number of occurrences of the strings of each state
+    occurrences: Vec<usize>,
+    // children in the suffix link tree
+    children: Vec<Vec<usize>>,
+    text_len: usize,
+}
+
+impl SuffixAutomaton {
+    /// Builds the suffix automaton of `s`.
+    pub fn build(s: &[u8]) -> Self {
+        let mut states = Vec::with_capacity(2 * s.len() + 1);
+        states.push(State {
+            len: 0,
+            link: None,
+            next: BTreeMap::new(),
+            first_pos: 0,
+            is_clone: false,
+        });
+        let mut last = 0;
+
+        for (i, &c) in s.iter().enumerate() {
+            let cur = states.len();
+            states.push(State {
+                len: states[last].len + 1,
+                link: None,
+                next: BTreeMap::new(),
+                first_pos: i,
+                is_clone: false,
+            });
+
+            let mut p = Some(last);
+            while let Some(v) = p {
+                if states[v].next.contains_key(&c) {
+                    break;
+                }
+                states[v].next.insert(c, cur);
+                p = states[v].link;
+            }
+
+            states[cur].link = Some(match p {
+                None => 0,
+                Some(p) => {
+                    let q = states[p].next[&c];
+                    if states[p].len + 1 == states[q].len {
+                        q
+                    } else {
+                        let clone = states.len();
+                        let mut state = states[q].clone();
+                        state.len = states[p].len + 1;
+                        state.is_clone = true;
+                        states.push(state);
+
+                        let mut p = Some(p);
+                        while let Some(v) = p {
+                            if states[v].next.get(&c) != Some(&q) {
+                                break;
+                            }
+                            states[v].next.insert(c, clone);
+                            p = states[v].link;
+                        }
+                        states[q].link = Some(clone);
+                        clone
+                    }
+                }
+            });
+            last = cur;
+        }
+
+        // Accumulate the occurrences along the suffix links from the longer states.
+        let mut occurrences = states
+            .iter()
+            .map(|s| if s.is_clone || s.len == 0 { 0 } else { 1 })
+            .collect::<Vec<_>>();
+        let mut order = (0..states.len()).collect::<Vec<_>>();
+        order.sort_unstable_by_key(|&v| core::cmp::Reverse(states[v].len));
+        let mut children = vec![vec![]; states.len()];
+        for &v in &order {
+            if let Some(link) = states[v].link {
+                occurrences[link] += occurrences[v];
+                children[link].push(v);
+            }
+        }
+
+        Self {
+            states,
+            occurrences,
+            children,
+            text_len: s.len(),
+        }
+    }
+
+    /// Returns the number of the states.
+    pub fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns the state reached by `pattern`, or `None` if it is not a substring.
+    fn walk(&self, pattern: &[u8]) -> Option<usize> {
+        pattern
+            .iter()
+            .try_fold(0, |v, c| self.states[v].next.get(c).copied())
+    }
+
+    /// Returns the number of the distinct non-empty substrings.
+    pub fn count_distinct_substrings(&self) -> u64 {
+        self.states
+            .iter()
+            .filter_map(|s| s.link.map(|link| (s.len - self.states[link].len) as u64))
+            .sum()
+    }
+
+    /// Returns the number of the (possibly overlapping) occurrences of `pattern`.
+    ///
+    /// The empty pattern occurs `n + 1` times.
+    pub fn count_occurrences(&self, pattern: &[u8]) -> usize {
+        if pattern.is_empty() {
+            return self.text_len + 1;
+        }
+        self.walk(pattern).map_or(0, |v| self.occurrences[v])
+    }
+
+    /// Returns the start positions of all the occurrences of `pattern` in ascending order.
+    pub fn all_occurrences(&self, pattern: &[u8]) -> Vec<usize> {
+        if pattern.is_empty() {
+            return (0..=self.text_len).collect();
+        }
+        let v = match self.walk(pattern) {
+            Some(v) => v,
+            None => return vec![],
+        };
+
+        // Each non-cloned state in the subtree corresponds to exactly one end position.
+        let mut positions = vec![];
+        let mut stack = vec![v];
+        while let Some(u) = stack.pop() {
+            if !self.states[u].is_clone {
+                positions.push(self.states[u].first_pos + 1 - pattern.len());
+            }
+            stack.extend(self.children[u].iter().copied());
+        }
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns the length of the longest common substring of the original string and `other`.
+    pub fn longest_common_substring(&self, other: &[u8]) -> usize {
+        let (mut v, mut len, mut best) = (0, 0, 0);
+        for c in other {
+            // Shorten the match until it can be extended with `c`.
+            while v != 0 && !self.states[v].next.contains_key(c) {
+                v = self.states[v].link.unwrap();
+                len = self.states[v].len;
+            }
+            if let Some(&to) = self.states[v].next.get(c) {
+                v = to;
+                len += 1;
+            }
+            best = best.max(len);
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn rand_string(seed: &mut u64, n: usize, sigma: u64) -> Vec<u8> {
+        (0..n)
+            .map(|_| {
+                *seed ^= *seed << 13;
+                *seed ^= *seed >> 7;
+                *seed ^= *seed << 17;
+                b'a' + (*seed % sigma) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn random() {
+        let mut seed = 0xC0FF_EE00_u64;
+        for n in 0..40 {
+            let s = rand_string(&mut seed, n, 3);
+            let sam = SuffixAutomaton::build(&s);
+            assert!(sam.num_states() <= 2 * n.max(1));
+
+            let substrings = (0..n)
+                .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+                .map(|(i, j)| s[i..j].to_vec())
+                .collect::<BTreeSet<_>>();
+            assert_eq!(sam.count_distinct_substrings(), substrings.len() as u64);
+
+            for _ in 0..10 {
+                let m = (seed % 4) as usize;
+                let p = rand_string(&mut seed, m, 3);
+                let naive = (0..=n)
+                    .filter(|&i| i + m <= n && s[i..i + m] == p[..])
+                    .collect::<Vec<_>>();
+                assert_eq!(sam.count_occurrences(&p), naive.len());
+                assert_eq!(sam.all_occurrences(&p), naive);
+
+                let t = rand_string(&mut seed, 10, 3);
+                let naive_lcs = (0..=t.len())
+                    .flat_map(|i| (i..=t.len()).map(move |j| (i, j)))
+                    .filter(|&(i, j)| substrings.contains(&t[i..j]) || i == j)
+                    .map(|(i, j)| j - i)
+                    .max()
+                    .unwrap();
+                assert_eq!(sam.longest_common_substring(&t), naive_lcs);
+            }
+        }
+    }
+
+    #[test]
+    fn repeated() {
+        let sam = SuffixAutomaton::build(b"aaaaa");
+        assert_eq!(sam.count_distinct_substrings(), 5);
+        assert_eq!(sam.count_occurrences(b"aa"), 4);
+        assert_eq!(sam.all_occurrences(b"aaa"), vec![0, 1, 2]);
+        assert_eq!(sam.count_occurrences(b"b"), 0);
+        assert!(sam.all_occurrences(b"ab").is_empty());
+    }
+}
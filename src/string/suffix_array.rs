@@ -0,0 +1,206 @@
+use core::cmp::Ordering::{Greater, Less};
+use core::ops::Range;
+
+/// Returns the suffix array of `s`, i.e. the starting positions of the suffixes in lexicographic order.
+///
+/// It sorts the suffixes by the first `2k` bytes using the ranks by the first `k` bytes (prefix doubling).
+///
+/// This operation is O(*n* log²(*n*)).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::suffix_array::suffix_array;
+///
+/// assert_eq!(suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+/// ```
+pub fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa = (0..n).collect::<Vec<_>>();
+    if n <= 1 {
+        return sa;
+    }
+    // ranks start from 1, and 0 is for the empty suffix
+    let mut rank = s.iter().map(|&c| c as usize + 1).collect::<Vec<_>>();
+    let mut next = vec![0; n];
+    let mut k = 1;
+    loop {
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] } else { 0 });
+        sa.sort_unstable_by_key(|&i| key(i));
+        next[sa[0]] = 1;
+        for w in 1..n {
+            next[sa[w]] = next[sa[w - 1]] + (key(sa[w - 1]) < key(sa[w])) as usize;
+        }
+        core::mem::swap(&mut rank, &mut next);
+        // all the ranks are distinct
+        if rank[sa[n - 1]] == n {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// Returns the LCP array of `s` with Kasai's algorithm,
+/// where `lcp[i]` is the length of the longest common prefix of the suffixes `sa[i]` and `sa[i + 1]`.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::suffix_array::{lcp_array, suffix_array};
+///
+/// let sa = suffix_array(b"banana");
+/// assert_eq!(lcp_array(b"banana", &sa), vec![1, 3, 0, 0, 2]);
+/// ```
+pub fn lcp_array(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n.saturating_sub(1)];
+    // the LCP decreases by at most one when the suffix is shortened by one
+    let mut h = 0_usize;
+    for i in 0..n {
+        h = h.saturating_sub(1);
+        if rank[i] + 1 == n {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] + 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+    }
+    lcp
+}
+
+/// Builds a [`SuffixArrayIndex`] of `text`.
+pub fn build_sa_matching_index(text: &[u8]) -> SuffixArrayIndex<'_> {
+    SuffixArrayIndex::new(text)
+}
+
+/// A full-text index with the suffix array to find the occurrences of patterns.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::string::suffix_array::build_sa_matching_index;
+///
+/// let index = build_sa_matching_index(b"abracadabra");
+/// assert_eq!(index.count(b"abra"), 2);
+/// assert_eq!(index.count(b"a"), 5);
+/// assert_eq!(index.count(b"cab"), 0);
+///
+/// let mut positions = index.sa()[index.find_all(b"bra")].to_vec();
+/// positions.sort_unstable();
+/// assert_eq!(positions, vec![1, 8]);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm    | Worst case       |
+/// | ------------ | ---------------- |
+/// | [`new`]      | O(*n* log²(*n*)) |
+/// | [`find_all`] | O(*m* log(*n*))  |
+/// | [`count`]    | O(*m* log(*n*))  |
+///
+/// *m* is the length of the pattern.
+///
+/// [`new`]: SuffixArrayIndex::new
+/// [`find_all`]: SuffixArrayIndex::find_all
+/// [`count`]: SuffixArrayIndex::count
+#[derive(Clone, Debug)]
+pub struct SuffixArrayIndex<'a> {
+    text: &'a [u8],
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a> SuffixArrayIndex<'a> {
+    pub fn new(text: &'a [u8]) -> Self {
+        let sa = suffix_array(text);
+        let lcp = lcp_array(text, &sa);
+        Self { text, sa, lcp }
+    }
+
+    pub fn text(&self) -> &'a [u8] {
+        self.text
+    }
+
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Returns the range of the suffix array whose suffixes start with `pattern`,
+    /// so `self.sa()[range]` are the positions of the occurrences.
+    ///
+    /// An empty pattern matches all the suffixes.
+    pub fn find_all(&self, pattern: &[u8]) -> Range<usize> {
+        // compares only the first `m` bytes, so the suffixes with `pattern` as a prefix are equal
+        let prefix = |i: usize| &self.text[i..self.text.len().min(i + pattern.len())];
+        let lower = self
+            .sa
+            .binary_search_by(|&i| if prefix(i) < pattern { Less } else { Greater })
+            .unwrap_err();
+        let upper = self
+            .sa
+            .binary_search_by(|&i| if prefix(i) <= pattern { Less } else { Greater })
+            .unwrap_err();
+        lower..upper
+    }
+
+    /// Returns the number of the occurrences of `pattern`.
+    pub fn count(&self, pattern: &[u8]) -> usize {
+        self.find_all(pattern).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..50 {
+            for &sigma in &[1, 2, 3, 26] {
+                let s = (0..n)
+                    .map(|_| b'a' + (rand() % sigma) as u8)
+                    .collect::<Vec<_>>();
+                let index = build_sa_matching_index(&s);
+
+                let mut expected = (0..n).collect::<Vec<_>>();
+                expected.sort_by_key(|&i| &s[i..]);
+                assert_eq!(index.sa(), &expected[..]);
+                for (i, &h) in index.lcp().iter().enumerate() {
+                    let (a, b) = (&s[expected[i]..], &s[expected[i + 1]..]);
+                    assert_eq!(h, a.iter().zip(b).take_while(|(x, y)| x == y).count());
+                }
+
+                for _ in 0..10 {
+                    let m = rand() % 4;
+                    let pattern = (0..m)
+                        .map(|_| b'a' + (rand() % sigma) as u8)
+                        .collect::<Vec<_>>();
+                    let mut positions = index.sa()[index.find_all(&pattern)].to_vec();
+                    positions.sort_unstable();
+                    let naive = (0..n)
+                        .filter(|&i| s[i..].starts_with(&pattern))
+                        .collect::<Vec<_>>();
+                    assert_eq!(positions, naive);
+                    assert_eq!(index.count(&pattern), naive.len());
+                }
+            }
+        }
+    }
+}
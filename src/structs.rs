@@ -1,5 +1,10 @@
-use crate::math::num::Monoid;
-#[cfg_attr(nightly, codesnip::entry("BinaryIndexedTree", include("Monoid")))]
+use crate::math::num::alge_struct::monoid_pow;
+use crate::math::num::alge_struct::types::AddGroup;
+use crate::math::num::{Group, Monoid};
+#[cfg_attr(
+    nightly,
+    codesnip::entry("BinaryIndexedTree", include("Monoid", "Group"))
+)]
 pub mod binary_indexed_tree;
 #[codesnip::entry("BinaryIndexedTree")]
 #[allow(unused_imports)]
@@ -9,3 +14,42 @@ use binary_indexed_tree::{BinaryIndexedTree, BIT};
 pub mod union_find;
 
 pub mod segment_tree;
+
+#[cfg_attr(nightly, codesnip::entry("DynamicConnectivity"))]
+pub mod dynamic_connectivity;
+
+#[cfg_attr(nightly, codesnip::entry("ImplicitTreap"))]
+pub mod treap;
+
+#[cfg_attr(nightly, codesnip::entry("PersistentArray"))]
+pub mod persistent_array;
+
+#[cfg_attr(nightly, codesnip::entry("LeftistHeap"))]
+pub mod leftist_heap;
+
+#[cfg_attr(nightly, codesnip::entry("WaveletTree"))]
+pub mod wavelet_tree;
+
+#[cfg_attr(nightly, codesnip::entry("SqrtDecomp", include("Monoid", "MonoidPow")))]
+pub mod sqrt_decomp;
+
+#[cfg_attr(nightly, codesnip::entry("SparseSet"))]
+pub mod sparse_set;
+
+#[cfg_attr(nightly, codesnip::entry("MergeSortTree"))]
+pub mod merge_sort_tree;
+
+#[cfg_attr(nightly, codesnip::entry("CartesianTree"))]
+pub mod cartesian_tree;
+
+#[cfg_attr(nightly, codesnip::entry("VebTree"))]
+pub mod veb;
+
+#[cfg_attr(nightly, codesnip::entry("FractionalCascading"))]
+pub mod fractional_cascading;
+
+#[cfg_attr(
+    nightly,
+    codesnip::entry("OrderStatisticBIT", include("BinaryIndexedTree", "AddGroup"))
+)]
+pub mod order_statistic_bit;
@@ -1,5 +1,6 @@
-use super::Monoid;
+use super::{Group, Monoid};
 
+use std::fmt;
 use std::ops::{RangeFull, RangeTo, RangeToInclusive};
 
 /// A data structure that can efficiently operate elements and calculate prefix folds in a vec.
@@ -60,6 +61,11 @@ impl<T: Monoid> BinaryIndexedTree<T> {
         self.tree.len()
     }
 
+    /// Returns `true` if the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
     /// Returns a folded value.
     /// The `index` is allowed with [`RangeTo`], [`RangeToInclusive`] or [`RangeFull`].
     ///
@@ -107,6 +113,81 @@ impl<T: Monoid> BinaryIndexedTree<T> {
             i += lsb(i + 1);
         }
     }
+
+    /// Returns the largest `r` such that `f(&self.fold(..r))` is `true`.
+    ///
+    /// `f` must be monotone, i.e. `true` for shorter prefixes and `false` for longer ones,
+    /// and `f(&Monoid::id())` must be `true`.
+    /// The prefix is extended greedily by the nodes of decreasing sizes.
+    ///
+    /// This operation is O(log(*n*)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lib_rust::structs::binary_indexed_tree::BinaryIndexedTree;
+    /// use lib_rust::math::num::alge_struct::types::AddMonoid;
+    ///
+    /// let bit = BinaryIndexedTree::<AddMonoid<_>>::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(bit.max_right(|&s| s <= 5), 2);
+    /// assert_eq!(bit.max_right(|&s| s <= 6), 3);
+    /// assert_eq!(bit.max_right(|&s| s < 100), 4);
+    /// ```
+    pub fn max_right<F: FnMut(&T::Set) -> bool>(&self, mut f: F) -> usize {
+        let n = self.len();
+        let mut r = 0;
+        let mut acc = T::id();
+        let mut step = if n == 0 {
+            0
+        } else {
+            1 << (63 - (n as u64).leading_zeros())
+        };
+        while step > 0 {
+            if r + step <= n {
+                let next = T::operate(&acc, &self.tree[r + step - 1]);
+                if f(&next) {
+                    r += step;
+                    acc = next;
+                }
+            }
+            step >>= 1;
+        }
+        r
+    }
+}
+
+impl<T: Group> BinaryIndexedTree<T> {
+    /// Reconstructs the original values.
+    ///
+    /// The node `i` covers the elements in `(i + 1 - lsb(i + 1), i + 1]`,
+    /// so each prefix fold is obtained from a shorter one with a single operation.
+    ///
+    /// This operation is O(*n*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use lib_rust::structs::binary_indexed_tree::BinaryIndexedTree;
+    /// use lib_rust::math::num::alge_struct::types::AddGroup;
+    ///
+    /// let mut bit = BinaryIndexedTree::<AddGroup<_>>::from(vec![3, 1, 4, 1, 5]);
+    /// bit.operate(2, &5);
+    /// assert_eq!(bit.to_vec(), vec![3, 1, 9, 1, 5]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T::Set> {
+        let n = self.len();
+        // prefix[i] is the fold of the first `i` elements
+        let mut prefix = Vec::with_capacity(n + 1);
+        prefix.push(T::id());
+        for (i, node) in self.tree.iter().enumerate() {
+            let p = T::operate(&prefix[i + 1 - lsb(i + 1)], node);
+            prefix.push(p);
+        }
+        prefix
+            .windows(2)
+            .map(|w| T::operate(&T::inv(&w[0]), &w[1]))
+            .collect()
+    }
 }
 
 pub trait BITIndex<T: Monoid> {
@@ -139,6 +220,26 @@ impl<T: Monoid> BITIndex<T> for RangeFull {
     }
 }
 
+impl<T: Monoid> Clone for BinaryIndexedTree<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for BinaryIndexedTree<T>
+where
+    T: Monoid,
+    T::Set: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinaryIndexedTree")
+            .field("tree", &self.tree)
+            .finish()
+    }
+}
+
 impl<T: Monoid> From<Vec<T::Set>> for BinaryIndexedTree<T> {
     fn from(v: Vec<T::Set>) -> Self {
         let mut ret = Self::with_size(v.len());
@@ -148,3 +249,79 @@ impl<T: Monoid> From<Vec<T::Set>> for BinaryIndexedTree<T> {
         ret
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::AddGroup;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn to_vec() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as i64 % 100);
+
+        for n in 0..40 {
+            let mut naive = (0..n).map(|_| rand()).collect::<Vec<_>>();
+            let mut bit = BinaryIndexedTree::<AddGroup<i64>>::from(naive.clone());
+            for _ in 0..n {
+                let (i, x) = ((rand() + 100) as usize % n, rand());
+                naive[i] += x;
+                bit.operate(i, &x);
+                assert_eq!(bit.to_vec(), naive);
+            }
+            assert_eq!(bit.to_vec(), naive);
+        }
+    }
+
+    #[test]
+    fn random_operations() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for _ in 0..1000 {
+            let n = rand() % 20;
+            // non-negative values so that the prefix sums are monotone for `max_right`
+            let mut naive = (0..n).map(|_| (rand() % 10) as i64).collect::<Vec<_>>();
+            let mut bit = if rand() % 2 == 0 {
+                BinaryIndexedTree::<AddGroup<i64>>::from(naive.clone())
+            } else {
+                let mut bit = BinaryIndexedTree::<AddGroup<i64>>::with_size(n);
+                for (i, x) in naive.iter().enumerate() {
+                    bit.operate(i, x);
+                }
+                bit
+            };
+            for _ in 0..20 {
+                if n > 0 {
+                    let (i, v) = (rand() % n, (rand() % 10) as i64);
+                    naive[i] += v;
+                    bit.operate(i, &v);
+                }
+
+                let prefix = naive
+                    .iter()
+                    .scan(0, |acc, &x| {
+                        *acc += x;
+                        Some(*acc)
+                    })
+                    .collect::<Vec<_>>();
+                for (j, &p) in prefix.iter().enumerate() {
+                    assert_eq!(bit.fold(..=j), p);
+                    assert_eq!(bit.fold(..j + 1), p);
+                }
+                assert_eq!(bit.fold(..0), 0);
+                assert_eq!(bit.fold(..), prefix.last().copied().unwrap_or(0));
+
+                let (l, r) = (rand() % (n + 1), rand() % (n + 1));
+                let (l, r) = (l.min(r), l.max(r));
+                assert_eq!(
+                    bit.fold(..r) - bit.fold(..l),
+                    naive[l..r].iter().sum::<i64>()
+                );
+
+                let bound = (rand() % 100) as i64;
+                let expected = prefix.iter().take_while(|&&p| p <= bound).count();
+                assert_eq!(bit.max_right(|&s| s <= bound), expected);
+            }
+        }
+    }
+}
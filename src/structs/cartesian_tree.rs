@@ -0,0 +1,102 @@
+/// Builds the parent array of the Cartesian tree where `above(a, b)` tells `a` must be an ancestor of `b`.
+fn build<T, F: Fn(&T, &T) -> bool>(arr: &[T], above: F) -> Vec<Option<usize>> {
+    let mut parent = vec![None; arr.len()];
+    // the right spine of the tree built so far
+    let mut stack: Vec<usize> = Vec::with_capacity(arr.len());
+    for i in 0..arr.len() {
+        let mut last = None;
+        while let Some(&top) = stack.last() {
+            if above(&arr[top], &arr[i]) {
+                break;
+            }
+            last = stack.pop();
+        }
+        if let Some(last) = last {
+            parent[last] = Some(i);
+        }
+        if let Some(&top) = stack.last() {
+            parent[i] = Some(top);
+        }
+        stack.push(i);
+    }
+    parent
+}
+
+/// Builds the Cartesian tree of `arr` with the min-heap property, and returns the parent of each index.
+///
+/// The root has the parent `None`.
+/// The in-order traversal of the tree is `0, 1, …, n - 1`.
+/// For equal values, the left one is the ancestor.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::cartesian_tree::build_cartesian_tree_min;
+///
+/// //       1(1)
+/// //      /    \
+/// //   0(3)    3(2)
+/// //           /  \
+/// //        2(4)  4(5)
+/// let parent = build_cartesian_tree_min(&[3, 1, 4, 2, 5]);
+/// assert_eq!(parent, vec![Some(1), None, Some(3), Some(1), Some(3)]);
+/// ```
+pub fn build_cartesian_tree_min<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    build(arr, |a, b| a <= b)
+}
+
+/// Builds the Cartesian tree of `arr` with the max-heap property, and returns the parent of each index.
+///
+/// The root has the parent `None`.
+/// The in-order traversal of the tree is `0, 1, …, n - 1`.
+/// For equal values, the left one is the ancestor.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::cartesian_tree::build_cartesian_tree_max;
+///
+/// let parent = build_cartesian_tree_max(&[3, 1, 4, 2, 5]);
+/// assert_eq!(parent, vec![Some(2), Some(0), Some(4), Some(2), None]);
+/// ```
+pub fn build_cartesian_tree_max<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    build(arr, |a, b| a >= b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    /// Builds the tree recursively by choosing the leftmost minimum as the root.
+    fn naive(arr: &[u64], l: usize, r: usize, p: Option<usize>, parent: &mut [Option<usize>]) {
+        if l == r {
+            return;
+        }
+        let m = (l..r).fold(l, |m, i| if arr[i] < arr[m] { i } else { m });
+        parent[m] = p;
+        naive(arr, l, m, Some(m), parent);
+        naive(arr, m + 1, r, Some(m), parent);
+    }
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..30 {
+            for _ in 0..10 {
+                let arr = (0..n).map(|_| rand() % 5).collect::<Vec<_>>();
+                let mut expected = vec![None; n];
+                naive(&arr, 0, n, None, &mut expected);
+                assert_eq!(build_cartesian_tree_min(&arr), expected);
+
+                let neg = arr.iter().map(|&x| 4 - x).collect::<Vec<_>>();
+                assert_eq!(build_cartesian_tree_max(&neg), expected);
+            }
+        }
+    }
+}
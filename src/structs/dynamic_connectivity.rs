@@ -0,0 +1,232 @@
+/// A union-find without path compression which can undo the unions.
+struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    // merged roots `(child, parent)`, or `None` if nothing is merged
+    history: Vec<Option<(usize, usize)>>,
+}
+
+impl RollbackUnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+            history: vec![],
+        }
+    }
+
+    fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            self.history.push(None);
+            return;
+        }
+        if self.size[a] > self.size[b] {
+            core::mem::swap(&mut a, &mut b);
+        }
+        self.parent[a] = b;
+        self.size[b] += self.size[a];
+        self.components -= 1;
+        self.history.push(Some((a, b)));
+    }
+
+    fn rollback(&mut self) {
+        if let Some((a, b)) = self.history.pop().unwrap() {
+            self.parent[a] = a;
+            self.size[b] -= self.size[a];
+            self.components += 1;
+        }
+    }
+}
+
+/// Offline dynamic connectivity with a segment tree on the time and a union-find with rollback.
+///
+/// Each edge exists during a time interval, and each query asks whether two nodes are connected at a time.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::dynamic_connectivity::DynamicConnectivity;
+///
+/// let mut dc = DynamicConnectivity::new(3);
+/// dc.add_edge(0, 1, 0, 2);
+/// dc.add_edge(1, 2, 1, 3);
+/// dc.add_query(0, 0, 2);
+/// dc.add_query(1, 0, 2);
+/// dc.add_query(2, 0, 2);
+///
+/// let mut result = vec![];
+/// dc.run(3, |time, connected, components| result.push((time, connected, components)));
+/// assert_eq!(result, vec![(0, false, 2), (1, true, 1), (2, false, 2)]);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm     | Worst case                        |
+/// | ------------- | --------------------------------- |
+/// | [`add_edge`]  | O(1)                              |
+/// | [`add_query`] | O(1)                              |
+/// | [`run`]       | O((*E* log(*T*) + *Q*) log(*V*))  |
+///
+/// [`add_edge`]: DynamicConnectivity::add_edge
+/// [`add_query`]: DynamicConnectivity::add_query
+/// [`run`]: DynamicConnectivity::run
+pub struct DynamicConnectivity {
+    n: usize,
+    edges: Vec<(usize, usize, usize, usize)>,
+    queries: Vec<(usize, usize, usize)>,
+}
+
+impl DynamicConnectivity {
+    /// Creates an empty graph with `n` nodes.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            edges: vec![],
+            queries: vec![],
+        }
+    }
+
+    /// Adds an undirected edge `(u, v)` which exists during the time `[from, to)`.
+    pub fn add_edge(&mut self, u: usize, v: usize, from: usize, to: usize) {
+        assert!(u < self.n && v < self.n);
+        self.edges.push((u, v, from, to));
+    }
+
+    /// Adds a query whether `u` and `v` are connected at `time`.
+    pub fn add_query(&mut self, time: usize, u: usize, v: usize) {
+        assert!(u < self.n && v < self.n);
+        self.queries.push((time, u, v));
+    }
+
+    /// Answers the queries in the time `[0, n_times)` in the order of the time,
+    /// calling `callback(time, are_connected, n_components)` for each query.
+    ///
+    /// The queries at the same time are answered in the order they were added.
+    pub fn run<F: FnMut(usize, bool, usize)>(&self, n_times: usize, mut callback: F) {
+        if n_times == 0 {
+            return;
+        }
+        let size = n_times.next_power_of_two();
+
+        // segment tree on the time, node `k` has the children `2k` and `2k + 1`
+        let mut segment = vec![vec![]; 2 * size];
+        for &(u, v, from, to) in &self.edges {
+            let (mut l, mut r) = (from.min(n_times) + size, to.min(n_times) + size);
+            while l < r {
+                if l & 1 == 1 {
+                    segment[l].push((u, v));
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    segment[r].push((u, v));
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+        }
+
+        let mut queries = vec![vec![]; n_times];
+        for &(time, u, v) in &self.queries {
+            if time < n_times {
+                queries[time].push((u, v));
+            }
+        }
+
+        let mut uf = RollbackUnionFind::new(self.n);
+        // (node, entering)
+        let mut stack = vec![(1, true)];
+        while let Some((k, entering)) = stack.pop() {
+            if !entering {
+                for _ in 0..segment[k].len() {
+                    uf.rollback();
+                }
+                continue;
+            }
+            if k >= size + n_times {
+                continue;
+            }
+            for &(u, v) in &segment[k] {
+                uf.union(u, v);
+            }
+            stack.push((k, false));
+            if k >= size {
+                let time = k - size;
+                for &(u, v) in &queries[time] {
+                    callback(time, uf.find(u) == uf.find(v), uf.components);
+                }
+            } else {
+                stack.push((2 * k + 1, true));
+                stack.push((2 * k, true));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::union_find::UnionFind;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(0x1234_5678_u64, |seed| seed as usize);
+        for _ in 0..30 {
+            let n = rand() % 8 + 1;
+            let n_times = rand() % 20 + 1;
+            let mut dc = DynamicConnectivity::new(n);
+            let mut edges = vec![];
+            for _ in 0..rand() % 15 {
+                let (u, v) = (rand() % n, rand() % n);
+                let from = rand() % (n_times + 2);
+                let to = from + rand() % (n_times + 2);
+                dc.add_edge(u, v, from, to);
+                edges.push((u, v, from, to));
+            }
+            let mut queries = vec![];
+            for _ in 0..rand() % 30 {
+                let (t, u, v) = (rand() % (n_times + 1), rand() % n, rand() % n);
+                dc.add_query(t, u, v);
+                queries.push((t, u, v));
+            }
+
+            let mut result = vec![];
+            dc.run(n_times, |t, c, k| result.push((t, c, k)));
+
+            let mut expected = vec![];
+            for time in 0..n_times {
+                let mut uf = UnionFind::new(n);
+                let mut components = n;
+                for &(u, v, from, to) in &edges {
+                    if from <= time && time < to && uf.union(u, v) {
+                        components -= 1;
+                    }
+                }
+                for &(t, u, v) in &queries {
+                    if t == time {
+                        expected.push((t, uf.equiv(u, v), components));
+                    }
+                }
+            }
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn no_time() {
+        let mut dc = DynamicConnectivity::new(2);
+        dc.add_query(0, 0, 1);
+        dc.run(0, |_, _, _| panic!());
+    }
+}
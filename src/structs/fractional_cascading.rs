@@ -0,0 +1,153 @@
+use core::cmp::Ordering::{Greater, Less};
+
+#[derive(Clone, Debug)]
+struct Level<T> {
+    // the array merged with every other element of the next level
+    merged: Vec<T>,
+    // `own[p]` is the number of the elements of the original array in `merged[..p]`
+    own: Vec<usize>,
+    // `promoted[p]` is the number of the elements from the next level in `merged[..p]`
+    promoted: Vec<usize>,
+}
+
+/// Fractional cascading to find the lower bounds of the same value in many sorted arrays.
+///
+/// The `i`-th level is the `i`-th array merged with the elements at the odd indices of the `i + 1`-th level,
+/// so the lower bound in a level determines the one in the next level with a single comparison.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::fractional_cascading::FractionalCascading;
+///
+/// let fc = FractionalCascading::build(vec![
+///     vec![1, 3, 5, 7],
+///     vec![2, 4, 6],
+///     vec![],
+///     vec![5, 5, 5, 9],
+/// ]);
+/// assert_eq!(fc.query(&5), vec![2, 2, 0, 0]);
+/// assert_eq!(fc.query(&6), vec![3, 2, 0, 3]);
+/// assert_eq!(fc.query(&10), vec![4, 3, 0, 4]);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm | Worst case        |
+/// | --------- | ----------------- |
+/// | Memory    | O(*n*)            |
+/// | [`build`] | O(*n*)            |
+/// | [`query`] | O(log(*n*) + *k*) |
+///
+/// *n* is the total length of the arrays and *k* is the number of the arrays.
+///
+/// [`build`]: FractionalCascading::build
+/// [`query`]: FractionalCascading::query
+#[derive(Clone, Debug)]
+pub struct FractionalCascading<T> {
+    levels: Vec<Level<T>>,
+}
+
+impl<T: Ord + Clone> FractionalCascading<T> {
+    /// Builds from the arrays, each of which must be sorted in ascending order.
+    pub fn build(arrays: Vec<Vec<T>>) -> Self {
+        let mut levels = Vec::<Level<T>>::with_capacity(arrays.len());
+        for array in arrays.into_iter().rev() {
+            let next = levels
+                .last()
+                .map(|level| {
+                    level
+                        .merged
+                        .iter()
+                        .skip(1)
+                        .step_by(2)
+                        .cloned()
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            let len = array.len() + next.len();
+            let mut merged = Vec::with_capacity(len);
+            let mut own = Vec::with_capacity(len + 1);
+            let mut promoted = Vec::with_capacity(len + 1);
+            let (mut i, mut j) = (0, 0);
+            own.push(0);
+            promoted.push(0);
+            while i < array.len() || j < next.len() {
+                if j == next.len() || (i < array.len() && array[i] <= next[j]) {
+                    merged.push(array[i].clone());
+                    i += 1;
+                } else {
+                    merged.push(next[j].clone());
+                    j += 1;
+                }
+                own.push(i);
+                promoted.push(j);
+            }
+            levels.push(Level {
+                merged,
+                own,
+                promoted,
+            });
+        }
+        levels.reverse();
+        Self { levels }
+    }
+
+    /// Returns the lower bound of `x` in each array, i.e. the number of the elements less than `x`.
+    pub fn query(&self, x: &T) -> Vec<usize> {
+        let mut res = Vec::with_capacity(self.levels.len());
+        let first = match self.levels.first() {
+            Some(level) => level,
+            None => return res,
+        };
+        let mut p = first
+            .merged
+            .binary_search_by(|y| if y < x { Less } else { Greater })
+            .unwrap_err();
+        for (i, level) in self.levels.iter().enumerate() {
+            res.push(level.own[p]);
+            if let Some(next) = self.levels.get(i + 1) {
+                // `next.merged[2c - 1] < x <= next.merged[2c + 1]`
+                let c = level.promoted[p];
+                p = 2 * c;
+                if p < next.merged.len() && next.merged[p] < *x {
+                    p += 1;
+                }
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for k in 0..10 {
+            for _ in 0..10 {
+                let arrays = (0..k)
+                    .map(|_| {
+                        let len = rand() % 20;
+                        let mut a = (0..len).map(|_| rand() % 30).collect::<Vec<_>>();
+                        a.sort_unstable();
+                        a
+                    })
+                    .collect::<Vec<_>>();
+                let fc = FractionalCascading::build(arrays.clone());
+                for x in 0..=31 {
+                    let expected = arrays
+                        .iter()
+                        .map(|a| a.iter().filter(|&&y| y < x).count())
+                        .collect::<Vec<_>>();
+                    assert_eq!(fc.query(&x), expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use core::mem;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    // the length of the right spine
+    rank: usize,
+    len: usize,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+fn rank<T>(node: &Option<Box<Node<T>>>) -> usize {
+    node.as_ref().map_or(0, |node| node.rank)
+}
+
+fn len<T>(node: &Option<Box<Node<T>>>) -> usize {
+    node.as_ref().map_or(0, |node| node.len)
+}
+
+fn merge<T: Ord>(a: Option<Box<Node<T>>>, b: Option<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+    match (a, b) {
+        (None, node) | (node, None) => node,
+        (Some(a), Some(b)) => {
+            let (mut a, b) = if a.value <= b.value { (a, b) } else { (b, a) };
+            a.right = merge(a.right.take(), Some(b));
+            if rank(&a.left) < rank(&a.right) {
+                mem::swap(&mut a.left, &mut a.right);
+            }
+            a.rank = rank(&a.right) + 1;
+            a.len = len(&a.left) + len(&a.right) + 1;
+            Some(a)
+        }
+    }
+}
+
+/// A leftist heap, which is a min-heap supporting merges.
+///
+/// Use [`Reverse`](core::cmp::Reverse) for a max-heap.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::leftist_heap::LeftistHeap;
+///
+/// let a = LeftistHeap::new().push(3).push(1);
+/// let b = LeftistHeap::new().push(2).push(5);
+/// let heap = a.merge(b);
+/// assert_eq!(heap.peek(), Some(&1));
+///
+/// let (min, heap) = heap.pop().unwrap();
+/// assert_eq!(min, 1);
+/// assert_eq!(heap.into_sorted_vec(), vec![2, 3, 5]);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm | Worst case  |
+/// | --------- | ----------- |
+/// | Memory    | O(*n*)      |
+/// | [`push`]  | O(log(*n*)) |
+/// | [`pop`]   | O(log(*n*)) |
+/// | [`peek`]  | O(1)        |
+/// | [`merge`] | O(log(*n*)) |
+///
+/// [`push`]: LeftistHeap::push
+/// [`pop`]: LeftistHeap::pop
+/// [`peek`]: LeftistHeap::peek
+/// [`merge`]: LeftistHeap::merge
+#[derive(Clone, Debug)]
+pub struct LeftistHeap<T: Ord> {
+    root: Option<Box<Node<T>>>,
+}
+
+impl<T: Ord> LeftistHeap<T> {
+    /// Creates an empty heap.
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    pub fn len(&self) -> usize {
+        len(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the heap with `value` added.
+    pub fn push(self, value: T) -> Self {
+        let node = Box::new(Node {
+            value,
+            rank: 1,
+            len: 1,
+            left: None,
+            right: None,
+        });
+        self.merge(Self { root: Some(node) })
+    }
+
+    /// Removes the minimum element, and returns it with the rest of the heap.
+    ///
+    /// Returns `None` if the heap is empty.
+    pub fn pop(mut self) -> Option<(T, Self)> {
+        self.root.take().map(|node| {
+            let Node {
+                value, left, right, ..
+            } = *node;
+            (
+                value,
+                Self {
+                    root: merge(left, right),
+                },
+            )
+        })
+    }
+
+    /// Returns the minimum element, or `None` if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// Merges two heaps.
+    pub fn merge(mut self, mut other: Self) -> Self {
+        Self {
+            root: merge(self.root.take(), other.root.take()),
+        }
+    }
+
+    /// Returns the elements in ascending order.
+    ///
+    /// This operation is O(*n* log(*n*)).
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut ret = Vec::with_capacity(self.len());
+        let mut heap = self;
+        while let Some((value, rest)) = heap.pop() {
+            ret.push(value);
+            heap = rest;
+        }
+        ret
+    }
+}
+
+impl<T: Ord> Default for LeftistHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Drop for LeftistHeap<T> {
+    // The left spine may be long, so the nodes are dropped without recursion.
+    fn drop(&mut self) {
+        let mut stack = self.root.take().into_iter().collect::<Vec<_>>();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        let mut heaps = vec![(LeftistHeap::new(), BinaryHeap::new()); 5];
+        for _ in 0..1000 {
+            let i = rand() as usize % heaps.len();
+            match rand() % 4 {
+                0 | 1 => {
+                    let value = rand() % 100;
+                    let (heap, mut naive) = heaps.swap_remove(i);
+                    naive.push(Reverse(value));
+                    heaps.push((heap.push(value), naive));
+                }
+                2 => {
+                    let (heap, mut naive) = heaps.swap_remove(i);
+                    let expected = naive.pop().map(|Reverse(x)| x);
+                    match heap.pop() {
+                        Some((value, heap)) => {
+                            assert_eq!(Some(value), expected);
+                            heaps.push((heap, naive));
+                        }
+                        None => {
+                            assert_eq!(expected, None);
+                            heaps.push((LeftistHeap::new(), naive));
+                        }
+                    }
+                }
+                _ => {
+                    let j = rand() as usize % heaps.len();
+                    if i != j {
+                        let (a, mut na) = heaps.swap_remove(i.max(j));
+                        let (b, nb) = heaps.swap_remove(i.min(j));
+                        na.extend(nb);
+                        heaps.push((a.merge(b), na));
+                        heaps.push((LeftistHeap::new(), BinaryHeap::new()));
+                    }
+                }
+            }
+            for (heap, naive) in &heaps {
+                assert_eq!(heap.len(), naive.len());
+                assert_eq!(heap.peek(), naive.peek().map(|Reverse(x)| x));
+            }
+        }
+    }
+
+    #[test]
+    fn long_spine() {
+        let mut heap = LeftistHeap::new();
+        for i in (0..100_000).rev() {
+            heap = heap.push(i);
+        }
+        assert_eq!(heap.len(), 100_000);
+        assert_eq!(heap.peek(), Some(&0));
+    }
+}
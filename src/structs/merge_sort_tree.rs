@@ -0,0 +1,166 @@
+use core::cmp::Ordering;
+use core::ops::Range;
+
+/// Returns the number of elements less than `value` in the sorted slice.
+fn lower_bound<T: Ord>(sorted: &[T], value: &T) -> usize {
+    sorted
+        .binary_search_by(|x| {
+            if x < value {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_err()
+}
+
+/// A segment tree whose nodes store the sorted elements of their segments,
+/// for range order statistics of a static array.
+///
+/// It is simpler than [`WaveletTree`](super::wavelet_tree::WaveletTree) but slower.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::merge_sort_tree::MergeSortTree;
+///
+/// let tree = MergeSortTree::build(&[5, 1, 4, 1, 3, 9, 2]);
+/// // [1, 4, 1, 3] sorted is [1, 1, 3, 4]
+/// assert_eq!(tree.kth_smallest(1..5, 0), 1);
+/// assert_eq!(tree.kth_smallest(1..5, 2), 3);
+/// assert_eq!(tree.count_less_than(1..5, &4), 3);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm           | Worst case        |
+/// | ------------------- | ----------------- |
+/// | Memory              | O(*n* log(*n*))   |
+/// | [`build`]           | O(*n* log(*n*))   |
+/// | [`kth_smallest`]    | O(log(*n*)³)      |
+/// | [`count_less_than`] | O(log(*n*)²)      |
+///
+/// [`build`]: MergeSortTree::build
+/// [`kth_smallest`]: MergeSortTree::kth_smallest
+/// [`count_less_than`]: MergeSortTree::count_less_than
+#[derive(Clone, Debug)]
+pub struct MergeSortTree<T: Ord> {
+    len: usize,
+    // `nodes[len + i]` is the `i`-th element, and `nodes[i]` is the merge of its children
+    nodes: Vec<Vec<T>>,
+    // all the elements in ascending order
+    sorted: Vec<T>,
+}
+
+impl<T: Ord + Clone> MergeSortTree<T> {
+    /// Builds the tree of `arr`.
+    pub fn build(arr: &[T]) -> Self {
+        let len = arr.len();
+        let mut nodes = vec![vec![]; 2 * len];
+        for (node, x) in nodes[len..].iter_mut().zip(arr) {
+            node.push(x.clone());
+        }
+        for i in (1..len).rev() {
+            let (left, right) = (&nodes[2 * i], &nodes[2 * i + 1]);
+            let mut merged = Vec::with_capacity(left.len() + right.len());
+            let (mut a, mut b) = (0, 0);
+            while a < left.len() || b < right.len() {
+                if b == right.len() || (a < left.len() && left[a] <= right[b]) {
+                    merged.push(left[a].clone());
+                    a += 1;
+                } else {
+                    merged.push(right[b].clone());
+                    b += 1;
+                }
+            }
+            nodes[i] = merged;
+        }
+        let mut sorted = arr.to_vec();
+        sorted.sort();
+        Self { len, nodes, sorted }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements less than `value` in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the array.
+    pub fn count_less_than(&self, range: Range<usize>, value: &T) -> usize {
+        assert!(range.start <= range.end && range.end <= self.len);
+        let (mut l, mut r) = (range.start + self.len, range.end + self.len);
+        let mut count = 0;
+        while l < r {
+            if l & 1 == 1 {
+                count += lower_bound(&self.nodes[l], value);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                count += lower_bound(&self.nodes[r], value);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        count
+    }
+
+    /// Returns the `k`-th (0-indexed) smallest element in `range`.
+    ///
+    /// It binary searches the answer among all the elements with [`count_less_than`](Self::count_less_than).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the array or `k >= range.len()`.
+    pub fn kth_smallest(&self, range: Range<usize>, k: usize) -> T {
+        assert!(k < range.len());
+        // the answer is the largest element with at most `k` smaller elements in `range`
+        let (mut ok, mut ng) = (0, self.sorted.len());
+        while ng - ok > 1 {
+            let mid = (ok + ng) / 2;
+            if self.count_less_than(range.clone(), &self.sorted[mid]) <= k {
+                ok = mid;
+            } else {
+                ng = mid;
+            }
+        }
+        self.sorted[ok].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..20 {
+            let arr = (0..n).map(|_| rand() % 10).collect::<Vec<_>>();
+            let tree = MergeSortTree::build(&arr);
+            assert_eq!(tree.len(), n);
+            for l in 0..=n {
+                for r in l..=n {
+                    let mut sorted = arr[l..r].to_vec();
+                    sorted.sort();
+                    for (k, x) in sorted.iter().enumerate() {
+                        assert_eq!(&tree.kth_smallest(l..r, k), x);
+                    }
+                    for v in 0..=10 {
+                        let expected = sorted.iter().filter(|&&x| x < v).count();
+                        assert_eq!(tree.count_less_than(l..r, &v), expected);
+                    }
+                }
+            }
+        }
+    }
+}
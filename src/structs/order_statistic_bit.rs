@@ -0,0 +1,168 @@
+use super::{AddGroup, BinaryIndexedTree};
+
+/// A multiset of integers in a universe given in advance, which answers the ranks and the `k`-th smallest elements.
+///
+/// The universe is coordinate-compressed, and a BIT keeps the number of each element.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::order_statistic_bit::OrderStatisticBIT;
+///
+/// let mut set = OrderStatisticBIT::new(&[100, 5, 42, 7, 5]);
+/// set.insert(42);
+/// set.insert(5);
+/// set.insert(42);
+/// assert_eq!(set.rank(41), 1);
+/// assert_eq!(set.rank(42), 3);
+/// assert_eq!(set.select(1), 42);
+/// assert!(set.remove(42));
+/// assert!(!set.remove(7));
+/// assert_eq!(set.select(1), 42);
+/// assert_eq!(set.len(), 2);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm  | Worst case        |
+/// | ---------- | ----------------- |
+/// | Memory     | O(*m*)            |
+/// | [`new`]    | O(*m* log(*m*))   |
+/// | [`insert`] | O(log(*m*))       |
+/// | [`remove`] | O(log(*m*))       |
+/// | [`rank`]   | O(log(*m*))       |
+/// | [`select`] | O(log(*m*))       |
+///
+/// *m* is the size of the universe.
+///
+/// [`new`]: OrderStatisticBIT::new
+/// [`insert`]: OrderStatisticBIT::insert
+/// [`remove`]: OrderStatisticBIT::remove
+/// [`rank`]: OrderStatisticBIT::rank
+/// [`select`]: OrderStatisticBIT::select
+#[derive(Clone, Debug)]
+pub struct OrderStatisticBIT {
+    // the sorted and deduplicated universe
+    values: Vec<usize>,
+    counts: BinaryIndexedTree<AddGroup<i64>>,
+    len: usize,
+}
+
+impl OrderStatisticBIT {
+    /// Creates an empty multiset whose elements are in `universe`.
+    pub fn new(universe: &[usize]) -> Self {
+        let mut values = universe.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        let counts = BinaryIndexedTree::with_size(values.len());
+        Self {
+            values,
+            counts,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements including duplicates.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn index(&self, x: usize) -> usize {
+        self.values
+            .binary_search(&x)
+            .unwrap_or_else(|_| panic!("{} is not in the universe", x))
+    }
+
+    /// Inserts `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not in the universe.
+    pub fn insert(&mut self, x: usize) {
+        let i = self.index(x);
+        self.counts.operate(i, &1);
+        self.len += 1;
+    }
+
+    /// Removes one `x`, and returns `true` if it was in the multiset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is not in the universe.
+    pub fn remove(&mut self, x: usize) -> bool {
+        let i = self.index(x);
+        if self.counts.fold(..=i) == self.counts.fold(..i) {
+            return false;
+        }
+        self.counts.operate(i, &-1);
+        self.len -= 1;
+        true
+    }
+
+    /// Returns the number of elements `<= x`.
+    ///
+    /// `x` does not have to be in the universe.
+    pub fn rank(&self, x: usize) -> usize {
+        let i = match self.values.binary_search(&x) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        };
+        self.counts.fold(..i) as usize
+    }
+
+    /// Returns the `k`-th smallest element (0-indexed).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= self.len()`.
+    pub fn select(&self, k: usize) -> usize {
+        assert!(k < self.len);
+        // the number of the distinct values whose prefix has at most `k` elements
+        let i = self.counts.max_right(|&s| s <= k as i64);
+        self.values[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for m in 1..20 {
+            let universe = (0..m).map(|_| rand() % 50).collect::<Vec<_>>();
+            let mut set = OrderStatisticBIT::new(&universe);
+            let mut naive = vec![];
+            for _ in 0..100 {
+                let x = universe[rand() % m];
+                if rand() % 3 == 0 {
+                    let removed = naive.iter().position(|&y| y == x).map(|i| naive.remove(i));
+                    assert_eq!(set.remove(x), removed.is_some());
+                } else {
+                    set.insert(x);
+                    naive.push(x);
+                }
+                naive.sort_unstable();
+                assert_eq!(set.len(), naive.len());
+                for (k, &y) in naive.iter().enumerate() {
+                    assert_eq!(set.select(k), y);
+                }
+                for x in 0..=50 {
+                    assert_eq!(set.rank(x), naive.iter().filter(|&&y| y <= x).count());
+                }
+            }
+
+            // a clone is independent of the original
+            let mut cloned = set.clone();
+            cloned.insert(universe[0]);
+            assert_eq!(cloned.len(), set.len() + 1);
+        }
+    }
+}
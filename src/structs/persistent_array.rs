@@ -0,0 +1,200 @@
+#[derive(Clone, Debug)]
+enum Node<T> {
+    Leaf(T),
+    Inner(usize, usize),
+}
+
+/// A persistent array with path copying on a binary tree.
+///
+/// Every [`set`] creates a new version and keeps all the older versions accessible.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::persistent_array::PersistentArray;
+///
+/// let (mut arr, v0) = PersistentArray::new(&[1, 2, 3]);
+/// let v1 = arr.set(v0, 1, 20);
+/// let v2 = arr.set(v1, 2, 30);
+/// let v3 = arr.set(v0, 0, 10);
+/// assert_eq!(arr.get(v0, 1), &2);
+/// assert_eq!(arr.get(v1, 1), &20);
+/// assert_eq!(arr.get(v2, 2), &30);
+/// assert_eq!(arr.get(v3, 0), &10);
+/// assert_eq!(arr.get(v3, 1), &2);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm | Worst case          |
+/// | --------- | ------------------- |
+/// | Memory    | O(*n* + *q* log(*n*)) |
+/// | [`new`]   | O(*n*)              |
+/// | [`get`]   | O(log(*n*))         |
+/// | [`set`]   | O(log(*n*))         |
+///
+/// *q* is the number of [`set`] calls.
+///
+/// [`new`]: PersistentArray::new
+/// [`get`]: PersistentArray::get
+/// [`set`]: PersistentArray::set
+#[derive(Clone, Debug)]
+pub struct PersistentArray<T: Clone> {
+    len: usize,
+    nodes: Vec<Node<T>>,
+    roots: Vec<usize>,
+}
+
+impl<T: Clone> PersistentArray<T> {
+    /// Creates an array of `values` and returns it with the ID of the initial version.
+    pub fn new(values: &[T]) -> (Self, usize) {
+        fn build<T: Clone>(nodes: &mut Vec<Node<T>>, values: &[T]) -> usize {
+            let node = if values.len() == 1 {
+                Node::Leaf(values[0].clone())
+            } else {
+                let mid = values.len() / 2;
+                let l = build(nodes, &values[..mid]);
+                let r = build(nodes, &values[mid..]);
+                Node::Inner(l, r)
+            };
+            nodes.push(node);
+            nodes.len() - 1
+        }
+
+        let mut nodes = Vec::with_capacity(values.len() * 2);
+        let root = if values.is_empty() {
+            // never accessed since every index is out of bounds
+            !0
+        } else {
+            build(&mut nodes, values)
+        };
+        let arr = Self {
+            len: values.len(),
+            nodes,
+            roots: vec![root],
+        };
+        (arr, 0)
+    }
+
+    /// Returns the length of the array, which is common to all versions.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of versions.
+    pub fn versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns the `idx`-th element of `version`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` does not exist or `idx >= len`.
+    pub fn get(&self, version: usize, idx: usize) -> &T {
+        assert!(idx < self.len);
+        let mut node = self.roots[version];
+        let (mut lo, mut hi) = (0, self.len);
+        loop {
+            match &self.nodes[node] {
+                Node::Leaf(value) => return value,
+                &Node::Inner(l, r) => {
+                    let mid = lo + (hi - lo) / 2;
+                    if idx < mid {
+                        node = l;
+                        hi = mid;
+                    } else {
+                        node = r;
+                        lo = mid;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Creates a new version from `version` with the `idx`-th element replaced by `value`,
+    /// and returns the ID of the new version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` does not exist or `idx >= len`.
+    pub fn set(&mut self, version: usize, idx: usize, value: T) -> usize {
+        assert!(idx < self.len);
+        // collect the path from the root
+        let mut path = vec![];
+        let mut node = self.roots[version];
+        let (mut lo, mut hi) = (0, self.len);
+        while let Node::Inner(l, r) = self.nodes[node] {
+            let mid = lo + (hi - lo) / 2;
+            let go_left = idx < mid;
+            path.push((l, r, go_left));
+            if go_left {
+                node = l;
+                hi = mid;
+            } else {
+                node = r;
+                lo = mid;
+            }
+        }
+
+        // copy the path from the leaf
+        self.nodes.push(Node::Leaf(value));
+        let mut new = self.nodes.len() - 1;
+        for (l, r, go_left) in path.into_iter().rev() {
+            let node = if go_left {
+                Node::Inner(new, r)
+            } else {
+                Node::Inner(l, new)
+            };
+            self.nodes.push(node);
+            new = self.nodes.len() - 1;
+        }
+        self.roots.push(new);
+        self.roots.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 1..20 {
+            let init = (0..n).map(|_| rand() % 100).collect::<Vec<_>>();
+            let (mut arr, v0) = PersistentArray::new(&init);
+            let mut naive = vec![init];
+            assert_eq!(v0, 0);
+            for _ in 0..50 {
+                let version = rand() % naive.len();
+                let idx = rand() % n;
+                let value = rand() % 100;
+                let mut next = naive[version].clone();
+                next[idx] = value;
+                naive.push(next);
+                assert_eq!(arr.set(version, idx, value), naive.len() - 1);
+            }
+            assert_eq!(arr.versions(), naive.len());
+            for (version, values) in naive.iter().enumerate() {
+                for (idx, value) in values.iter().enumerate() {
+                    assert_eq!(arr.get(version, idx), value);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let (arr, v0) = PersistentArray::<i32>::new(&[]);
+        assert!(arr.is_empty());
+        assert_eq!(arr.versions(), 1);
+        assert_eq!(v0, 0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::math::num::Monoid;
+use crate::math::num::{Monoid, Zero};
 
 #[cfg_attr(nightly, codesnip::entry("LazySegTree", include("Monoid")))]
 mod lazy;
@@ -8,6 +8,21 @@ pub use lazy::{LSTMonoid, LazySegTree};
 #[cfg_attr(nightly, codesnip::entry("SegmentTree", include("Monoid")))]
 mod segtree;
 #[codesnip::entry("SegmentTree")]
-pub use segtree::SegmentTree;
+pub use segtree::{SegmentTree, SegmentTreeIter};
+
+#[cfg_attr(nightly, codesnip::entry("RangeAssignSegTree", include("Zero")))]
+mod range_assign;
+#[codesnip::entry("RangeAssignSegTree")]
+pub use range_assign::RangeAssignSegTree;
+
+#[cfg_attr(nightly, codesnip::entry("RangeAddMaxSegTree", include("Zero")))]
+mod range_add;
+#[codesnip::entry("RangeAddMaxSegTree")]
+pub use range_add::RangeAddMaxSegTree;
+
+#[cfg_attr(nightly, codesnip::entry("DynamicSegTree", include("Monoid")))]
+mod dynamic;
+#[codesnip::entry("DynamicSegTree")]
+pub use dynamic::DynamicSegTree;
 
 pub mod types;
@@ -0,0 +1,186 @@
+use super::Monoid;
+use core::ops::{
+    Bound::{Excluded, Included, Unbounded},
+    RangeBounds,
+};
+
+struct Node<T> {
+    value: T,
+    children: [Option<usize>; 2],
+}
+
+/// A segment tree over a huge index range, where the nodes are created only when they are updated.
+///
+/// The nodes are allocated in a `Vec`, and the absent nodes are treated as `Monoid::id()`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::structs::segment_tree::DynamicSegTree;
+///
+/// let mut seg = DynamicSegTree::<AddMonoid<i64>>::new(1_000_000_000_000_000_000);
+/// seg.update(3, 5);
+/// seg.update(999_999_999_999_999_999, 7);
+/// seg.update(3, 2);
+/// assert_eq!(seg.fold(..), 9);
+/// assert_eq!(seg.fold(4..), 7);
+/// assert_eq!(seg.get(3), 2);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm  | Worst case      |
+/// | ---------- | --------------- |
+/// | Memory     | O(*q* log(*U*)) |
+/// | [`update`] | O(log(*U*))     |
+/// | [`fold`]   | O(log(*U*))     |
+///
+/// *U* is the size of the index range and *q* is the number of the updates.
+///
+/// [`update`]: DynamicSegTree::update
+/// [`fold`]: DynamicSegTree::fold
+pub struct DynamicSegTree<M: Monoid> {
+    size: u64,
+    nodes: Vec<Node<M::Set>>,
+}
+
+impl<M: Monoid> DynamicSegTree<M> {
+    /// Creates a tree for the indices in `[0, size)`, where all the values are `Monoid::id()`.
+    pub fn new(size: u64) -> Self {
+        Self {
+            size,
+            nodes: vec![Node {
+                value: M::id(),
+                children: [None, None],
+            }],
+        }
+    }
+
+    fn value(&self, k: Option<usize>) -> M::Set {
+        k.map_or_else(M::id, |k| self.nodes[k].value.clone())
+    }
+
+    /// Replaces the value at `index` with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the range.
+    pub fn update(&mut self, index: u64, value: M::Set) {
+        assert!(index < self.size);
+        let (mut l, mut r) = (0, self.size);
+        let mut path = vec![0];
+        while r - l > 1 {
+            let k = *path.last().unwrap();
+            let m = l + (r - l) / 2;
+            let side = if index < m {
+                r = m;
+                0
+            } else {
+                l = m;
+                1
+            };
+            let child = match self.nodes[k].children[side] {
+                Some(child) => child,
+                None => {
+                    self.nodes.push(Node {
+                        value: M::id(),
+                        children: [None, None],
+                    });
+                    self.nodes[k].children[side] = Some(self.nodes.len() - 1);
+                    self.nodes.len() - 1
+                }
+            };
+            path.push(child);
+        }
+
+        self.nodes[path.pop().unwrap()].value = value;
+        for k in path.into_iter().rev() {
+            let [left, right] = self.nodes[k].children;
+            self.nodes[k].value = M::operate(&self.value(left), &self.value(right));
+        }
+    }
+
+    /// Returns the value at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the range.
+    pub fn get(&self, index: u64) -> M::Set {
+        self.fold(index..=index)
+    }
+
+    /// Returns the fold of the values in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the range.
+    pub fn fold<R: RangeBounds<u64>>(&self, range: R) -> M::Set {
+        let l = match range.start_bound() {
+            Included(&l) => l,
+            Excluded(&l) => l + 1,
+            Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            Included(&r) => r + 1,
+            Excluded(&r) => r,
+            Unbounded => self.size,
+        };
+        assert!(l <= r && r <= self.size);
+        self.fold_rec(Some(0), 0, self.size, l, r)
+    }
+
+    fn fold_rec(&self, k: Option<usize>, nl: u64, nr: u64, l: u64, r: u64) -> M::Set {
+        let k = match k {
+            Some(k) if l < nr && nl < r => k,
+            _ => return M::id(),
+        };
+        if l <= nl && nr <= r {
+            return self.nodes[k].value.clone();
+        }
+        let m = nl + (nr - nl) / 2;
+        let [left, right] = self.nodes[k].children;
+        M::operate(
+            &self.fold_rec(left, nl, m, l, r),
+            &self.fold_rec(right, m, nr, l, r),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::MaxMonoid;
+    use crate::testing::xorshift;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for &size in &[1, 2, 7, 64, 1_000_000_000_000_000_000, (1 << 63) - 1] {
+            let mut seg = DynamicSegTree::<MaxMonoid<u64>>::new(size);
+            let mut naive = BTreeMap::new();
+            // a few distinct indices to make the ranges overlap
+            let indices = (0..10).map(|_| rand() % size).collect::<Vec<_>>();
+            for _ in 0..200 {
+                let i = indices[rand() as usize % indices.len()];
+                let v = rand() % 100;
+                seg.update(i, v);
+                naive.insert(i, v);
+
+                let a = indices[rand() as usize % indices.len()];
+                let b = indices[rand() as usize % indices.len()];
+                let (l, r) = (a.min(b), a.max(b));
+                let expected = naive.range(l..=r).map(|(_, &v)| v).max().unwrap_or(0);
+                assert_eq!(seg.fold(l..=r), expected);
+                assert_eq!(
+                    seg.fold(l..r),
+                    naive.range(l..r).map(|(_, &v)| v).max().unwrap_or(0)
+                );
+                assert_eq!(seg.get(a), naive.get(&a).copied().unwrap_or(0));
+            }
+            assert_eq!(seg.fold(..), naive.values().copied().max().unwrap_or(0));
+        }
+    }
+}
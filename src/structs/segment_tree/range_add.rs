@@ -0,0 +1,281 @@
+use super::Zero;
+use core::ops::{
+    Add,
+    Bound::{Excluded, Included, Unbounded},
+    RangeBounds,
+};
+
+#[derive(Clone, Copy, Debug)]
+struct Summary<T> {
+    sum: T,
+    min: T,
+    max: T,
+    // the number of the elements, which excludes the padding leaves
+    len: usize,
+}
+
+/// Returns `v + v + … + v` (`k` times) with doubling.
+fn times<T: Copy + Add<Output = T> + Zero>(v: T, mut k: usize) -> T {
+    let (mut acc, mut base) = (T::zero(), v);
+    while k > 0 {
+        if k & 1 == 1 {
+            acc = acc + base;
+        }
+        k >>= 1;
+        if k > 0 {
+            base = base + base;
+        }
+    }
+    acc
+}
+
+/// A lazy segment tree with range addition, which answers the sum, the minimum and the maximum of a range.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::segment_tree::RangeAddMaxSegTree;
+///
+/// let mut seg = RangeAddMaxSegTree::from(vec![3, 1, 4, 1, 5, 9, 2]);
+/// assert_eq!(seg.range_max(..), 9);
+/// seg.range_add(2..5, 7);
+/// // [3, 1, 11, 8, 12, 9, 2]
+/// assert_eq!(seg.range_sum(1..4), 20);
+/// assert_eq!(seg.range_min(2..), 2);
+/// assert_eq!(seg.range_max(..4), 11);
+/// assert_eq!(seg.get(4), 12);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm       | Worst case     |
+/// | --------------- | -------------- |
+/// | Memory          | O(*n*)         |
+/// | [`range_add`]   | O(log(*n*)²)   |
+/// | [`range_sum`]   | O(log(*n*)²)   |
+/// | [`range_min`]   | O(log(*n*))    |
+/// | [`range_max`]   | O(log(*n*))    |
+///
+/// The sum of `k` added values is computed with O(log(*k*)) additions,
+/// since `T` is not required to be multiplied by an integer.
+///
+/// [`range_add`]: RangeAddMaxSegTree::range_add
+/// [`range_sum`]: RangeAddMaxSegTree::range_sum
+/// [`range_min`]: RangeAddMaxSegTree::range_min
+/// [`range_max`]: RangeAddMaxSegTree::range_max
+#[derive(Clone, Debug)]
+pub struct RangeAddMaxSegTree<T> {
+    n: usize,
+    // the number of leaves, a power of two
+    size: usize,
+    // 1-indexed, and `None` for a segment of padding leaves only
+    nodes: Vec<Option<Summary<T>>>,
+    // the value added to the whole segment, which is not pushed to the children yet
+    lazy: Vec<T>,
+}
+
+impl<T: Ord + Copy + Add<Output = T> + Zero> RangeAddMaxSegTree<T> {
+    /// Creates a tree of `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self::from(vec![T::zero(); n])
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn merge(lhs: Option<Summary<T>>, rhs: Option<Summary<T>>) -> Option<Summary<T>> {
+        match (lhs, rhs) {
+            (Some(a), Some(b)) => Some(Summary {
+                sum: a.sum + b.sum,
+                min: a.min.min(b.min),
+                max: a.max.max(b.max),
+                len: a.len + b.len,
+            }),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn added(s: Summary<T>, delta: T) -> Summary<T> {
+        Summary {
+            sum: s.sum + times(delta, s.len),
+            min: s.min + delta,
+            max: s.max + delta,
+            len: s.len,
+        }
+    }
+
+    fn add_node(&mut self, i: usize, delta: T) {
+        self.nodes[i] = self.nodes[i].map(|s| Self::added(s, delta));
+        if i < self.size {
+            self.lazy[i] = self.lazy[i] + delta;
+        }
+    }
+
+    fn push(&mut self, i: usize) {
+        let delta = core::mem::replace(&mut self.lazy[i], T::zero());
+        if delta != T::zero() {
+            self.add_node(2 * i, delta);
+            self.add_node(2 * i + 1, delta);
+        }
+    }
+
+    fn to_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let l = match range.start_bound() {
+            Included(&l) => l,
+            Excluded(&l) => l + 1,
+            Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            Included(&r) => r + 1,
+            Excluded(&r) => r,
+            Unbounded => self.n,
+        };
+        assert!(l <= r);
+        assert!(r <= self.n);
+        (l, r)
+    }
+
+    /// Adds `delta` to all the elements in `range`.
+    pub fn range_add<R: RangeBounds<usize>>(&mut self, range: R, delta: T) {
+        let (l, r) = self.to_range(range);
+        if l < r {
+            self.add_rec(1, 0, self.size, l, r, delta);
+        }
+    }
+
+    fn add_rec(&mut self, i: usize, lo: usize, hi: usize, l: usize, r: usize, delta: T) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            self.add_node(i, delta);
+            return;
+        }
+        self.push(i);
+        let mid = (lo + hi) / 2;
+        self.add_rec(2 * i, lo, mid, l, r, delta);
+        self.add_rec(2 * i + 1, mid, hi, l, r, delta);
+        self.nodes[i] = Self::merge(self.nodes[2 * i], self.nodes[2 * i + 1]);
+    }
+
+    fn query_rec(&self, i: usize, lo: usize, hi: usize, l: usize, r: usize) -> Option<Summary<T>> {
+        if r <= lo || hi <= l {
+            return None;
+        }
+        if l <= lo && hi <= r {
+            return self.nodes[i];
+        }
+        let mid = (lo + hi) / 2;
+        Self::merge(
+            self.query_rec(2 * i, lo, mid, l, r),
+            self.query_rec(2 * i + 1, mid, hi, l, r),
+        )
+        .map(|s| Self::added(s, self.lazy[i]))
+    }
+
+    fn query<R: RangeBounds<usize>>(&self, range: R) -> Option<Summary<T>> {
+        let (l, r) = self.to_range(range);
+        self.query_rec(1, 0, self.size, l, r)
+    }
+
+    /// Returns the `index`-th element.
+    pub fn get(&self, index: usize) -> T {
+        self.query(index..=index).unwrap().sum
+    }
+
+    /// Returns the sum of the elements in `range`, or zero for an empty range.
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        match self.query(range) {
+            Some(s) => s.sum,
+            None => T::zero(),
+        }
+    }
+
+    /// Returns the minimum of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn range_min<R: RangeBounds<usize>>(&self, range: R) -> T {
+        self.query(range).expect("the range must not be empty").min
+    }
+
+    /// Returns the maximum of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn range_max<R: RangeBounds<usize>>(&self, range: R) -> T {
+        self.query(range).expect("the range must not be empty").max
+    }
+}
+
+impl<T: Ord + Copy + Add<Output = T> + Zero> From<Vec<T>> for RangeAddMaxSegTree<T> {
+    fn from(v: Vec<T>) -> Self {
+        let n = v.len();
+        let size = n.next_power_of_two();
+        let mut nodes = vec![None; 2 * size];
+        for (node, x) in nodes[size..].iter_mut().zip(v) {
+            *node = Some(Summary {
+                sum: x,
+                min: x,
+                max: x,
+                len: 1,
+            });
+        }
+        for i in (1..size).rev() {
+            nodes[i] = Self::merge(nodes[2 * i], nodes[2 * i + 1]);
+        }
+        Self {
+            n,
+            size,
+            nodes,
+            lazy: vec![T::zero(); size],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..20 {
+            let mut naive = (0..n)
+                .map(|_| (rand() % 20) as i64 - 10)
+                .collect::<Vec<_>>();
+            let mut seg = RangeAddMaxSegTree::from(naive.clone());
+            for _ in 0..50 {
+                let l = rand() % (n + 1);
+                let r = l + rand() % (n + 1 - l);
+                let v = (rand() % 20) as i64 - 10;
+                for x in &mut naive[l..r] {
+                    *x += v;
+                }
+                seg.range_add(l..r, v);
+                for (i, &x) in naive.iter().enumerate() {
+                    assert_eq!(seg.get(i), x);
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(seg.range_sum(l..r), naive[l..r].iter().sum::<i64>());
+                        if l < r {
+                            assert_eq!(&seg.range_min(l..r), naive[l..r].iter().min().unwrap());
+                            assert_eq!(&seg.range_max(l..r), naive[l..r].iter().max().unwrap());
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(RangeAddMaxSegTree::<i64>::new(3).range_sum(..), 0);
+    }
+}
@@ -0,0 +1,271 @@
+use super::Zero;
+use core::ops::{
+    Add,
+    Bound::{Excluded, Included, Unbounded},
+    RangeBounds,
+};
+
+#[derive(Clone, Debug)]
+struct Summary<T> {
+    sum: T,
+    min: T,
+    max: T,
+}
+
+/// Returns `v + v + … + v` (`k` times) with doubling.
+fn times<T: Clone + Add<Output = T> + Zero>(v: &T, mut k: usize) -> T {
+    let (mut acc, mut base) = (T::zero(), v.clone());
+    while k > 0 {
+        if k & 1 == 1 {
+            acc = acc + base.clone();
+        }
+        k >>= 1;
+        if k > 0 {
+            base = base.clone() + base;
+        }
+    }
+    acc
+}
+
+/// A lazy segment tree with range assignment, which answers the sum, the minimum and the maximum of a range.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::segment_tree::RangeAssignSegTree;
+///
+/// let mut seg = RangeAssignSegTree::from(vec![3, 1, 4, 1, 5, 9, 2]);
+/// assert_eq!(seg.query_sum(..), 25);
+/// seg.apply_range(2..5, 7);
+/// // [3, 1, 7, 7, 7, 9, 2]
+/// assert_eq!(seg.query_sum(1..4), 15);
+/// assert_eq!(seg.query_min(2..), 2);
+/// assert_eq!(seg.query_max(..5), 7);
+/// assert_eq!(seg.get(3), 7);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm       | Worst case     |
+/// | --------------- | -------------- |
+/// | Memory          | O(*n*)         |
+/// | [`apply_range`] | O(log(*n*)²)   |
+/// | [`query_sum`]   | O(log(*n*))    |
+/// | [`query_min`]   | O(log(*n*))    |
+/// | [`query_max`]   | O(log(*n*))    |
+///
+/// The sum of `k` assigned values is computed with O(log(*k*)) additions,
+/// since `T` is not required to be multiplied by an integer.
+///
+/// [`apply_range`]: RangeAssignSegTree::apply_range
+/// [`query_sum`]: RangeAssignSegTree::query_sum
+/// [`query_min`]: RangeAssignSegTree::query_min
+/// [`query_max`]: RangeAssignSegTree::query_max
+#[derive(Clone, Debug)]
+pub struct RangeAssignSegTree<T> {
+    n: usize,
+    // the number of leaves, a power of two
+    size: usize,
+    // 1-indexed, and `None` for a segment of padding leaves only
+    nodes: Vec<Option<Summary<T>>>,
+    // the value assigned to the whole segment, which is not pushed to the children yet
+    lazy: Vec<Option<T>>,
+}
+
+impl<T: Clone + Ord + Add<Output = T> + Zero> RangeAssignSegTree<T> {
+    /// Creates a tree of `n` zeros.
+    pub fn new(n: usize) -> Self {
+        Self::from(vec![T::zero(); n])
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn merge(lhs: &Option<Summary<T>>, rhs: &Option<Summary<T>>) -> Option<Summary<T>> {
+        match (lhs, rhs) {
+            (Some(a), Some(b)) => Some(Summary {
+                sum: a.sum.clone() + b.sum.clone(),
+                min: Ord::min(&a.min, &b.min).clone(),
+                max: Ord::max(&a.max, &b.max).clone(),
+            }),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        }
+    }
+
+    fn assigned(v: &T, len: usize) -> Summary<T> {
+        Summary {
+            sum: times(v, len),
+            min: v.clone(),
+            max: v.clone(),
+        }
+    }
+
+    fn assign_node(&mut self, i: usize, len: usize, v: &T) {
+        self.nodes[i] = Some(Self::assigned(v, len));
+        if i < self.size {
+            self.lazy[i] = Some(v.clone());
+        }
+    }
+
+    fn push(&mut self, i: usize, len: usize) {
+        if let Some(v) = self.lazy[i].take() {
+            self.assign_node(2 * i, len / 2, &v);
+            self.assign_node(2 * i + 1, len / 2, &v);
+        }
+    }
+
+    fn to_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let l = match range.start_bound() {
+            Included(&l) => l,
+            Excluded(&l) => l + 1,
+            Unbounded => 0,
+        };
+        let r = match range.end_bound() {
+            Included(&r) => r + 1,
+            Excluded(&r) => r,
+            Unbounded => self.n,
+        };
+        assert!(l <= r);
+        assert!(r <= self.n);
+        (l, r)
+    }
+
+    /// Assigns `value` to all the elements in `range`.
+    pub fn apply_range<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let (l, r) = self.to_range(range);
+        if l < r {
+            self.apply_rec(1, 0, self.size, l, r, &value);
+        }
+    }
+
+    fn apply_rec(&mut self, i: usize, lo: usize, hi: usize, l: usize, r: usize, v: &T) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            // a fully covered segment has no padding leaves
+            self.assign_node(i, hi - lo, v);
+            return;
+        }
+        self.push(i, hi - lo);
+        let mid = (lo + hi) / 2;
+        self.apply_rec(2 * i, lo, mid, l, r, v);
+        self.apply_rec(2 * i + 1, mid, hi, l, r, v);
+        self.nodes[i] = Self::merge(&self.nodes[2 * i], &self.nodes[2 * i + 1]);
+    }
+
+    fn query_rec(&self, i: usize, lo: usize, hi: usize, l: usize, r: usize) -> Option<Summary<T>> {
+        if r <= lo || hi <= l {
+            return None;
+        }
+        if l <= lo && hi <= r {
+            return self.nodes[i].clone();
+        }
+        if let Some(v) = &self.lazy[i] {
+            return Some(Self::assigned(v, r.min(hi) - l.max(lo)));
+        }
+        let mid = (lo + hi) / 2;
+        Self::merge(
+            &self.query_rec(2 * i, lo, mid, l, r),
+            &self.query_rec(2 * i + 1, mid, hi, l, r),
+        )
+    }
+
+    fn query<R: RangeBounds<usize>>(&self, range: R) -> Option<Summary<T>> {
+        let (l, r) = self.to_range(range);
+        self.query_rec(1, 0, self.size, l, r)
+    }
+
+    /// Returns the `index`-th element.
+    pub fn get(&self, index: usize) -> T {
+        self.query(index..=index).unwrap().sum
+    }
+
+    /// Returns the sum of the elements in `range`, or zero for an empty range.
+    pub fn query_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        self.query(range).map_or_else(T::zero, |s| s.sum)
+    }
+
+    /// Returns the minimum of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn query_min<R: RangeBounds<usize>>(&self, range: R) -> T {
+        self.query(range).expect("the range must not be empty").min
+    }
+
+    /// Returns the maximum of the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn query_max<R: RangeBounds<usize>>(&self, range: R) -> T {
+        self.query(range).expect("the range must not be empty").max
+    }
+}
+
+impl<T: Clone + Ord + Add<Output = T> + Zero> From<Vec<T>> for RangeAssignSegTree<T> {
+    fn from(v: Vec<T>) -> Self {
+        let n = v.len();
+        let size = n.next_power_of_two();
+        let mut nodes = vec![None; 2 * size];
+        for (node, x) in nodes[size..].iter_mut().zip(v) {
+            *node = Some(Self::assigned(&x, 1));
+        }
+        for i in (1..size).rev() {
+            nodes[i] = Self::merge(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Self {
+            n,
+            size,
+            nodes,
+            lazy: vec![None; size],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0..20 {
+            let mut naive = (0..n)
+                .map(|_| (rand() % 20) as i64 - 10)
+                .collect::<Vec<_>>();
+            let mut seg = RangeAssignSegTree::from(naive.clone());
+            for _ in 0..50 {
+                let l = rand() % (n + 1);
+                let r = l + rand() % (n + 1 - l);
+                let v = (rand() % 20) as i64 - 10;
+                for x in &mut naive[l..r] {
+                    *x = v;
+                }
+                seg.apply_range(l..r, v);
+                for (i, &x) in naive.iter().enumerate() {
+                    assert_eq!(seg.get(i), x);
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(seg.query_sum(l..r), naive[l..r].iter().sum::<i64>());
+                        if l < r {
+                            assert_eq!(&seg.query_min(l..r), naive[l..r].iter().min().unwrap());
+                            assert_eq!(&seg.query_max(l..r), naive[l..r].iter().max().unwrap());
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(RangeAssignSegTree::<i64>::new(3).query_sum(..), 0);
+    }
+}
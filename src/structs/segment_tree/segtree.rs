@@ -1,10 +1,11 @@
 use super::Monoid;
 use core::{
+    iter::FromIterator,
     ops::{
         Bound::{Excluded, Included, Unbounded},
         Index, RangeBounds,
     },
-    slice::SliceIndex,
+    slice::{self, SliceIndex},
 };
 
 pub struct SegmentTree<M: Monoid> {
@@ -19,6 +20,9 @@ impl<M: Monoid> SegmentTree<M> {
 
     fn init(n: usize, s: &[M::Set]) -> Self {
         // let n = n.next_power_of_two();
+        if n == 0 {
+            return Self { n, tree: vec![] };
+        }
         let mut tree = vec![M::id(); n * 2 - 1];
         tree[n - 1..n - 1 + s.len()].clone_from_slice(s);
         let mut res = Self { n, tree };
@@ -28,6 +32,11 @@ impl<M: Monoid> SegmentTree<M> {
         res
     }
 
+    /// Returns the leaves, which are the last `n` nodes.
+    fn leaves(&self) -> &[M::Set] {
+        &self.tree[self.tree.len() - self.n..]
+    }
+
     fn update(&mut self, i: usize) {
         self.tree[i] = M::operate(&self.tree[i * 2 + 1], &self.tree[i * 2 + 2]);
     }
@@ -46,7 +55,7 @@ impl<M: Monoid> SegmentTree<M> {
     where
         I: SliceIndex<[M::Set]>,
     {
-        self.tree[self.n - 1..].get(index)
+        self.leaves().get(index)
     }
 
     pub fn query<R>(&self, range: R) -> M::Set
@@ -66,8 +75,9 @@ impl<M: Monoid> SegmentTree<M> {
         assert!(l <= r);
         assert!(r <= self.n);
 
-        let mut l = l + self.n - 1;
-        let mut r = r + self.n - 1;
+        let offset = self.tree.len() - self.n;
+        let mut l = l + offset;
+        let mut r = r + offset;
 
         let is_odd = |x: usize| x & 1 == 0;
         let div2 = |x: &mut usize| *x >>= 1;
@@ -87,6 +97,80 @@ impl<M: Monoid> SegmentTree<M> {
         }
         M::operate(&vl, &vr)
     }
+
+    /// Returns an iterator over the leaf values in index order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib_rust::math::num::alge_struct::types::monoid::AddMonoid;
+    /// use lib_rust::structs::segment_tree::SegmentTree;
+    ///
+    /// let segtree: SegmentTree<AddMonoid<i32>> = vec![3, 1, 4].into_iter().collect();
+    /// assert_eq!(segtree.iter().copied().collect::<Vec<_>>(), vec![3, 1, 4]);
+    /// assert_eq!(segtree.iter().rev().next(), Some(&4));
+    /// assert_eq!(segtree.iter().len(), 3);
+    /// ```
+    pub fn iter(&self) -> SegmentTreeIter<'_, M> {
+        SegmentTreeIter {
+            iter: self.leaves().iter(),
+        }
+    }
+}
+
+/// An iterator over the leaf values of a [`SegmentTree`].
+///
+/// This struct is created by [`SegmentTree::iter`].
+pub struct SegmentTreeIter<'a, M: Monoid> {
+    iter: slice::Iter<'a, M::Set>,
+}
+
+impl<'a, M: Monoid> Iterator for SegmentTreeIter<'a, M> {
+    type Item = &'a M::Set;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, M: Monoid> DoubleEndedIterator for SegmentTreeIter<'a, M> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a, M: Monoid> ExactSizeIterator for SegmentTreeIter<'a, M> {}
+
+impl<'a, M: Monoid> IntoIterator for &'a SegmentTree<M> {
+    type Item = &'a M::Set;
+    type IntoIter = SegmentTreeIter<'a, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<M: Monoid> IntoIterator for SegmentTree<M> {
+    type Item = M::Set;
+    type IntoIter = std::vec::IntoIter<M::Set>;
+
+    /// Consumes the tree and yields the leaf values in index order.
+    fn into_iter(self) -> Self::IntoIter {
+        let mut tree = self.tree;
+        tree.drain(..tree.len() - self.n);
+        tree.into_iter()
+    }
+}
+
+impl<M: Monoid> FromIterator<M::Set> for SegmentTree<M> {
+    /// Builds the tree in O(*n*).
+    fn from_iter<I: IntoIterator<Item = M::Set>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
 }
 
 impl<M: Monoid> From<Vec<M::Set>> for SegmentTree<M> {
@@ -103,7 +187,7 @@ where
     type Output = <I as SliceIndex<[M::Set]>>::Output;
 
     fn index(&self, index: I) -> &Self::Output {
-        Index::index(&self.tree[self.n - 1..], index)
+        Index::index(self.leaves(), index)
     }
 }
 
@@ -149,6 +233,29 @@ mod tests {
         and, BitAndMonoid
     }
 
+    #[test]
+    fn iter() {
+        let v = vec![2, 7, 1, 8, 2, 8];
+        let segtree: SegmentTree<AddMonoid<i32>> = v.iter().copied().collect();
+        assert_eq!(segtree.query(..), 28);
+
+        let mut it = segtree.iter();
+        assert_eq!(it.len(), 6);
+        assert_eq!(it.next(), Some(&2));
+        assert_eq!(it.next_back(), Some(&8));
+        assert_eq!(it.len(), 4);
+        assert_eq!(it.copied().collect::<Vec<_>>(), vec![7, 1, 8, 2]);
+
+        assert_eq!((&segtree).into_iter().rev().count(), 6);
+        assert_eq!(segtree.into_iter().collect::<Vec<_>>(), v);
+
+        let empty: SegmentTree<AddMonoid<i32>> = core::iter::empty().collect();
+        assert_eq!(empty.query(..), 0);
+        assert_eq!(empty.iter().len(), 0);
+        assert_eq!(empty.get(0), None);
+        assert_eq!(empty.into_iter().count(), 0);
+    }
+
     fn check_segtree<M>(ans: &[M::Set], segtree: &SegmentTree<M>)
     where
         M: Monoid,
@@ -0,0 +1,161 @@
+/// A set of integers in `[0, n)` with O(1) insertion, removal, membership test and clearing.
+///
+/// Unlike `HashSet`, [`clear`] does not touch the elements, so it is cheap to reuse the set many times.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::sparse_set::SparseSet;
+///
+/// let mut set = SparseSet::new(10);
+/// set.insert(3);
+/// set.insert(7);
+/// set.insert(3);
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(7));
+/// set.remove(3);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![7]);
+/// set.clear();
+/// assert!(set.is_empty());
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm    | Worst case |
+/// | ------------ | ---------- |
+/// | [`new`]      | O(*n*)     |
+/// | [`insert`]   | O(1)       |
+/// | [`contains`] | O(1)       |
+/// | [`remove`]   | O(1)       |
+/// | [`clear`]    | O(1)       |
+///
+/// [`new`]: SparseSet::new
+/// [`insert`]: SparseSet::insert
+/// [`contains`]: SparseSet::contains
+/// [`remove`]: SparseSet::remove
+/// [`clear`]: SparseSet::clear
+#[derive(Clone, Debug)]
+pub struct SparseSet {
+    // `dense[..size]` are the elements
+    dense: Vec<usize>,
+    // `sparse[x]` is the position of `x` in `dense` if `x` is in the set
+    sparse: Vec<usize>,
+    size: usize,
+}
+
+impl SparseSet {
+    /// Creates an empty set for the integers in `[0, capacity)`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            dense: vec![0; capacity],
+            sparse: vec![0; capacity],
+            size: 0,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the upper bound (exclusive) of the elements.
+    pub fn capacity(&self) -> usize {
+        self.sparse.len()
+    }
+
+    /// Returns `true` if the set contains `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of `[0, capacity)`.
+    pub fn contains(&self, x: usize) -> bool {
+        let i = self.sparse[x];
+        i < self.size && self.dense[i] == x
+    }
+
+    /// Adds `x` to the set. Nothing happens if `x` is already in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of `[0, capacity)`.
+    pub fn insert(&mut self, x: usize) {
+        if self.contains(x) {
+            return;
+        }
+        self.dense[self.size] = x;
+        self.sparse[x] = self.size;
+        self.size += 1;
+    }
+
+    /// Removes `x` from the set. Nothing happens if `x` is not in the set.
+    ///
+    /// The last element in the iteration order is moved to the position of `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of `[0, capacity)`.
+    pub fn remove(&mut self, x: usize) {
+        if !self.contains(x) {
+            return;
+        }
+        let i = self.sparse[x];
+        let last = self.dense[self.size - 1];
+        self.dense[i] = last;
+        self.sparse[last] = i;
+        self.size -= 1;
+    }
+
+    /// Removes all the elements.
+    pub fn clear(&mut self) {
+        self.size = 0;
+    }
+
+    /// Returns an iterator over the elements in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.dense[..self.size].iter().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        let n = 20;
+        let mut set = SparseSet::new(n);
+        let mut naive = BTreeSet::new();
+        for _ in 0..2000 {
+            let x = rand() % n;
+            match rand() % 10 {
+                0 => {
+                    set.clear();
+                    naive.clear();
+                }
+                1..=5 => {
+                    set.insert(x);
+                    naive.insert(x);
+                }
+                _ => {
+                    set.remove(x);
+                    naive.remove(&x);
+                }
+            }
+            assert_eq!(set.len(), naive.len());
+            for y in 0..n {
+                assert_eq!(set.contains(y), naive.contains(&y));
+            }
+            let mut elements = set.iter().collect::<Vec<_>>();
+            elements.sort();
+            assert_eq!(elements, naive.iter().copied().collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use super::{monoid_pow, Monoid};
+use core::ops::{
+    Bound::{Excluded, Included, Unbounded},
+    RangeBounds,
+};
+
+/// Converts `range` to `[l, r)` and checks it is in `[0, n)`.
+fn to_range<R: RangeBounds<usize>>(range: R, n: usize) -> (usize, usize) {
+    let l = match range.start_bound() {
+        Included(&l) => l,
+        Excluded(&l) => l + 1,
+        Unbounded => 0,
+    };
+    let r = match range.end_bound() {
+        Included(&r) => r + 1,
+        Excluded(&r) => r,
+        Unbounded => n,
+    };
+    assert!(l <= r && r <= n);
+    (l, r)
+}
+
+/// Returns the block size for `n` elements, which is about √*n*.
+fn block_size(n: usize) -> usize {
+    let mut b = 1;
+    while b * b < n {
+        b += 1;
+    }
+    b
+}
+
+fn fold<M: Monoid>(values: &[M::Set]) -> M::Set {
+    values.iter().fold(M::id(), |acc, x| M::operate(&acc, x))
+}
+
+/// Square root decomposition for range folds of a monoid with point updates.
+///
+/// It does not require the inverse elements.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::MaxMonoid;
+/// use lib_rust::structs::sqrt_decomp::SqrtDecomp;
+///
+/// let mut sd = SqrtDecomp::<MaxMonoid<i32>>::from_slice(&[3, 1, 4, 1, 5, 9, 2]);
+/// assert_eq!(sd.query(1..4), 4);
+/// sd.update(2, 0);
+/// assert_eq!(sd.query(1..4), 1);
+/// assert_eq!(sd.query(..), 9);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm      | Worst case |
+/// | -------------- | ---------- |
+/// | Memory         | O(*n*)     |
+/// | [`from_slice`] | O(*n*)     |
+/// | [`update`]     | O(√*n*)    |
+/// | [`query`]      | O(√*n*)    |
+///
+/// [`from_slice`]: SqrtDecomp::from_slice
+/// [`update`]: SqrtDecomp::update
+/// [`query`]: SqrtDecomp::query
+pub struct SqrtDecomp<M: Monoid> {
+    size: usize,
+    values: Vec<M::Set>,
+    blocks: Vec<M::Set>,
+}
+
+impl<M: Monoid> SqrtDecomp<M> {
+    pub fn from_slice(s: &[M::Set]) -> Self {
+        let size = block_size(s.len());
+        let blocks = s.chunks(size).map(fold::<M>).collect();
+        Self {
+            size,
+            values: s.to_vec(),
+            blocks,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> &M::Set {
+        &self.values[index]
+    }
+
+    /// Replaces the `index`-th element with `value`.
+    ///
+    /// The fold of its block is recomputed, so it is O(√*n*).
+    pub fn update(&mut self, index: usize, value: M::Set) {
+        self.values[index] = value;
+        let b = index / self.size;
+        let end = (b * self.size + self.size).min(self.values.len());
+        self.blocks[b] = fold::<M>(&self.values[b * self.size..end]);
+    }
+
+    /// Returns the fold of the elements in `range`.
+    // `div_ceil` requires Rust 1.73
+    #[allow(clippy::manual_div_ceil)]
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> M::Set {
+        let (l, r) = to_range(range, self.values.len());
+        let (bl, br) = ((l + self.size - 1) / self.size, r / self.size);
+        if bl >= br {
+            return fold::<M>(&self.values[l..r]);
+        }
+        let left = fold::<M>(&self.values[l..bl * self.size]);
+        let mid = fold::<M>(&self.blocks[bl..br]);
+        let right = fold::<M>(&self.values[br * self.size..r]);
+        M::operate(&M::operate(&left, &mid), &right)
+    }
+}
+
+/// Square root decomposition for range folds of a monoid with range assignments.
+///
+/// The fold of `k` copies of an assigned value is computed with [`monoid_pow`],
+/// so it works for any monoid including non-invertible ones like `MaxMonoid`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::{AddMonoid, MaxMonoid};
+/// use lib_rust::structs::sqrt_decomp::SqrtDecompRangeUpdate;
+///
+/// let mut sd = SqrtDecompRangeUpdate::<MaxMonoid<i32>>::from_slice(&[3, 1, 4, 1, 5, 9, 2]);
+/// sd.assign(4..7, 0);
+/// assert_eq!(sd.query(..), 4);
+///
+/// let mut sd = SqrtDecompRangeUpdate::<AddMonoid<i32>>::from_slice(&[1; 10]);
+/// sd.assign(2..8, 3);
+/// assert_eq!(sd.query(..), 2 + 6 * 3 + 2);
+/// assert_eq!(sd.get(5), 3);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm      | Worst case       |
+/// | -------------- | ---------------- |
+/// | Memory         | O(*n*)           |
+/// | [`from_slice`] | O(*n*)           |
+/// | [`assign`]     | O(√*n* log(*n*)) |
+/// | [`query`]      | O(√*n*)          |
+///
+/// [`from_slice`]: SqrtDecompRangeUpdate::from_slice
+/// [`assign`]: SqrtDecompRangeUpdate::assign
+/// [`query`]: SqrtDecompRangeUpdate::query
+pub struct SqrtDecompRangeUpdate<M: Monoid> {
+    size: usize,
+    values: Vec<M::Set>,
+    blocks: Vec<M::Set>,
+    // the value assigned to the whole block, which is not applied to `values` yet
+    lazy: Vec<Option<M::Set>>,
+}
+
+impl<M: Monoid> SqrtDecompRangeUpdate<M> {
+    pub fn from_slice(s: &[M::Set]) -> Self {
+        let size = block_size(s.len());
+        let blocks = s.chunks(size).map(fold::<M>).collect::<Vec<_>>();
+        let lazy = vec![None; blocks.len()];
+        Self {
+            size,
+            values: s.to_vec(),
+            blocks,
+            lazy,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn block_range(&self, b: usize) -> (usize, usize) {
+        (
+            b * self.size,
+            (b * self.size + self.size).min(self.values.len()),
+        )
+    }
+
+    /// Applies the pending assignment of the block `b` to `values`.
+    fn push(&mut self, b: usize) {
+        if let Some(v) = self.lazy[b].take() {
+            let (l, r) = self.block_range(b);
+            for x in &mut self.values[l..r] {
+                *x = v.clone();
+            }
+        }
+    }
+
+    pub fn get(&self, index: usize) -> M::Set {
+        match &self.lazy[index / self.size] {
+            Some(v) => v.clone(),
+            None => self.values[index].clone(),
+        }
+    }
+
+    /// Assigns `value` to all the elements in `range`.
+    pub fn assign<R: RangeBounds<usize>>(&mut self, range: R, value: M::Set) {
+        let (l, r) = to_range(range, self.values.len());
+        if l == r {
+            return;
+        }
+        for b in l / self.size..=(r - 1) / self.size {
+            let (bl, br) = self.block_range(b);
+            if l <= bl && br <= r {
+                self.blocks[b] = monoid_pow::<M>(&value, (br - bl) as u64);
+                self.lazy[b] = Some(value.clone());
+            } else {
+                self.push(b);
+                for x in &mut self.values[l.max(bl)..r.min(br)] {
+                    *x = value.clone();
+                }
+                self.blocks[b] = fold::<M>(&self.values[bl..br]);
+            }
+        }
+    }
+
+    /// Returns the fold of the elements in `range`.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> M::Set {
+        let (l, r) = to_range(range, self.values.len());
+        if l == r {
+            return M::id();
+        }
+        let mut acc = M::id();
+        for b in l / self.size..=(r - 1) / self.size {
+            let (bl, br) = self.block_range(b);
+            let (ql, qr) = (l.max(bl), r.min(br));
+            let x = if (ql, qr) == (bl, br) {
+                self.blocks[b].clone()
+            } else {
+                match &self.lazy[b] {
+                    Some(v) => monoid_pow::<M>(v, (qr - ql) as u64),
+                    None => fold::<M>(&self.values[ql..qr]),
+                }
+            };
+            acc = M::operate(&acc, &x);
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::{AddMonoid, MaxMonoid};
+    use crate::testing::xorshift;
+
+    #[test]
+    fn point_update() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..30 {
+            let mut naive = (0..n).map(|_| rand() % 100).collect::<Vec<_>>();
+            let mut sd = SqrtDecomp::<MaxMonoid<u64>>::from_slice(&naive);
+            for _ in 0..30 {
+                if n > 0 {
+                    let i = rand() as usize % n;
+                    let v = rand() % 100;
+                    naive[i] = v;
+                    sd.update(i, v);
+                    assert_eq!(sd.get(i), &v);
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(
+                            sd.query(l..r),
+                            naive[l..r].iter().copied().max().unwrap_or(0)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn range_assign() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..30 {
+            let mut naive = (0..n).map(|_| rand() % 100).collect::<Vec<_>>();
+            let mut sd = SqrtDecompRangeUpdate::<AddMonoid<u64>>::from_slice(&naive);
+            for _ in 0..30 {
+                let l = rand() as usize % (n + 1);
+                let r = l + rand() as usize % (n + 1 - l);
+                let v = rand() % 100;
+                for x in &mut naive[l..r] {
+                    *x = v;
+                }
+                sd.assign(l..r, v);
+                for (i, &x) in naive.iter().enumerate() {
+                    assert_eq!(sd.get(i), x);
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(sd.query(l..r), naive[l..r].iter().sum::<u64>());
+                    }
+                }
+            }
+        }
+    }
+}
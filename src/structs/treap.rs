@@ -0,0 +1,387 @@
+use core::mem;
+use core::ops::Range;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Returns a pseudo-random priority with xorshift.
+fn random_priority() -> u64 {
+    static SEED: AtomicU64 = AtomicU64::new(88_172_645_463_325_252);
+    let mut x = SEED.load(Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    SEED.store(x, Ordering::Relaxed);
+    x
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    value: T,
+    priority: u64,
+    size: usize,
+    // The children of this node have to be swapped and reversed.
+    rev: bool,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            priority: random_priority(),
+            size: 1,
+            rev: false,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+
+    fn push(&mut self) {
+        if self.rev {
+            mem::swap(&mut self.left, &mut self.right);
+            for child in self.left.iter_mut().chain(self.right.iter_mut()) {
+                child.rev ^= true;
+            }
+            self.rev = false;
+        }
+    }
+}
+
+fn size<T>(node: &Link<T>) -> usize {
+    node.as_ref().map_or(0, |node| node.size)
+}
+
+/// Builds a treap of `values`, taking the values from the back.
+fn build<T>(values: &mut Vec<T>, len: usize) -> Link<T> {
+    if len == 0 {
+        return None;
+    }
+    let mid = len / 2;
+    let right = build(values, len - mid - 1);
+    let mut node = Box::new(Node::new(values.pop().unwrap()));
+    let left = build(values, mid);
+    node.left = left;
+    node.right = right;
+    node.update();
+
+    // restore the heap property by sifting the priority down
+    let mut cur = &mut node;
+    loop {
+        let (l, r) = (
+            cur.left.as_ref().map_or(0, |n| n.priority),
+            cur.right.as_ref().map_or(0, |n| n.priority),
+        );
+        let child = if l >= r {
+            &mut cur.left
+        } else {
+            &mut cur.right
+        };
+        match child {
+            Some(child) if child.priority > cur.priority => {
+                mem::swap(&mut child.priority, &mut cur.priority);
+                cur = child;
+            }
+            _ => break,
+        }
+    }
+    Some(node)
+}
+
+/// Splits `node` into the first `pos` elements and the rest.
+fn split<T>(node: Link<T>, pos: usize) -> (Link<T>, Link<T>) {
+    match node {
+        None => (None, None),
+        Some(mut node) => {
+            node.push();
+            let left_size = size(&node.left);
+            if pos <= left_size {
+                let (l, r) = split(node.left.take(), pos);
+                node.left = r;
+                node.update();
+                (l, Some(node))
+            } else {
+                let (l, r) = split(node.right.take(), pos - left_size - 1);
+                node.right = l;
+                node.update();
+                (Some(node), r)
+            }
+        }
+    }
+}
+
+fn merge<T>(left: Link<T>, right: Link<T>) -> Link<T> {
+    match (left, right) {
+        (None, node) | (node, None) => node,
+        (Some(mut l), Some(mut r)) => {
+            if l.priority > r.priority {
+                l.push();
+                l.right = merge(l.right.take(), Some(r));
+                l.update();
+                Some(l)
+            } else {
+                r.push();
+                r.left = merge(Some(l), r.left.take());
+                r.update();
+                Some(r)
+            }
+        }
+    }
+}
+
+/// An implicit treap, which is a sequence supporting splits, merges and range reversals.
+///
+/// The priorities of the nodes are pseudo-random, so the complexities are expected values.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::treap::ImplicitTreap;
+///
+/// let treap = ImplicitTreap::from_vec(vec![1, 2, 3, 4, 5]);
+/// let (left, right) = treap.split(2);
+/// assert_eq!(left.to_vec(), vec![1, 2]);
+/// assert_eq!(right.to_vec(), vec![3, 4, 5]);
+///
+/// let mut treap = ImplicitTreap::merge(right, left);
+/// assert_eq!(treap.to_vec(), vec![3, 4, 5, 1, 2]);
+/// treap.reverse(1..4);
+/// assert_eq!(treap.to_vec(), vec![3, 1, 5, 4, 2]);
+/// treap.set(0, 10);
+/// assert_eq!(treap.get(0), &10);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm    | Average     |
+/// | ------------ | ----------- |
+/// | Memory       | O(*n*)      |
+/// | [`from_vec`] | O(*n*)      |
+/// | [`split`]    | O(log(*n*)) |
+/// | [`merge`]    | O(log(*n*)) |
+/// | [`get`]      | O(log(*n*)) |
+/// | [`set`]      | O(log(*n*)) |
+/// | [`reverse`]  | O(log(*n*)) |
+///
+/// [`from_vec`]: ImplicitTreap::from_vec
+/// [`split`]: ImplicitTreap::split
+/// [`merge`]: ImplicitTreap::merge
+/// [`get`]: ImplicitTreap::get
+/// [`set`]: ImplicitTreap::set
+/// [`reverse`]: ImplicitTreap::reverse
+#[derive(Clone, Debug)]
+pub struct ImplicitTreap<T: Clone> {
+    root: Link<T>,
+}
+
+impl<T: Clone> ImplicitTreap<T> {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// Creates a sequence of `v`.
+    pub fn from_vec(mut v: Vec<T>) -> Self {
+        let len = v.len();
+        Self {
+            root: build(&mut v, len),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Splits the sequence into `[0, pos)` and `[pos, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos > len`.
+    pub fn split(self, pos: usize) -> (Self, Self) {
+        assert!(pos <= self.len());
+        let (l, r) = split(self.root, pos);
+        (Self { root: l }, Self { root: r })
+    }
+
+    /// Concatenates `left` and `right`.
+    pub fn merge(left: Self, right: Self) -> Self {
+        Self {
+            root: merge(left.root, right.root),
+        }
+    }
+
+    /// Returns the `pos`-th element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos >= len`.
+    pub fn get(&self, mut pos: usize) -> &T {
+        assert!(pos < self.len());
+        let mut node = self.root.as_ref();
+        // whether the current subtree is reversed by the pending flags of the ancestors
+        let mut flipped = false;
+        while let Some(n) = node {
+            flipped ^= n.rev;
+            let (l, r) = if flipped {
+                (&n.right, &n.left)
+            } else {
+                (&n.left, &n.right)
+            };
+            let left_size = size(l);
+            if pos < left_size {
+                node = l.as_ref();
+            } else if pos == left_size {
+                return &n.value;
+            } else {
+                pos -= left_size + 1;
+                node = r.as_ref();
+            }
+        }
+        unreachable!()
+    }
+
+    /// Replaces the `pos`-th element with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos >= len`.
+    pub fn set(&mut self, mut pos: usize, value: T) {
+        assert!(pos < self.len());
+        let mut node = self.root.as_mut();
+        while let Some(n) = node {
+            n.push();
+            let left_size = size(&n.left);
+            if pos < left_size {
+                node = n.left.as_mut();
+            } else if pos == left_size {
+                n.value = value;
+                return;
+            } else {
+                pos -= left_size + 1;
+                node = n.right.as_mut();
+            }
+        }
+        unreachable!()
+    }
+
+    /// Reverses the elements in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end` or `range.end > len`.
+    pub fn reverse(&mut self, range: Range<usize>) {
+        assert!(range.start <= range.end && range.end <= self.len());
+        let (rest, right) = split(self.root.take(), range.end);
+        let (left, mut mid) = split(rest, range.start);
+        if let Some(mid) = mid.as_mut() {
+            mid.rev ^= true;
+        }
+        self.root = merge(merge(left, mid), right);
+    }
+
+    /// Returns the elements in order.
+    ///
+    /// This operation is O(*n*).
+    pub fn to_vec(&self) -> Vec<T> {
+        fn dfs<T: Clone>(node: &Link<T>, flipped: bool, out: &mut Vec<T>) {
+            if let Some(n) = node {
+                let flipped = flipped ^ n.rev;
+                let (l, r) = if flipped {
+                    (&n.right, &n.left)
+                } else {
+                    (&n.left, &n.right)
+                };
+                dfs(l, flipped, out);
+                out.push(n.value.clone());
+                dfs(r, flipped, out);
+            }
+        }
+
+        let mut ret = Vec::with_capacity(self.len());
+        dfs(&self.root, false, &mut ret);
+        ret
+    }
+}
+
+impl<T: Clone> Default for ImplicitTreap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random_operations() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        let mut naive = (0..50).collect::<Vec<_>>();
+        let mut treap = ImplicitTreap::from_vec(naive.clone());
+        for _ in 0..500 {
+            let n = naive.len();
+            match rand() % 4 {
+                0 => {
+                    let l = rand() % (n + 1);
+                    let r = l + rand() % (n + 1 - l);
+                    naive[l..r].reverse();
+                    treap.reverse(l..r);
+                }
+                1 => {
+                    let pos = rand() % n;
+                    let value = rand() % 1000;
+                    naive[pos] = value;
+                    treap.set(pos, value);
+                }
+                2 => {
+                    // rotate
+                    let pos = rand() % (n + 1);
+                    naive.rotate_left(pos);
+                    let (l, r) = treap.split(pos);
+                    treap = ImplicitTreap::merge(r, l);
+                }
+                _ => {
+                    let pos = rand() % n;
+                    assert_eq!(treap.get(pos), &naive[pos]);
+                }
+            }
+            assert_eq!(treap.len(), n);
+            assert_eq!(treap.to_vec(), naive);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let treap = ImplicitTreap::<i32>::from_vec(vec![]);
+        assert!(treap.is_empty());
+        let (l, r) = treap.split(0);
+        assert!(l.is_empty() && r.is_empty());
+        let mut treap = ImplicitTreap::merge(l, ImplicitTreap::from_vec(vec![1]));
+        treap.reverse(0..1);
+        treap.reverse(1..1);
+        assert_eq!(treap.to_vec(), vec![1]);
+    }
+
+    #[test]
+    fn deep() {
+        let n = 100_000;
+        let mut treap = ImplicitTreap::new();
+        for i in 0..n {
+            treap = ImplicitTreap::merge(treap, ImplicitTreap::from_vec(vec![i]));
+        }
+        treap.reverse(0..n);
+        assert_eq!(treap.get(0), &(n - 1));
+        assert_eq!(treap.get(n - 1), &0);
+    }
+}
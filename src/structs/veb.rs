@@ -0,0 +1,362 @@
+// the universe of a leaf fits in a `u64`
+const LEAF_LOG: u32 = 6;
+
+/// The van Emde Boas tree, a set of integers in `[0, u)` where `u` is a power of two.
+///
+/// A node of the universe 2<sup>*k*</sup> consists of a summary and clusters of the universe about 2<sup>*k*/2</sup>,
+/// and the minimum is kept out of the clusters, so each operation recurses only once.
+/// The universes up to 64 are represented as bitsets.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::veb::VebTree;
+///
+/// let mut veb = VebTree::new(1 << 16);
+/// veb.insert(3);
+/// veb.insert(1000);
+/// veb.insert(65535);
+/// assert!(veb.contains(1000));
+/// assert_eq!(veb.successor(3), Some(1000));
+/// assert_eq!(veb.predecessor(3), None);
+/// veb.delete(1000);
+/// assert_eq!(veb.successor(3), Some(65535));
+/// assert_eq!((veb.min(), veb.max()), (Some(3), Some(65535)));
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm                       | Worst case       |
+/// | ------------------------------- | ---------------- |
+/// | Memory                          | O(*u*)           |
+/// | [`new`]                         | O(*u*)           |
+/// | [`insert`]                      | O(log(log(*u*))) |
+/// | [`delete`]                      | O(log(log(*u*))) |
+/// | [`contains`]                    | O(log(log(*u*))) |
+/// | [`successor`] / [`predecessor`] | O(log(log(*u*))) |
+/// | [`min`] / [`max`]               | O(1)             |
+///
+/// [`new`]: VebTree::new
+/// [`insert`]: VebTree::insert
+/// [`delete`]: VebTree::delete
+/// [`contains`]: VebTree::contains
+/// [`successor`]: VebTree::successor
+/// [`predecessor`]: VebTree::predecessor
+/// [`min`]: VebTree::min
+/// [`max`]: VebTree::max
+#[derive(Clone, Debug)]
+pub struct VebTree {
+    log: u32,
+    // the elements of a leaf
+    bits: u64,
+    // the minimum and the maximum of a non-leaf node, where the minimum is not in the clusters
+    min: Option<usize>,
+    max: Option<usize>,
+    summary: Option<Box<VebTree>>,
+    clusters: Vec<VebTree>,
+}
+
+impl VebTree {
+    /// Creates an empty set for the integers in `[0, universe)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `universe` is not a power of two.
+    pub fn new(universe: usize) -> Self {
+        assert!(universe.is_power_of_two());
+        Self::with_log(universe.trailing_zeros())
+    }
+
+    fn with_log(log: u32) -> Self {
+        let (summary, clusters) = if log <= LEAF_LOG {
+            (None, vec![])
+        } else {
+            let (high, low) = (log - log / 2, log / 2);
+            let summary = Box::new(Self::with_log(high));
+            (Some(summary), vec![Self::with_log(low); 1 << high])
+        };
+        Self {
+            log,
+            bits: 0,
+            min: None,
+            max: None,
+            summary,
+            clusters,
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.log <= LEAF_LOG
+    }
+
+    fn split(&self, x: usize) -> (usize, usize) {
+        let low = self.log / 2;
+        (x >> low, x & ((1 << low) - 1))
+    }
+
+    fn index(&self, high: usize, low: usize) -> usize {
+        high << (self.log / 2) | low
+    }
+
+    fn summary(&self) -> &Self {
+        self.summary.as_ref().unwrap()
+    }
+
+    fn summary_mut(&mut self) -> &mut Self {
+        self.summary.as_mut().unwrap()
+    }
+
+    /// Returns the size of the universe.
+    pub fn universe(&self) -> usize {
+        1 << self.log
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        if self.is_leaf() {
+            if self.bits == 0 {
+                None
+            } else {
+                Some(self.bits.trailing_zeros() as usize)
+            }
+        } else {
+            self.min
+        }
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        if self.is_leaf() {
+            if self.bits == 0 {
+                None
+            } else {
+                Some(63 - self.bits.leading_zeros() as usize)
+            }
+        } else {
+            self.max
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `x` is out of the universe.
+    pub fn contains(&self, x: usize) -> bool {
+        assert!(x < self.universe());
+        self.contains_inner(x)
+    }
+
+    fn contains_inner(&self, x: usize) -> bool {
+        if self.is_leaf() {
+            return self.bits >> x & 1 == 1;
+        }
+        if self.min == Some(x) || self.max == Some(x) {
+            return true;
+        }
+        let (h, l) = self.split(x);
+        self.clusters[h].contains_inner(l)
+    }
+
+    /// Inserts `x`. It does nothing if `x` is already in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of the universe.
+    pub fn insert(&mut self, x: usize) {
+        assert!(x < self.universe());
+        self.insert_inner(x);
+    }
+
+    fn insert_inner(&mut self, mut x: usize) {
+        if self.is_leaf() {
+            self.bits |= 1 << x;
+            return;
+        }
+        match self.min {
+            None => {
+                self.min = Some(x);
+                self.max = Some(x);
+                return;
+            }
+            Some(min) if x == min => return,
+            // the new minimum is kept here and the old one goes down to the cluster
+            Some(min) if x < min => {
+                self.min = Some(x);
+                x = min;
+            }
+            _ => {}
+        }
+        let (h, l) = self.split(x);
+        if self.clusters[h].min().is_none() {
+            self.summary_mut().insert_inner(h);
+        }
+        self.clusters[h].insert_inner(l);
+        if self.max < Some(x) {
+            self.max = Some(x);
+        }
+    }
+
+    /// Deletes `x`. It does nothing if `x` is not in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of the universe.
+    pub fn delete(&mut self, x: usize) {
+        assert!(x < self.universe());
+        self.delete_inner(x);
+    }
+
+    fn delete_inner(&mut self, mut x: usize) {
+        if self.is_leaf() {
+            self.bits &= !(1 << x);
+            return;
+        }
+        let (min, max) = match (self.min, self.max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return,
+        };
+        if min == max {
+            if x == min {
+                self.min = None;
+                self.max = None;
+            }
+            return;
+        }
+        if x == min {
+            // the next element becomes the minimum, and it is removed from the cluster instead
+            let h = self.summary().min().unwrap();
+            x = self.index(h, self.clusters[h].min().unwrap());
+            self.min = Some(x);
+        }
+        let (h, l) = self.split(x);
+        self.clusters[h].delete_inner(l);
+        if self.clusters[h].min().is_none() {
+            self.summary_mut().delete_inner(h);
+            if x == max {
+                self.max = match self.summary().max() {
+                    Some(h) => Some(self.index(h, self.clusters[h].max().unwrap())),
+                    None => self.min,
+                };
+            }
+        } else if x == max {
+            self.max = Some(self.index(h, self.clusters[h].max().unwrap()));
+        }
+    }
+
+    /// Returns the smallest element greater than `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of the universe.
+    pub fn successor(&self, x: usize) -> Option<usize> {
+        assert!(x < self.universe());
+        self.successor_inner(x)
+    }
+
+    fn successor_inner(&self, x: usize) -> Option<usize> {
+        if self.is_leaf() {
+            let rest = if x + 1 < 64 {
+                self.bits >> (x + 1) << (x + 1)
+            } else {
+                0
+            };
+            return if rest == 0 {
+                None
+            } else {
+                Some(rest.trailing_zeros() as usize)
+            };
+        }
+        match self.min {
+            None => return None,
+            Some(min) if x < min => return Some(min),
+            _ => {}
+        }
+        let (h, l) = self.split(x);
+        if Some(l) < self.clusters[h].max() {
+            let l = self.clusters[h].successor_inner(l).unwrap();
+            return Some(self.index(h, l));
+        }
+        self.summary()
+            .successor_inner(h)
+            .map(|h| self.index(h, self.clusters[h].min().unwrap()))
+    }
+
+    /// Returns the largest element less than `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of the universe.
+    pub fn predecessor(&self, x: usize) -> Option<usize> {
+        assert!(x < self.universe());
+        self.predecessor_inner(x)
+    }
+
+    fn predecessor_inner(&self, x: usize) -> Option<usize> {
+        if self.is_leaf() {
+            let rest = self.bits & ((1 << x) - 1);
+            return if rest == 0 {
+                None
+            } else {
+                Some(63 - rest.leading_zeros() as usize)
+            };
+        }
+        match self.max {
+            None => return None,
+            Some(max) if max < x => return Some(max),
+            _ => {}
+        }
+        let (h, l) = self.split(x);
+        if let Some(min) = self.clusters[h].min() {
+            if min < l {
+                let l = self.clusters[h].predecessor_inner(l).unwrap();
+                return Some(self.index(h, l));
+            }
+        }
+        match self.summary().predecessor_inner(h) {
+            Some(h) => Some(self.index(h, self.clusters[h].max().unwrap())),
+            // only the minimum, which is not in the clusters, can be less than `x`
+            None => self.min.filter(|&min| min < x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for &log in &[0, 1, 3, 6, 7, 10, 13] {
+            let u = 1 << log;
+            let mut veb = VebTree::new(u);
+            let mut naive = BTreeSet::new();
+            for _ in 0..3000 {
+                let x = rand() % u;
+                match rand() % 3 {
+                    0 => {
+                        veb.insert(x);
+                        naive.insert(x);
+                    }
+                    1 => {
+                        veb.delete(x);
+                        naive.remove(&x);
+                    }
+                    _ => {
+                        // delete an existing element to make the set small again
+                        if let Some(&y) = naive.range(x..).next() {
+                            veb.delete(y);
+                            naive.remove(&y);
+                        }
+                    }
+                }
+                let x = rand() % u;
+                assert_eq!(veb.contains(x), naive.contains(&x));
+                assert_eq!(veb.successor(x), naive.range(x + 1..).next().copied());
+                assert_eq!(veb.predecessor(x), naive.range(..x).next_back().copied());
+                assert_eq!(veb.min(), naive.iter().next().copied());
+                assert_eq!(veb.max(), naive.iter().next_back().copied());
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+/// A bit vector with rank queries.
+#[derive(Clone, Debug)]
+struct BitLevel {
+    // `zeros[i]` is the number of zeros in `[0, i)`
+    zeros: Vec<usize>,
+}
+
+impl BitLevel {
+    fn rank0(&self, i: usize) -> usize {
+        self.zeros[i]
+    }
+
+    fn num_zeros(&self) -> usize {
+        *self.zeros.last().unwrap()
+    }
+}
+
+/// A wavelet tree (implemented as a wavelet matrix) for range order statistics of a static array.
+///
+/// The values are compressed, so the complexities depend on the number of distinct values *σ*.
+/// The ranges are half-open, `[l, r)`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::structs::wavelet_tree::WaveletTree;
+///
+/// let tree = WaveletTree::build(&[5, 1, 4, 1, 3, 9, 2]);
+/// // [1, 4, 1, 3] sorted is [1, 1, 3, 4]
+/// assert_eq!(tree.kth_smallest(1, 5, 0), 1);
+/// assert_eq!(tree.kth_smallest(1, 5, 2), 3);
+/// assert_eq!(tree.count_less_than(1, 5, &4), 3);
+/// assert_eq!(tree.count_in_range(0, 7, &2, &5), 3);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm           | Worst case      |
+/// | ------------------- | --------------- |
+/// | Memory              | O(*n* log(*σ*)) |
+/// | [`build`]           | O(*n* log(*n*)) |
+/// | [`kth_smallest`]    | O(log(*σ*))     |
+/// | [`count_less_than`] | O(log(*σ*))     |
+/// | [`count_in_range`]  | O(log(*σ*))     |
+///
+/// [`build`]: WaveletTree::build
+/// [`kth_smallest`]: WaveletTree::kth_smallest
+/// [`count_less_than`]: WaveletTree::count_less_than
+/// [`count_in_range`]: WaveletTree::count_in_range
+#[derive(Clone, Debug)]
+pub struct WaveletTree<T: Ord> {
+    len: usize,
+    // sorted distinct values
+    values: Vec<T>,
+    // from the most significant bit
+    levels: Vec<BitLevel>,
+}
+
+impl<T: Ord + Clone> WaveletTree<T> {
+    /// Builds the tree of `arr`.
+    pub fn build(arr: &[T]) -> Self {
+        let mut values = arr.to_vec();
+        values.sort();
+        values.dedup();
+        let mut codes = arr
+            .iter()
+            .map(|x| values.binary_search(x).unwrap())
+            .collect::<Vec<_>>();
+
+        let mut bits = 0;
+        while (1 << bits) < values.len() {
+            bits += 1;
+        }
+
+        let mut levels = Vec::with_capacity(bits);
+        for b in (0..bits).rev() {
+            let mut zeros = Vec::with_capacity(codes.len() + 1);
+            zeros.push(0);
+            for &c in &codes {
+                zeros.push(zeros.last().unwrap() + (c >> b & 1 == 0) as usize);
+            }
+            // stable partition by the bit
+            let (mut lo, hi): (Vec<_>, Vec<_>) = codes.iter().partition(|&&c| c >> b & 1 == 0);
+            lo.extend(hi);
+            codes = lo;
+            levels.push(BitLevel { zeros });
+        }
+
+        Self {
+            len: arr.len(),
+            values,
+            levels,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the `k`-th (0-indexed) smallest value in `arr[l..r]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is invalid or `k >= r - l`.
+    pub fn kth_smallest(&self, mut l: usize, mut r: usize, mut k: usize) -> T {
+        assert!(l <= r && r <= self.len);
+        assert!(k < r - l);
+        let mut code = 0;
+        for level in &self.levels {
+            let (zl, zr) = (level.rank0(l), level.rank0(r));
+            code <<= 1;
+            if k < zr - zl {
+                l = zl;
+                r = zr;
+            } else {
+                k -= zr - zl;
+                code |= 1;
+                let nz = level.num_zeros();
+                l = nz + (l - zl);
+                r = nz + (r - zr);
+            }
+        }
+        self.values[code].clone()
+    }
+
+    /// Returns the number of values less than `value` in `arr[l..r]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is invalid.
+    pub fn count_less_than(&self, mut l: usize, mut r: usize, value: &T) -> usize {
+        assert!(l <= r && r <= self.len);
+        // the number of distinct values less than `value`
+        let bound = match self.values.binary_search(value) {
+            Ok(i) | Err(i) => i,
+        };
+        if bound >> self.levels.len() > 0 {
+            return r - l;
+        }
+
+        let mut count = 0;
+        let bits = self.levels.len();
+        for (i, level) in self.levels.iter().enumerate() {
+            let (zl, zr) = (level.rank0(l), level.rank0(r));
+            if bound >> (bits - 1 - i) & 1 == 1 {
+                count += zr - zl;
+                let nz = level.num_zeros();
+                l = nz + (l - zl);
+                r = nz + (r - zr);
+            } else {
+                l = zl;
+                r = zr;
+            }
+        }
+        count
+    }
+
+    /// Returns the number of values `x` such that `lo <= x < hi` in `arr[l..r]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is invalid.
+    pub fn count_in_range(&self, l: usize, r: usize, lo: &T, hi: &T) -> usize {
+        if lo >= hi {
+            assert!(l <= r && r <= self.len);
+            return 0;
+        }
+        self.count_less_than(l, r, hi) - self.count_less_than(l, r, lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed);
+
+        for n in 0..25 {
+            let modulo = rand() % 20 + 1;
+            let a = (0..n).map(|_| rand() % modulo).collect::<Vec<_>>();
+            let tree = WaveletTree::build(&a);
+            assert_eq!(tree.len(), n);
+            for l in 0..=n {
+                for r in l..=n {
+                    let mut sorted = a[l..r].to_vec();
+                    sorted.sort();
+                    for (k, &x) in sorted.iter().enumerate() {
+                        assert_eq!(tree.kth_smallest(l, r, k), x);
+                    }
+                    for v in 0..=modulo {
+                        let less = sorted.iter().filter(|&&x| x < v).count();
+                        assert_eq!(tree.count_less_than(l, r, &v), less);
+                        let between = sorted.iter().filter(|&&x| 2 <= x && x < v).count();
+                        assert_eq!(tree.count_in_range(l, r, &2, &v), between);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let words = ["pear", "apple", "fig", "apple", "kiwi"];
+        let tree = WaveletTree::build(&words);
+        assert_eq!(tree.kth_smallest(0, 5, 2), "fig");
+        assert_eq!(tree.count_less_than(1, 4, &"b"), 2);
+    }
+}
@@ -0,0 +1,9 @@
+/// Returns a xorshift generator starting from `seed`, whose outputs are mapped by `f`.
+pub(crate) fn xorshift<T>(mut seed: u64, f: impl Fn(u64) -> T) -> impl FnMut() -> T {
+    move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        f(seed)
+    }
+}
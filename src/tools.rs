@@ -4,9 +4,18 @@ pub mod abs_sub;
 #[cfg_attr(nightly, codesnip::entry("Bisect"))]
 pub mod bisect;
 
+#[cfg_attr(nightly, codesnip::entry("FastIo"))]
+pub mod fast_io;
+
 #[cfg_attr(nightly, codesnip::entry("MinMax"))]
 pub mod min_max;
 
+#[cfg_attr(nightly, codesnip::entry("PrefixFold", include("Monoid", "Group")))]
+pub mod prefix_fold;
+
+#[cfg_attr(nightly, codesnip::entry("PrefixSum2D", include("Zero")))]
+pub mod prefix_sum_2d;
+
 pub mod abs_pow;
 pub mod matrix_ops;
 pub mod mod_pow;
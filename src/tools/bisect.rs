@@ -126,33 +126,40 @@ impl<R: RangeBounds<usize>> RangeBisect<usize> for R {
             Unbounded => core::usize::MAX,
         };
 
-        let mut mid = start + (end - start) / 2;
-        let mut cmp = f(mid);
-        while end - start >= 1 && cmp != Equal {
-            match cmp {
-                Less => start = mid + 1,
-                Greater => end = mid,
-                Equal => unreachable!(),
+        // find an index `mid` with `f(mid) == Equal`, without calling `f` out of `start..end`
+        let mut mid = None;
+        while start < end {
+            let m = start + (end - start) / 2;
+            match f(m) {
+                Less => start = m + 1,
+                Greater => end = m,
+                Equal => {
+                    mid = Some(m);
+                    break;
+                }
             }
-            mid = start + (end - start) / 2;
-            cmp = f(mid);
         }
+        let mid = match mid {
+            Some(mid) => mid,
+            None => return start..start,
+        };
 
+        // the lower and upper bounds are searched independently in `[start, mid]` and `[mid + 1, end]`
         let mut lower = (start, mid);
-        while lower.1 - lower.0 >= 1 {
-            let mid = lower.0 + (lower.1 - lower.0) / 2;
-            match f(mid) {
-                Less => lower.0 = mid + 1,
-                Equal | Greater => lower.1 = mid,
+        while lower.0 < lower.1 {
+            let m = lower.0 + (lower.1 - lower.0) / 2;
+            match f(m) {
+                Less => lower.0 = m + 1,
+                Equal | Greater => lower.1 = m,
             }
         }
 
-        let mut upper = (mid, end);
-        while upper.1 - upper.0 >= 1 {
-            let mid = upper.0 + (upper.1 - upper.0) / 2;
-            match f(mid) {
-                Less | Equal => upper.0 = mid + 1,
-                Greater => upper.1 = mid,
+        let mut upper = (mid + 1, end);
+        while upper.0 < upper.1 {
+            let m = upper.0 + (upper.1 - upper.0) / 2;
+            match f(m) {
+                Less | Equal => upper.0 = m + 1,
+                Greater => upper.1 = m,
             }
         }
 
@@ -163,6 +170,7 @@ impl<R: RangeBounds<usize>> RangeBisect<usize> for R {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::xorshift;
     use std::cmp::Reverse;
 
     #[test]
@@ -228,6 +236,39 @@ mod tests {
         assert!(v[i..].iter().all(|&x| !(x < 5)));
     }
 
+    #[test]
+    fn find_range_random() {
+        let mut rand = xorshift(88172645463325252_u64, |seed| seed as usize);
+
+        for n in 0_usize..30 {
+            for _ in 0..20 {
+                // many duplicates
+                let mut v = (0..n).map(|_| rand() % 5).collect::<Vec<_>>();
+                v.sort();
+                for x in 0..=6 {
+                    let lower = v.iter().filter(|&&y| y < x).count();
+                    let upper = v.iter().filter(|&&y| y <= x).count();
+                    assert_eq!(v.find_range(&x), lower..upper, "{:?} {}", v, x);
+                    assert_eq!(v.lower_bound(&x), lower);
+                    assert_eq!(v.upper_bound(&x), upper);
+
+                    // `f` is called only in the range
+                    let r = (2..n.max(2)).find_range_by(|i| {
+                        assert!(2 <= i && i < n);
+                        v[i].cmp(&x)
+                    });
+                    let expected = if n <= 2 {
+                        2..2
+                    } else {
+                        2 + v[2..].iter().filter(|&&y| y < x).count()
+                            ..2 + v[2..].iter().filter(|&&y| y <= x).count()
+                    };
+                    assert_eq!(r, expected);
+                }
+            }
+        }
+    }
+
     #[test]
     fn range_bisect() {
         assert_eq!((..).partition_point(|i| i * 2 < 13), 7)
@@ -0,0 +1,205 @@
+use std::fmt::{Debug, Display};
+use std::io::{self, BufRead, BufReader, BufWriter, Stdin, Stdout, Write};
+use std::str::FromStr;
+
+/// A buffered reader which reads whitespace-separated tokens.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::read;
+/// use lib_rust::tools::fast_io::StdinReader;
+///
+/// let input = "3\n1 2 3\nabc\n";
+/// let mut reader = StdinReader::from_reader(input.as_bytes());
+/// let n: usize = reader.read();
+/// assert_eq!(reader.read_vec::<i64>(n), vec![1, 2, 3]);
+/// assert_eq!(read!(reader, String), "abc");
+/// ```
+pub struct StdinReader<R: BufRead = BufReader<Stdin>> {
+    reader: R,
+    // tokens of the current line in reverse order
+    tokens: Vec<String>,
+}
+
+impl StdinReader {
+    /// Creates a reader of the standard input.
+    pub fn new() -> Self {
+        Self::from_reader(BufReader::new(io::stdin()))
+    }
+}
+
+impl Default for StdinReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: BufRead> StdinReader<R> {
+    /// Creates a reader of `reader`.
+    pub fn from_reader(reader: R) -> Self {
+        Self {
+            reader,
+            tokens: vec![],
+        }
+    }
+
+    /// Reads the next token and parses it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ends or the token cannot be parsed.
+    pub fn read<T>(&mut self) -> T
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        loop {
+            if let Some(token) = self.tokens.pop() {
+                return token.parse().expect("failed to parse a token");
+            }
+            let mut line = String::new();
+            let len = self
+                .reader
+                .read_line(&mut line)
+                .expect("failed to read a line");
+            assert!(len > 0, "unexpected end of input");
+            self.tokens = line.split_whitespace().rev().map(String::from).collect();
+        }
+    }
+
+    /// Reads the next `n` tokens.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the input ends or a token cannot be parsed.
+    pub fn read_vec<T>(&mut self, n: usize) -> Vec<T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        (0..n).map(|_| self.read()).collect()
+    }
+}
+
+/// A buffered writer of the standard output.
+///
+/// The output is flushed when it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::tools::fast_io::StdoutWriter;
+///
+/// let mut out = vec![];
+/// {
+///     let mut writer = StdoutWriter::from_writer(&mut out);
+///     writer.print(1);
+///     writer.print(' ');
+///     writer.println("abc");
+/// }
+/// assert_eq!(out, b"1 abc\n");
+/// ```
+pub struct StdoutWriter<W: Write = BufWriter<Stdout>> {
+    writer: W,
+}
+
+impl StdoutWriter {
+    /// Creates a writer of the standard output.
+    pub fn new() -> Self {
+        Self::from_writer(BufWriter::new(io::stdout()))
+    }
+}
+
+impl Default for StdoutWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write> StdoutWriter<W> {
+    /// Creates a writer of `writer`.
+    pub fn from_writer(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes `x`.
+    pub fn print<T: Display>(&mut self, x: T) {
+        write!(self.writer, "{}", x).expect("failed to write");
+    }
+
+    /// Writes `x` and a newline.
+    pub fn println<T: Display>(&mut self, x: T) {
+        writeln!(self.writer, "{}", x).expect("failed to write");
+    }
+
+    /// Flushes the buffer.
+    pub fn flush(&mut self) {
+        self.writer.flush().expect("failed to flush");
+    }
+}
+
+impl<W: Write> Drop for StdoutWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+/// Reads a value of the type from a [`StdinReader`].
+///
+/// `read!(reader, [T; n])` reads `n` values into a `Vec<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::read;
+/// use lib_rust::tools::fast_io::StdinReader;
+///
+/// let mut reader = StdinReader::from_reader("2 -1 4\n".as_bytes());
+/// let n = read!(reader, usize);
+/// assert_eq!(read!(reader, [i32; n]), vec![-1, 4]);
+/// ```
+#[macro_export]
+macro_rules! read {
+    ($reader:expr, [$t:ty; $n:expr]) => {
+        $reader.read_vec::<$t>($n)
+    };
+    ($reader:expr, $t:ty) => {
+        $reader.read::<$t>()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_tokens() {
+        let input = "  1  2\n\n-3\n4.5 x\n";
+        let mut reader = StdinReader::from_reader(input.as_bytes());
+        assert_eq!(reader.read_vec::<i32>(3), vec![1, 2, -3]);
+        assert_eq!(reader.read::<f64>(), 4.5);
+        assert_eq!(reader.read::<char>(), 'x');
+    }
+
+    #[test]
+    #[should_panic]
+    fn read_eof() {
+        let mut reader = StdinReader::from_reader("1\n".as_bytes());
+        reader.read::<i32>();
+        reader.read::<i32>();
+    }
+
+    #[test]
+    fn write() {
+        let mut out = vec![];
+        {
+            let mut writer = StdoutWriter::from_writer(&mut out);
+            for i in 0..3 {
+                writer.println(i);
+            }
+            writer.flush();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "0\n1\n2\n");
+    }
+}
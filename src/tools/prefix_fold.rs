@@ -0,0 +1,140 @@
+use crate::math::num::{Group, Monoid};
+
+/// Returns the prefix folds of `arr`, where the `i`-th element is the fold of `arr[..=i]`.
+///
+/// This operation is O(*n*).
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddMonoid;
+/// use lib_rust::tools::prefix_fold::prefix_fold;
+///
+/// assert_eq!(prefix_fold::<AddMonoid<i32>>(&[3, 1, 4, 1]), vec![3, 4, 8, 9]);
+/// ```
+pub fn prefix_fold<M: Monoid>(arr: &[M::Set]) -> Vec<M::Set> {
+    let mut ret = Vec::with_capacity(arr.len());
+    let mut acc = M::id();
+    for x in arr {
+        acc = M::operate(&acc, x);
+        ret.push(acc.clone());
+    }
+    ret
+}
+
+/// Returns the fold of `arr[l..r]` from `prefix`, the result of [`prefix_fold`] of `arr`.
+///
+/// It works for non-commutative groups as well.
+///
+/// This operation is O(1).
+///
+/// # Panics
+///
+/// Panics if `l > r` or `r > prefix.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::AddGroup;
+/// use lib_rust::tools::prefix_fold::{prefix_fold, range_fold};
+///
+/// let prefix = prefix_fold::<AddGroup<i32>>(&[3, 1, 4, 1, 5]);
+/// assert_eq!(range_fold::<AddGroup<i32>>(&prefix, 1, 4), 6);
+/// assert_eq!(range_fold::<AddGroup<i32>>(&prefix, 0, 5), 14);
+/// assert_eq!(range_fold::<AddGroup<i32>>(&prefix, 2, 2), 0);
+/// ```
+pub fn range_fold<M: Group>(prefix: &[M::Set], l: usize, r: usize) -> M::Set {
+    assert!(l <= r && r <= prefix.len());
+    if l == r {
+        M::id()
+    } else if l == 0 {
+        prefix[r - 1].clone()
+    } else {
+        M::operate(&M::inv(&prefix[l - 1]), &prefix[r - 1])
+    }
+}
+
+/// Returns the fold of `arr[l..r]` naively.
+///
+/// Use [`range_fold`] with the prefix folds for a group,
+/// or a segment tree for a monoid if there are many queries.
+///
+/// This operation is O(*r* - *l*).
+///
+/// # Panics
+///
+/// Panics if `l > r` or `r > arr.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::math::num::alge_struct::types::MaxMonoid;
+/// use lib_rust::tools::prefix_fold::range_fold_monoid;
+///
+/// assert_eq!(range_fold_monoid::<MaxMonoid<i32>>(&[3, 1, 4, 1, 5], 1, 4), 4);
+/// ```
+pub fn range_fold_monoid<M: Monoid>(arr: &[M::Set], l: usize, r: usize) -> M::Set {
+    arr[l..r].iter().fold(M::id(), |acc, x| M::operate(&acc, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::num::alge_struct::types::AddGroup;
+    use crate::math::num::Semigroup;
+
+    /// The group of permutations of 3 elements, which is non-commutative.
+    enum Perm3 {}
+
+    impl Semigroup for Perm3 {
+        type Set = [usize; 3];
+
+        // applies `lhs` first, then `rhs`
+        fn operate(lhs: &Self::Set, rhs: &Self::Set) -> Self::Set {
+            [rhs[lhs[0]], rhs[lhs[1]], rhs[lhs[2]]]
+        }
+    }
+
+    impl Monoid for Perm3 {
+        fn id() -> Self::Set {
+            [0, 1, 2]
+        }
+    }
+
+    impl Group for Perm3 {
+        fn inv(x: &Self::Set) -> Self::Set {
+            let mut ret = [0; 3];
+            for (i, &p) in x.iter().enumerate() {
+                ret[p] = i;
+            }
+            ret
+        }
+    }
+
+    #[test]
+    fn add() {
+        let a = [5_i64, -2, 7, 0, 3, -8];
+        let prefix = prefix_fold::<AddGroup<i64>>(&a);
+        for l in 0..=a.len() {
+            for r in l..=a.len() {
+                let expected = a[l..r].iter().sum::<i64>();
+                assert_eq!(range_fold::<AddGroup<i64>>(&prefix, l, r), expected);
+                assert_eq!(range_fold_monoid::<AddGroup<i64>>(&a, l, r), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn non_commutative() {
+        let a = [[1, 0, 2], [2, 1, 0], [0, 2, 1], [1, 2, 0], [1, 0, 2]];
+        let prefix = prefix_fold::<Perm3>(&a);
+        for l in 0..=a.len() {
+            for r in l..=a.len() {
+                assert_eq!(
+                    range_fold::<Perm3>(&prefix, l, r),
+                    range_fold_monoid::<Perm3>(&a, l, r)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::math::num::Zero;
+use core::ops::{Add, Sub};
+
+/// 2D prefix sums of a static grid for rectangle sum queries.
+///
+/// # Examples
+///
+/// ```
+/// use lib_rust::tools::prefix_sum_2d::PrefixSum2D;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// let sum = PrefixSum2D::build(&grid);
+/// assert_eq!(sum.query(0, 0, 2, 3), 21);
+/// assert_eq!(sum.query(1, 1, 2, 3), 11);
+/// assert_eq!(sum.query(0, 1, 0, 3), 0);
+/// ```
+///
+/// # Time complexity
+///
+/// | Algorithm | Worst case |
+/// | --------- | ---------- |
+/// | [`build`] | O(*HW*)    |
+/// | [`query`] | O(1)       |
+///
+/// [`build`]: PrefixSum2D::build
+/// [`query`]: PrefixSum2D::query
+#[derive(Clone, Debug)]
+pub struct PrefixSum2D<T> {
+    // sum[i][j] is the sum of grid[..i][..j]
+    sum: Vec<Vec<T>>,
+}
+
+impl<T> PrefixSum2D<T>
+where
+    T: Clone + Zero + Add<Output = T> + Sub<Output = T>,
+{
+    /// Builds the prefix sums of `grid`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows have different lengths.
+    pub fn build(grid: &[Vec<T>]) -> Self {
+        let h = grid.len();
+        let w = grid.first().map_or(0, Vec::len);
+        assert!(grid.iter().all(|row| row.len() == w));
+
+        let mut sum = vec![vec![T::zero(); w + 1]; h + 1];
+        for (i, row) in grid.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                sum[i + 1][j + 1] =
+                    sum[i][j + 1].clone() + sum[i + 1][j].clone() - sum[i][j].clone() + x.clone();
+            }
+        }
+        Self { sum }
+    }
+
+    /// Returns the sum of `grid[r1..r2][c1..c2]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r1 > r2`, `c1 > c2` or the rectangle is out of the grid.
+    pub fn query(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> T {
+        assert!(r1 <= r2 && c1 <= c2);
+        let s = &self.sum;
+        s[r2][c2].clone() + s[r1][c1].clone() - s[r1][c2].clone() - s[r2][c1].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::xorshift;
+
+    #[test]
+    fn brute_force() {
+        let mut rand = xorshift(0x8BAD_F00D_u64, |seed| seed);
+        for &(h, w) in &[(1, 1), (3, 5), (6, 2), (7, 7)] {
+            let grid = (0..h)
+                .map(|_| {
+                    (0..w)
+                        .map(|_| (rand() % 41) as i64 - 20)
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let sum = PrefixSum2D::build(&grid);
+            for r1 in 0..=h {
+                for r2 in r1..=h {
+                    for c1 in 0..=w {
+                        for c2 in c1..=w {
+                            let naive = grid[r1..r2]
+                                .iter()
+                                .map(|row| row[c1..c2].iter().sum::<i64>())
+                                .sum::<i64>();
+                            assert_eq!(sum.query(r1, c1, r2, c2), naive);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let sum = PrefixSum2D::<u32>::build(&[]);
+        assert_eq!(sum.query(0, 0, 0, 0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_range() {
+        let sum = PrefixSum2D::build(&[vec![1_u32]]);
+        sum.query(0, 0, 2, 1);
+    }
+}